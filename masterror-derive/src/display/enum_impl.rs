@@ -751,7 +751,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_tuple_placeholder(&bindings, &placeholder, None);
         assert!(result.is_ok());
@@ -770,7 +771,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_tuple_placeholder(&bindings, &placeholder, None);
         assert!(result.is_ok());
@@ -789,7 +791,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_tuple_placeholder(&bindings, &placeholder, None);
         assert!(result.is_err());
@@ -806,7 +809,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_tuple_placeholder(&bindings, &placeholder, None);
         assert!(result.is_ok());
@@ -823,7 +827,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_tuple_placeholder(&bindings, &placeholder, None);
         assert!(result.is_err());
@@ -842,7 +847,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_named_placeholder(&fields, &bindings, &placeholder, None);
         assert!(result.is_ok());
@@ -863,7 +869,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_named_placeholder(&fields, &bindings, &placeholder, None);
         assert!(result.is_ok());
@@ -884,7 +891,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_named_placeholder(&fields, &bindings, &placeholder, None);
         assert!(result.is_err());
@@ -903,7 +911,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_named_placeholder(&fields, &bindings, &placeholder, None);
         assert!(result.is_err());
@@ -922,7 +931,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = variant_named_placeholder(&fields, &bindings, &placeholder, None);
         assert!(result.is_err());
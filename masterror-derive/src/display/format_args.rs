@@ -760,7 +760,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = env.resolve_placeholder(&placeholder)?;
         assert!(result.is_some());
@@ -783,7 +784,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = env.resolve_placeholder(&placeholder)?;
         assert!(result.is_some());
@@ -806,7 +808,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = env.resolve_placeholder(&placeholder)?;
         assert!(result.is_some());
@@ -825,7 +828,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = env.resolve_placeholder(&placeholder)?;
         assert!(result.is_none());
@@ -849,7 +853,8 @@ mod tests {
             formatter:  TemplateFormatter::Pointer {
                 alternate: false
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = env.resolve_placeholder(&placeholder)?;
         assert!(result.is_some());
@@ -871,7 +876,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_struct_shorthand(&fields, &shorthand, &placeholder)?;
         assert!(result.expr.to_string().contains("self"));
@@ -895,7 +901,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_struct_shorthand(&fields, &shorthand, &placeholder)?;
         assert!(result.expr.to_string().contains("inner"));
@@ -915,7 +922,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_struct_shorthand(&fields, &shorthand, &placeholder);
         assert!(result.is_err());
@@ -937,7 +945,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_struct_shorthand(&fields, &shorthand, &placeholder)?;
         assert!(result.expr.to_string().contains("self"));
@@ -959,7 +968,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_struct_shorthand(&fields, &shorthand, &placeholder);
         assert!(result.is_err());
@@ -979,7 +989,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder)?;
         assert!(!result.pointer_value);
@@ -1003,7 +1014,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder)?;
         assert!(!result.pointer_value);
@@ -1024,7 +1036,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder);
         assert!(result.is_err());
@@ -1044,7 +1057,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder);
         assert!(result.is_err());
@@ -1067,7 +1081,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder)?;
         assert!(!result.pointer_value);
@@ -1094,7 +1109,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder)?;
         assert!(!result.pointer_value);
@@ -1119,7 +1135,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder);
         assert!(result.is_err());
@@ -1142,7 +1159,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder);
         assert!(result.is_err());
@@ -1169,7 +1187,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder);
         assert!(result.is_err());
@@ -1189,7 +1208,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = resolve_variant_shorthand(&fields, &bindings, &shorthand, &placeholder);
         assert!(result.is_err());
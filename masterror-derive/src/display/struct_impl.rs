@@ -511,7 +511,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = struct_placeholder_expr(&fields, &placeholder, None);
         assert!(result.is_ok());
@@ -531,7 +532,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = struct_placeholder_expr(&fields, &placeholder, None);
         assert!(result.is_ok());
@@ -551,7 +553,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = struct_placeholder_expr(&fields, &placeholder, None);
         assert!(result.is_err());
@@ -569,7 +572,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = struct_placeholder_expr(&fields, &placeholder, None);
         assert!(result.is_ok());
@@ -586,7 +590,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = struct_placeholder_expr(&fields, &placeholder, None);
         assert!(result.is_err());
@@ -604,7 +609,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = struct_placeholder_expr(&fields, &placeholder, None);
         assert!(result.is_ok());
@@ -12,6 +12,7 @@
 
 use std::borrow::Cow;
 
+use masterror_template::template::{TemplateFormatter, TemplateIdentifier};
 use proc_macro2::{Literal, Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 use syn::Error;
@@ -93,7 +94,7 @@ struct IndexedArgument {
 pub fn render_template<F>(
     template: &DisplayTemplate,
     preludes: Vec<TokenStream>,
-    format_args: Vec<ResolvedFormatArgument>,
+    mut format_args: Vec<ResolvedFormatArgument>,
     mut resolver: F
 ) -> Result<TokenStream, Error>
 where
@@ -105,6 +106,8 @@ where
     let mut has_placeholder = false;
     let mut has_implicit_placeholders = false;
     let mut requires_format_engine = false;
+    let mut count_arguments = Vec::new();
+    let mut optional_forms = Vec::new();
     for segment in &template.segments {
         match segment {
             TemplateSegmentSpec::Literal(text) => {
@@ -120,7 +123,17 @@ where
                 if placeholder_requires_format_engine(&placeholder.formatter) {
                     requires_format_engine = true;
                 }
-                let resolved = resolver(placeholder)?;
+                check_optional_form(placeholder, &mut optional_forms)?;
+                let mut resolved = resolver(placeholder)?;
+                if let Some(fallback) = &placeholder.optional {
+                    resolved = optional_placeholder_expr(resolved, fallback);
+                }
+                resolve_count_arguments(
+                    placeholder,
+                    &format_args,
+                    &mut resolver,
+                    &mut count_arguments
+                )?;
                 format_buffer.push_str(&placeholder_format_fragment(placeholder));
                 segments.push(RenderedSegment::Placeholder(PlaceholderRender {
                     identifier: placeholder.identifier.clone(),
@@ -141,6 +154,7 @@ where
     }
     if has_additional_arguments || has_implicit_placeholders || requires_format_engine {
         let format_literal = Literal::string(&format_buffer);
+        format_args.append(&mut count_arguments);
         let args = build_template_arguments(&segments, format_args);
         return Ok(quote! {
             #(#preludes)*
@@ -167,6 +181,123 @@ where
     })
 }
 
+/// Wraps a resolved `{name?}` expression so `Some(value)` formats `value`
+/// and `None` formats `fallback`.
+fn optional_placeholder_expr(
+    resolved: ResolvedPlaceholderExpr,
+    fallback: &str
+) -> ResolvedPlaceholderExpr {
+    let expr = resolved.expr;
+    ResolvedPlaceholderExpr::new(quote! {
+        &masterror::__private::OptionalArg::new(&(#expr), #fallback)
+    })
+}
+
+/// Rejects templates that format the same argument both with and without
+/// `?` (or with different fallbacks).
+///
+/// Named and positional arguments are passed to `write!` once, so every
+/// occurrence must render the same expression.
+fn check_optional_form<'a>(
+    placeholder: &'a TemplatePlaceholderSpec,
+    seen: &mut Vec<(String, Option<&'a str>)>
+) -> Result<(), Error> {
+    let key = match &placeholder.identifier {
+        TemplateIdentifierSpec::Named(name) => name.clone(),
+        TemplateIdentifierSpec::Positional(index) => index.to_string(),
+        TemplateIdentifierSpec::Implicit(_) => return Ok(())
+    };
+    let optional = placeholder.optional.as_deref();
+    match seen.iter().find(|(existing, _)| *existing == key) {
+        Some((_, previous)) if *previous != optional => Err(Error::new(
+            placeholder.span,
+            format!("`{key}` must use the same `?` fallback in every placeholder")
+        )),
+        Some(_) => Ok(()),
+        None => {
+            seen.push((key, optional));
+            Ok(())
+        }
+    }
+}
+
+/// Resolves `width$` / `.precision$` references of a placeholder.
+///
+/// References already supplied by explicit format arguments are skipped; the
+/// remaining ones are resolved like `{name}` / `{index}` placeholders so that
+/// `#[error("{code:0pad$}")]` picks up the `pad` field.
+///
+/// # Arguments
+///
+/// * `placeholder` - The placeholder whose format spec is inspected
+/// * `format_args` - Explicit format arguments from the error attribute
+/// * `resolver` - Function to resolve placeholder identifiers to expressions
+/// * `resolved` - Output buffer receiving the synthesized arguments
+fn resolve_count_arguments<F>(
+    placeholder: &TemplatePlaceholderSpec,
+    format_args: &[ResolvedFormatArgument],
+    resolver: &mut F,
+    resolved: &mut Vec<ResolvedFormatArgument>
+) -> Result<(), Error>
+where
+    F: FnMut(&TemplatePlaceholderSpec) -> Result<ResolvedPlaceholderExpr, Error>
+{
+    let formatter = &placeholder.formatter;
+    let references = formatter
+        .width_argument()
+        .into_iter()
+        .chain(formatter.precision_argument());
+    for reference in references {
+        let (identifier, kind) = match reference {
+            TemplateIdentifier::Named(name) => {
+                let provided = format_args.iter().any(|argument| match &argument.kind {
+                    ResolvedFormatArgumentKind::Named(ident) => ident == name,
+                    _ => false
+                });
+                if provided {
+                    continue;
+                }
+                (
+                    TemplateIdentifierSpec::Named(name.to_owned()),
+                    ResolvedFormatArgumentKind::Named(format_ident!(
+                        "{}",
+                        name,
+                        span = placeholder.span
+                    ))
+                )
+            }
+            TemplateIdentifier::Positional(index) => {
+                let provided = format_args.iter().any(|argument| match argument.kind {
+                    ResolvedFormatArgumentKind::Positional(existing)
+                    | ResolvedFormatArgumentKind::Implicit(existing) => existing == index,
+                    ResolvedFormatArgumentKind::Named(_) => false
+                });
+                if provided {
+                    continue;
+                }
+                (
+                    TemplateIdentifierSpec::Positional(index),
+                    ResolvedFormatArgumentKind::Positional(index)
+                )
+            }
+            TemplateIdentifier::Implicit(_) => continue
+        };
+        let count = resolver(&TemplatePlaceholderSpec {
+            span: placeholder.span,
+            identifier,
+            formatter: TemplateFormatter::Display {
+                spec: None
+            },
+            optional: None
+        })?;
+        resolved.push(ResolvedFormatArgument {
+            kind,
+            expr: count.expr
+        });
+    }
+    Ok(())
+}
+
 /// Builds the argument list for the `write!` macro.
 ///
 /// Collects all arguments (from placeholders and explicit format arguments),
@@ -428,7 +559,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{foo}");
@@ -441,7 +573,8 @@ mod tests {
             formatter:  TemplateFormatter::Debug {
                 alternate: false
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{bar:?}");
@@ -454,7 +587,8 @@ mod tests {
             formatter:  TemplateFormatter::Debug {
                 alternate: true
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{bar:#?}");
@@ -467,7 +601,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{0}");
@@ -480,7 +615,8 @@ mod tests {
             formatter:  TemplateFormatter::LowerHex {
                 alternate: false
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{1:x}");
@@ -493,7 +629,8 @@ mod tests {
             formatter:  TemplateFormatter::LowerHex {
                 alternate: true
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{1:#x}");
@@ -506,7 +643,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: None
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{}");
@@ -519,7 +657,8 @@ mod tests {
             formatter:  TemplateFormatter::Binary {
                 alternate: false
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{:b}");
@@ -532,7 +671,8 @@ mod tests {
             formatter:  TemplateFormatter::Display {
                 spec: Some(">10".into())
             },
-            span:       Span::call_site()
+            span:       Span::call_site(),
+            optional:   None
         };
         let result = placeholder_format_fragment(&placeholder);
         assert_eq!(result, "{value:>10}");
@@ -667,6 +807,95 @@ mod tests {
         );
     }
 
+    fn count_placeholder(spec: &str) -> TemplatePlaceholderSpec {
+        TemplatePlaceholderSpec {
+            span:       Span::call_site(),
+            identifier: TemplateIdentifierSpec::Named("code".to_string()),
+            formatter:  TemplateFormatter::Display {
+                spec: Some(spec.into())
+            },
+            optional:   None
+        }
+    }
+
+    #[test]
+    fn test_resolve_count_arguments_resolves_field_references() -> Result<(), Error> {
+        let placeholder = count_placeholder(">pad$.1$");
+        let mut resolved = Vec::new();
+        resolve_count_arguments(
+            &placeholder,
+            &[],
+            &mut |spec: &TemplatePlaceholderSpec| {
+                Ok(ResolvedPlaceholderExpr::new(match &spec.identifier {
+                    TemplateIdentifierSpec::Named(name) => quote!(named(#name)),
+                    _ => quote!(positional)
+                }))
+            },
+            &mut resolved
+        )?;
+        assert_eq!(resolved.len(), 2);
+        assert!(matches!(
+            &resolved[0].kind,
+            ResolvedFormatArgumentKind::Named(ident) if ident == "pad"
+        ));
+        assert_eq!(
+            resolved[0].expr.to_string(),
+            quote!(named("pad")).to_string()
+        );
+        assert!(matches!(
+            resolved[1].kind,
+            ResolvedFormatArgumentKind::Positional(1)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_template_wraps_optional_placeholders() -> Result<(), Error> {
+        let template = crate::template_support::parse_display_template(syn::parse_quote!(
+            "{code?=none} {code?=none}"
+        ))?;
+        let tokens = render_template(&template, Vec::new(), Vec::new(), |_| {
+            Ok(ResolvedPlaceholderExpr::new(quote!(&self.code)))
+        })?;
+        let rendered = tokens.to_string();
+        assert!(rendered.contains("OptionalArg :: new"));
+        assert!(rendered.contains("\"none\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_template_rejects_mixed_optional_forms() -> Result<(), Error> {
+        for source in ["{code} {code?}", "{0?} {0?=-}"] {
+            let template = crate::template_support::parse_display_template(syn::LitStr::new(
+                source,
+                Span::call_site()
+            ))?;
+            let result = render_template(&template, Vec::new(), Vec::new(), |_| {
+                Ok(ResolvedPlaceholderExpr::new(quote!(&self.code)))
+            });
+            assert!(result.is_err(), "case: {source}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_count_arguments_skips_explicit_arguments() -> Result<(), Error> {
+        let placeholder = count_placeholder("0width$");
+        let explicit = [ResolvedFormatArgument {
+            kind: ResolvedFormatArgumentKind::Named(format_ident!("width")),
+            expr: quote!(self.pad)
+        }];
+        let mut resolved = Vec::new();
+        resolve_count_arguments(
+            &placeholder,
+            &explicit,
+            &mut |_: &TemplatePlaceholderSpec| Ok(ResolvedPlaceholderExpr::new(quote!(unused))),
+            &mut resolved
+        )?;
+        assert!(resolved.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_build_template_arguments_deduplicates_named() {
         let segments = vec![
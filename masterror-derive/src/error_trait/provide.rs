@@ -53,7 +53,7 @@ pub(crate) fn struct_provide_method(fields: &Fields) -> Option<TokenStream> {
     {
         let member = &backtrace.field().member;
         statements.push(provide_backtrace_tokens(
-            quote!(self.#member),
+            quote!((&self.#member)),
             backtrace.field(),
             &request
         ));
@@ -63,7 +63,9 @@ pub(crate) fn struct_provide_method(fields: &Fields) -> Option<TokenStream> {
             continue;
         }
         let member = &field.member;
-        let expr = quote!(self.#member);
+        // Borrow explicitly so the expression matches the by-reference
+        // bindings used for enum variants.
+        let expr = quote!((&self.#member));
         for spec in &field.attrs.provides {
             statements.extend(provide_custom_tokens(expr.clone(), field, spec, &request));
         }
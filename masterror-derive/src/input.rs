@@ -26,9 +26,10 @@ pub use types::{
     FieldAttrs, FieldRedactionKind, FieldRedactionSpec, Fields, FormatArg,
     FormatArgMethodTurbofish, FormatArgProjection, FormatArgProjectionMethodCall,
     FormatArgProjectionSegment, FormatArgShorthand, FormatArgValue, FormatArgsSpec,
-    FormatBindingKind, MasterrorSpec, ProvideSpec, RedactSpec, StructData, VariantData
+    FormatBindingKind, MasterrorSpec, ProvideSpec, RedactSpec, StructData, TagSpec, TelemetrySpec,
+    VariantData
 };
 // Re-export crate-internal utility functions
-pub(crate) use utils::{is_arc_type, is_backtrace_storage, option_inner_type};
+pub(crate) use utils::{is_arc_type, option_inner_type};
 // Re-export public utility functions
 pub use utils::{is_option_type, placeholder_error};
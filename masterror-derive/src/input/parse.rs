@@ -19,7 +19,7 @@ use super::{
     types::{DisplaySpec, ErrorData, ErrorInput, Fields, FormatArgsSpec, StructData, VariantData},
     utils::{
         collect_errors, path_is, validate_backtrace_usage, validate_from_usage,
-        validate_transparent
+        validate_source_as_detail, validate_transparent
     }
 };
 
@@ -72,6 +72,7 @@ fn parse_struct(
     validate_from_usage(&fields, &display, errors);
     validate_backtrace_usage(&fields, errors);
     validate_transparent(&fields, &display, errors, None);
+    validate_source_as_detail(&fields, masterror.as_ref(), errors);
     Ok(ErrorData::Struct(Box::new(StructData {
         fields,
         display,
@@ -132,6 +133,7 @@ fn parse_variant(
     validate_from_usage(&fields, &display, errors);
     validate_backtrace_usage(&fields, errors);
     validate_transparent(&fields, &display, errors, Some(&variant));
+    validate_source_as_detail(&fields, masterror.as_ref(), errors);
     Ok(VariantData {
         ident: variant.ident,
         fields,
@@ -8,18 +8,20 @@
 //! `#[masterror(...)]` attributes from derive macro input.
 
 use proc_macro2::Span;
+use quote::format_ident;
 use syn::{
-    Attribute, Error, Expr, ExprPath, Ident, LitBool, LitStr, Token, TypePath,
+    Attribute, Error, Expr, ExprPath, Ident, LitBool, LitInt, LitStr, Member, Token, TypePath,
     ext::IdentExt,
     parse::{ParseBuffer, ParseStream},
-    spanned::Spanned
+    spanned::Spanned,
+    token::Paren
 };
 
 use super::{
     parse_format::parse_format_args,
     types::{
         AppErrorSpec, DisplaySpec, FieldRedactionKind, FieldRedactionSpec, FormatBindingKind,
-        MasterrorSpec, ProvideSpec, RedactSpec
+        MasterrorSpec, ProvideSpec, RedactSpec, TagSpec, TelemetrySpec
     },
     utils::path_is
 };
@@ -279,6 +281,11 @@ fn parse_masterror_attribute(attr: &Attribute) -> Result<MasterrorSpec, Error> {
         let mut redact = RedactSpec::default();
         let mut seen_redact = false;
         let mut telemetry = None;
+        let mut tags = None;
+        let mut retry_after = None;
+        let mut status = None;
+        let mut www_authenticate = None;
+        let mut source_as_detail = false;
         let mut map_grpc = None;
         let mut map_problem = None;
         while !input.is_empty() {
@@ -319,6 +326,55 @@ fn parse_masterror_attribute(attr: &Attribute) -> Result<MasterrorSpec, Error> {
                     }
                     telemetry = Some(parse_telemetry_block(input, ident.span())?);
                 }
+                "tags" => {
+                    if tags.is_some() {
+                        return Err(Error::new(ident.span(), "duplicate tags(...) block"));
+                    }
+                    tags = Some(parse_tags_block(input, ident.span())?);
+                }
+                "retry_after" => {
+                    if retry_after.is_some() {
+                        return Err(Error::new(
+                            ident.span(),
+                            "duplicate retry_after specification"
+                        ));
+                    }
+                    input.parse::<Token![=]>()?;
+                    let value: Expr = input.parse()?;
+                    retry_after = Some(value);
+                }
+                "status" => {
+                    if status.is_some() {
+                        return Err(Error::new(ident.span(), "duplicate status specification"));
+                    }
+                    input.parse::<Token![=]>()?;
+                    let value: LitInt = input.parse()?;
+                    let code: u16 = value.base10_parse()?;
+                    if !(100..=599).contains(&code) {
+                        return Err(Error::new(
+                            value.span(),
+                            "status must be an HTTP status code between 100 and 599"
+                        ));
+                    }
+                    status = Some(value);
+                }
+                "www_authenticate" => {
+                    if www_authenticate.is_some() {
+                        return Err(Error::new(
+                            ident.span(),
+                            "duplicate www_authenticate specification"
+                        ));
+                    }
+                    input.parse::<Token![=]>()?;
+                    let value: Expr = input.parse()?;
+                    www_authenticate = Some(value);
+                }
+                "source_as_detail" => {
+                    if source_as_detail {
+                        return Err(Error::new(ident.span(), "duplicate source_as_detail flag"));
+                    }
+                    source_as_detail = parse_flag_value(input)?;
+                }
                 "map" => {
                     input.parse::<Token![.]>()?;
                     let sub: Ident = input.call(Ident::parse_any)?;
@@ -393,6 +449,11 @@ fn parse_masterror_attribute(attr: &Attribute) -> Result<MasterrorSpec, Error> {
             expose_message,
             redact,
             telemetry: telemetry.unwrap_or_default(),
+            tags: tags.unwrap_or_default(),
+            retry_after,
+            status,
+            www_authenticate,
+            source_as_detail,
             map_grpc,
             map_problem,
             attribute_span: attr.span()
@@ -513,13 +574,22 @@ fn parse_redact_fields(
 }
 
 /// Parses telemetry(...) block contents.
-fn parse_telemetry_block(input: ParseStream, span: Span) -> Result<Vec<Expr>, Error> {
+fn parse_telemetry_block(input: ParseStream, span: Span) -> Result<Vec<TelemetrySpec>, Error> {
     let content;
     syn::parenthesized!(content in input);
     let mut entries = Vec::new();
     while !content.is_empty() {
-        let expr: Expr = content.parse()?;
-        entries.push(expr);
+        let is_serialize = content
+            .fork()
+            .call(Ident::parse_any)
+            .is_ok_and(|ident| ident == "serialize")
+            && content.peek2(Paren);
+        if is_serialize {
+            content.call(Ident::parse_any)?;
+            entries.push(parse_telemetry_serialize(&content)?);
+        } else {
+            entries.push(TelemetrySpec::Field(content.parse()?));
+        }
         if content.peek(Token![,]) {
             content.parse::<Token![,]>()?;
             if content.is_empty() {
@@ -538,6 +608,95 @@ fn parse_telemetry_block(input: ParseStream, span: Span) -> Result<Vec<Expr>, Er
     Ok(entries)
 }
 
+/// Parses the `(value, prefix = "...")` part of `serialize(...)` inside
+/// telemetry(...).
+///
+/// `self.field` is rewritten to the binding of that field, because the
+/// generated conversion destructures the value before evaluating telemetry.
+fn parse_telemetry_serialize(input: ParseStream) -> Result<TelemetrySpec, Error> {
+    let content;
+    let paren = syn::parenthesized!(content in input);
+    if content.is_empty() {
+        return Err(Error::new(
+            paren.span.join(),
+            "serialize(...) requires a value to flatten"
+        ));
+    }
+    let value = self_field_binding(content.parse()?);
+    let mut prefix = None;
+    if content.peek(Token![,]) {
+        content.parse::<Token![,]>()?;
+        let ident: Ident = content.call(Ident::parse_any)?;
+        if ident != "prefix" {
+            return Err(Error::new(
+                ident.span(),
+                "expected `prefix = \"...\"` in serialize(...)"
+            ));
+        }
+        content.parse::<Token![=]>()?;
+        prefix = Some(content.parse::<LitStr>()?);
+    }
+    if !content.is_empty() {
+        return Err(Error::new(
+            content.span(),
+            "unexpected tokens in serialize(...)"
+        ));
+    }
+    Ok(TelemetrySpec::Serialize {
+        value,
+        prefix
+    })
+}
+
+/// Rewrites `self.name` / `self.0` to the corresponding field binding.
+fn self_field_binding(expr: Expr) -> Expr {
+    if let Expr::Field(field) = &expr
+        && let Expr::Path(base) = field.base.as_ref()
+        && base.path.is_ident("self")
+    {
+        let binding = match &field.member {
+            Member::Named(ident) => ident.clone(),
+            Member::Unnamed(index) => format_ident!("__field{}", index.index, span = index.span)
+        };
+        return syn::parse_quote!(#binding);
+    }
+    expr
+}
+
+/// Parses tags(...) block contents.
+fn parse_tags_block(input: ParseStream, span: Span) -> Result<Vec<TagSpec>, Error> {
+    let content;
+    syn::parenthesized!(content in input);
+    if content.is_empty() {
+        return Err(Error::new(span, "tags(...) requires at least one tag"));
+    }
+    let mut tags: Vec<TagSpec> = Vec::new();
+    while !content.is_empty() {
+        let name: LitStr = content.parse()?;
+        if tags.iter().any(|tag| tag.name.value() == name.value()) {
+            return Err(Error::new(
+                name.span(),
+                format!("duplicate tag `{}` in tags(...)", name.value())
+            ));
+        }
+        content.parse::<Token![=]>()?;
+        let value: LitStr = content.parse()?;
+        tags.push(TagSpec {
+            name,
+            value
+        });
+        if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+        } else if !content.is_empty() {
+            return Err(Error::new(
+                content.span(),
+                "expected `,` or end of input in tags(...)"
+            ));
+        }
+    }
+    Ok(tags)
+}
+
 /// Parses #[error(...)] attribute contents.
 fn parse_error_attribute(attr: &Attribute) -> Result<DisplaySpec, Error> {
     mod kw {
@@ -947,6 +1106,111 @@ mod tests {
         assert_eq!(result.unwrap().telemetry.len(), 2);
     }
 
+    #[test]
+    fn parse_masterror_attribute_with_telemetry_serialize() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, telemetry(x, serialize(self.context, prefix = "ctx")))]
+        };
+        let telemetry = parse_masterror_attribute(&attr).unwrap().telemetry;
+        assert_eq!(telemetry.len(), 2);
+        assert!(matches!(telemetry[0], TelemetrySpec::Field(_)));
+        match &telemetry[1] {
+            TelemetrySpec::Serialize {
+                value,
+                prefix
+            } => {
+                assert!(matches!(value, Expr::Path(path) if path.path.is_ident("context")));
+                assert_eq!(prefix.as_ref().map(LitStr::value).as_deref(), Some("ctx"));
+            }
+            TelemetrySpec::Field(_) => panic!("expected serialize entry")
+        }
+    }
+
+    #[test]
+    fn parse_masterror_attribute_telemetry_serialize_tuple_field() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, telemetry(serialize(self.1)))]
+        };
+        let telemetry = parse_masterror_attribute(&attr).unwrap().telemetry;
+        assert!(matches!(
+            &telemetry[0],
+            TelemetrySpec::Serialize { value: Expr::Path(path), prefix: None }
+                if path.path.is_ident("__field1")
+        ));
+    }
+
+    #[test]
+    fn parse_masterror_attribute_telemetry_serialize_rejects_unknown_option() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, telemetry(serialize(ctx, scope = "x")))]
+        };
+        assert!(parse_masterror_attribute(&attr).is_err());
+    }
+
+    #[test]
+    fn parse_masterror_attribute_with_tags() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, tags("subsystem" = "billing", "team" = "payments"))]
+        };
+        let tags = parse_masterror_attribute(&attr).unwrap().tags;
+        let pairs: Vec<_> = tags
+            .iter()
+            .map(|tag| (tag.name.value(), tag.value.value()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("subsystem".to_owned(), "billing".to_owned()),
+                ("team".to_owned(), "payments".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_masterror_attribute_without_tags() {
+        let attr: Attribute = parse_quote! { #[masterror(code = 1, category = C)] };
+        assert!(parse_masterror_attribute(&attr).unwrap().tags.is_empty());
+    }
+
+    #[test]
+    fn parse_tags_block_empty() {
+        let attr: Attribute = parse_quote! { #[masterror(code = 1, category = C, tags())] };
+        let result = parse_masterror_attribute(&attr);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_tags_block_duplicate_name() {
+        let attr: Attribute =
+            parse_quote! { #[masterror(code = 1, category = C, tags("a" = "x", "a" = "y"))] };
+        let result = parse_masterror_attribute(&attr);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_tags_block_requires_string_value() {
+        let attr: Attribute = parse_quote! { #[masterror(code = 1, category = C, tags("a" = 1))] };
+        let result = parse_masterror_attribute(&attr);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_tags_block_missing_comma() {
+        let attr: Attribute =
+            parse_quote! { #[masterror(code = 1, category = C, tags("a" = "x" "b" = "y"))] };
+        let result = parse_masterror_attribute(&attr);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_masterror_attribute_duplicate_tags() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, tags("a" = "x"), tags("b" = "y"))]
+        };
+        let result = parse_masterror_attribute(&attr);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn parse_masterror_attribute_with_map_grpc() {
         let attr: Attribute =
@@ -965,6 +1229,99 @@ mod tests {
         assert!(result.unwrap().map_problem.is_some());
     }
 
+    #[test]
+    fn parse_masterror_attribute_with_retry_after() {
+        let attr: Attribute =
+            parse_quote! { #[masterror(code = 1, category = C, retry_after = 30)] };
+        let result = parse_masterror_attribute(&attr);
+        assert!(result.is_ok());
+        assert!(result.unwrap().retry_after.is_some());
+    }
+
+    #[test]
+    fn parse_masterror_attribute_duplicate_retry_after() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, retry_after = 1, retry_after = 2)]
+        };
+        let result = parse_masterror_attribute(&attr);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_masterror_attribute_with_status() {
+        let attr: Attribute = parse_quote! { #[masterror(code = 1, category = C, status = 418)] };
+        let spec = parse_masterror_attribute(&attr).expect("spec");
+        let status = spec.status.expect("status");
+        assert_eq!(status.base10_parse::<u16>().expect("u16"), 418);
+    }
+
+    #[test]
+    fn parse_masterror_attribute_status_out_of_range() {
+        for attr in [
+            parse_quote! { #[masterror(code = 1, category = C, status = 99)] },
+            parse_quote! { #[masterror(code = 1, category = C, status = 600)] },
+            parse_quote! { #[masterror(code = 1, category = C, status = 70000)] }
+        ] {
+            let attr: Attribute = attr;
+            assert!(parse_masterror_attribute(&attr).is_err());
+        }
+    }
+
+    #[test]
+    fn parse_masterror_attribute_source_as_detail() {
+        let attr: Attribute =
+            parse_quote! { #[masterror(code = 1, category = C, source_as_detail)] };
+        assert!(
+            parse_masterror_attribute(&attr)
+                .expect("spec")
+                .source_as_detail
+        );
+        let attr: Attribute = parse_quote! { #[masterror(code = 1, category = C)] };
+        assert!(
+            !parse_masterror_attribute(&attr)
+                .expect("spec")
+                .source_as_detail
+        );
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, source_as_detail, source_as_detail)]
+        };
+        assert!(parse_masterror_attribute(&attr).is_err());
+    }
+
+    #[test]
+    fn parse_masterror_attribute_duplicate_status() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, status = 418, status = 419)]
+        };
+        assert!(parse_masterror_attribute(&attr).is_err());
+    }
+
+    #[test]
+    fn parse_masterror_attribute_with_www_authenticate() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, www_authenticate = "Bearer realm=\"api\"")]
+        };
+        let spec = parse_masterror_attribute(&attr).expect("spec");
+        assert!(spec.www_authenticate.is_some());
+        assert!(spec.retry_after.is_none());
+    }
+
+    #[test]
+    fn parse_masterror_attribute_without_www_authenticate() {
+        let attr: Attribute = parse_quote! { #[masterror(code = 1, category = C)] };
+        let spec = parse_masterror_attribute(&attr).expect("spec");
+        assert!(spec.www_authenticate.is_none());
+    }
+
+    #[test]
+    fn parse_masterror_attribute_duplicate_www_authenticate() {
+        let attr: Attribute = parse_quote! {
+            #[masterror(code = 1, category = C, www_authenticate = "Basic", www_authenticate = "Bearer")]
+        };
+        let result = parse_masterror_attribute(&attr);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn parse_masterror_attribute_duplicate_code() {
         let attr: Attribute = parse_quote! { #[masterror(code = 1, code = 2, category = C)] };
@@ -163,6 +163,12 @@ fn parse_projection_segment(
 ) -> syn::Result<FormatArgProjectionSegment> {
     if input.peek(LitInt) {
         let literal: LitInt = input.parse()?;
+        if !literal.suffix().is_empty() {
+            return Err(syn::Error::new(
+                literal.span(),
+                "tuple index in projection must not have a suffix"
+            ));
+        }
         let index = literal.base10_parse::<usize>()?;
         return Ok(FormatArgProjectionSegment::Index {
             index,
@@ -330,6 +336,30 @@ mod tests {
         assert_eq!(spec.args.len(), 3);
     }
 
+    #[test]
+    fn parse_format_args_literals_are_expressions() {
+        let spec = syn::parse::Parser::parse2(parse_format_args, quote::quote! { , 200, "ok", 0 })
+            .unwrap();
+        assert_eq!(spec.args.len(), 3);
+        for arg in &spec.args {
+            assert!(matches!(
+                &arg.value,
+                FormatArgValue::Expr(syn::Expr::Lit(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_format_args_rejects_malformed_literal() {
+        for tokens in [
+            quote::quote! { , 200 300 },
+            quote::quote! { , "status" 200 },
+            quote::quote! { , .0u8 }
+        ] {
+            assert!(syn::parse::Parser::parse2(parse_format_args, tokens).is_err());
+        }
+    }
+
     #[test]
     fn raw_format_arg_named() {
         let result: Result<RawFormatArg, _> = syn::parse2(quote::quote! { name = value });
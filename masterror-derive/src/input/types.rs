@@ -11,8 +11,8 @@
 use proc_macro2::Span;
 use syn::{
     AngleBracketedGenericArguments, Attribute, Error, Expr, ExprPath, Field as SynField,
-    Fields as SynFields, Ident, LitStr, Token, TypePath, ext::IdentExt, punctuated::Punctuated,
-    spanned::Spanned, token::Paren
+    Fields as SynFields, Ident, LitInt, LitStr, Token, TypePath, ext::IdentExt,
+    punctuated::Punctuated, spanned::Spanned, token::Paren
 };
 
 use super::{parse_attr::parse_provide_attribute, utils::is_backtrace_storage};
@@ -74,19 +74,24 @@ pub struct AppErrorSpec {
 
 /// Masterror attribute specification.
 ///
-/// Configures error code, category, redaction, telemetry, and transport
+/// Configures error code, category, redaction, telemetry, static tags, retry
+/// advice, HTTP status override, source-to-details folding, and transport
 /// mappings.
 #[derive(Clone, Debug)]
 pub struct MasterrorSpec {
-    pub code:           Expr,
-    pub category:       ExprPath,
-    pub expose_message: bool,
-    pub redact:         RedactSpec,
-    pub telemetry:      Vec<Expr>,
-    pub map_grpc:       Option<Expr>,
-    pub map_problem:    Option<Expr>,
-    #[allow(dead_code)]
-    pub attribute_span: Span
+    pub code:             Expr,
+    pub category:         ExprPath,
+    pub expose_message:   bool,
+    pub redact:           RedactSpec,
+    pub telemetry:        Vec<TelemetrySpec>,
+    pub tags:             Vec<TagSpec>,
+    pub retry_after:      Option<Expr>,
+    pub status:           Option<LitInt>,
+    pub www_authenticate: Option<Expr>,
+    pub source_as_detail: bool,
+    pub map_grpc:         Option<Expr>,
+    pub map_problem:      Option<Expr>,
+    pub attribute_span:   Span
 }
 
 /// Field redaction configuration.
@@ -105,6 +110,26 @@ pub struct FieldRedactionSpec {
     pub policy: FieldRedactionKind
 }
 
+/// Entry of a `telemetry(...)` block.
+#[derive(Clone, Debug)]
+pub enum TelemetrySpec {
+    /// Expression evaluating to `Option<masterror::Field>`.
+    Field(Expr),
+    /// `serialize(value, prefix = "...")`: a `Serialize` value flattened into
+    /// metadata keys.
+    Serialize {
+        value:  Expr,
+        prefix: Option<LitStr>
+    }
+}
+
+/// Static metadata entry from `tags("name" = "value", ...)`.
+#[derive(Clone, Debug)]
+pub struct TagSpec {
+    pub name:  LitStr,
+    pub value: LitStr
+}
+
 /// Field redaction strategy.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FieldRedactionKind {
@@ -10,7 +10,7 @@
 use proc_macro2::Span;
 use syn::{Attribute, Error, GenericArgument};
 
-use super::types::{DisplaySpec, Field, Fields};
+use super::types::{DisplaySpec, Field, Fields, MasterrorSpec};
 use crate::template_support::TemplateIdentifierSpec;
 
 /// Validates #[from] attribute usage across fields.
@@ -125,6 +125,23 @@ fn validate_backtrace_field_type(field: &Field, errors: &mut Vec<Error>) {
     ));
 }
 
+/// Validates `#[masterror(source_as_detail)]` has a source to fold.
+pub(crate) fn validate_source_as_detail(
+    fields: &Fields,
+    masterror: Option<&MasterrorSpec>,
+    errors: &mut Vec<Error>
+) {
+    let Some(spec) = masterror else {
+        return;
+    };
+    if spec.source_as_detail && !fields.iter().any(|field| field.attrs.has_source()) {
+        errors.push(Error::new(
+            spec.attribute_span,
+            "`source_as_detail` requires a #[source] field"
+        ));
+    }
+}
+
 /// Validates transparent attribute requires exactly one field.
 pub(crate) fn validate_transparent(
     fields: &Fields,
@@ -355,6 +372,24 @@ mod tests {
         assert!(errors.is_empty()); // Single field is OK
     }
 
+    #[test]
+    fn validate_source_as_detail_requires_source() {
+        let attr: syn::Attribute =
+            parse_quote! { #[masterror(code = 1, category = C, source_as_detail)] };
+        let spec = crate::input::parse_attr::extract_masterror_spec(&[attr], &mut Vec::new())
+            .expect("spec")
+            .expect("present");
+        let with_source: syn::FieldsNamed = parse_quote! { { #[source] e: io::Error } };
+        let without_source: syn::FieldsNamed = parse_quote! { { reason: String } };
+        let mut errors = Vec::new();
+        let parsed = Fields::from_syn(&syn::Fields::Named(with_source), &mut errors);
+        validate_source_as_detail(&parsed, Some(&spec), &mut errors);
+        assert!(errors.is_empty());
+        let parsed = Fields::from_syn(&syn::Fields::Named(without_source), &mut errors);
+        validate_source_as_detail(&parsed, Some(&spec), &mut errors);
+        assert_eq!(errors.len(), 1);
+    }
+
     #[test]
     fn validate_backtrace_usage_single() {
         let fields: syn::FieldsNamed = parse_quote! {
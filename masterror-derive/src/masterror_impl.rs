@@ -96,7 +96,7 @@ fn expand_struct(input: &ErrorInput, data: &StructData) -> Result<TokenStream, E
         )
     })?;
     let conversion = struct_conversion_impl(input, data, spec);
-    let mappings = struct_mapping_impl(input, spec);
+    let mappings = struct_mapping_impl(input, &data.fields, spec);
     use quote::quote;
     Ok(quote! {
         #conversion
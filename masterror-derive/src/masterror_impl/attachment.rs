@@ -10,19 +10,24 @@
 //! - Source error chain attachment (#[source] fields)
 //! - Backtrace capture and attachment
 //! - Telemetry metadata (spans, events, custom fields)
+//! - Static tags (`tags("name" = "value", ...)`)
 //! - Field redaction policies (message, field-level)
+//! - Retry advice (`retry_after = ...`)
+//! - HTTP status override (`status = ...`)
+//! - Source message folded into details (`source_as_detail`)
+//! - Authentication challenges (`www_authenticate = ...`)
 //!
 //! The attachment system respects type safety and handles both owned and
 //! Arc-wrapped error sources.
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::Expr;
+use syn::{Expr, ExprLit, Lit, LitInt};
 
 use super::binding::BoundField;
 use crate::input::{
-    FieldRedactionKind, FieldRedactionSpec, Fields, RedactSpec, is_arc_type, is_option_type,
-    option_inner_type
+    FieldRedactionKind, FieldRedactionSpec, Fields, RedactSpec, TagSpec, TelemetrySpec,
+    is_arc_type, is_option_type, option_inner_type
 };
 
 /// Generates source error attachment tokens.
@@ -101,6 +106,52 @@ pub fn source_attachment_tokens(bound_fields: &[BoundField<'_>]) -> TokenStream
     TokenStream::new()
 }
 
+/// Generates tokens copying the source message into `details.cause`.
+///
+/// Emitted before [`source_attachment_tokens`], which moves the source into
+/// the error. Parsing guarantees a `#[source]` field exists when the flag is
+/// set.
+///
+/// # Arguments
+///
+/// * `enabled` - Whether `source_as_detail` was requested
+/// * `bound_fields` - List of bound fields to search
+///
+/// # Returns
+///
+/// A `TokenStream` recording the cause, or empty if not requested.
+///
+/// # Examples
+///
+/// ```ignore
+/// // For a required source:
+/// // Generates:
+/// masterror::__private::source_as_detail(&mut __masterror_error, &inner);
+/// ```
+pub fn source_detail_tokens(enabled: bool, bound_fields: &[BoundField<'_>]) -> TokenStream {
+    if !enabled {
+        return TokenStream::new();
+    }
+    let Some(bound) = bound_fields
+        .iter()
+        .find(|bound| bound.field.attrs.has_source())
+    else {
+        return TokenStream::new();
+    };
+    let binding = &bound.binding;
+    if is_option_type(&bound.field.ty) {
+        quote! {
+            if let Some(source) = &#binding {
+                masterror::__private::source_as_detail(&mut __masterror_error, source);
+            }
+        }
+    } else {
+        quote! {
+            masterror::__private::source_as_detail(&mut __masterror_error, &#binding);
+        }
+    }
+}
+
 /// Generates backtrace attachment tokens.
 ///
 /// Searches for a backtrace field (marked with `#[backtrace]` or named
@@ -165,11 +216,12 @@ pub fn backtrace_attachment_tokens(
 /// Generates telemetry metadata initialization tokens.
 ///
 /// Creates code to build a metadata object from configured telemetry entries.
-/// Each entry is an optional field that's inserted if present.
+/// Each field entry is an optional field that's inserted if present;
+/// `serialize(...)` entries are flattened into the metadata at runtime.
 ///
 /// # Arguments
 ///
-/// * `entries` - List of telemetry field expressions
+/// * `entries` - List of telemetry entries
 ///
 /// # Returns
 ///
@@ -187,6 +239,12 @@ pub fn backtrace_attachment_tokens(
 /// if let Some(field) = (span_id()) {
 ///     __masterror_metadata_inner.insert(field);
 /// }
+/// // For serialize(context, prefix = "ctx"):
+/// masterror::__private::flatten_telemetry(
+///     &mut __masterror_metadata_inner,
+///     Some("ctx"),
+///     &(context)
+/// );
 /// let __masterror_metadata = if __masterror_metadata_inner.is_empty() {
 ///     None
 /// } else {
@@ -196,15 +254,31 @@ pub fn backtrace_attachment_tokens(
 /// // For no telemetry:
 /// // Generates: let __masterror_metadata: Option<masterror::Metadata> = None;
 /// ```
-pub fn telemetry_initialization(entries: &[Expr]) -> TokenStream {
+pub fn telemetry_initialization(entries: &[TelemetrySpec]) -> TokenStream {
     if entries.is_empty() {
         quote!(let __masterror_metadata: Option<masterror::Metadata> = None;)
     } else {
-        let inserts = entries.iter().map(|expr| {
-            quote! {
+        let inserts = entries.iter().map(|entry| match entry {
+            TelemetrySpec::Field(expr) => quote! {
                 if let Some(field) = (#expr) {
                     __masterror_metadata_inner.insert(field);
                 }
+            },
+            TelemetrySpec::Serialize {
+                value,
+                prefix
+            } => {
+                let prefix = match prefix {
+                    Some(prefix) => quote!(Some(#prefix)),
+                    None => quote!(None)
+                };
+                quote! {
+                    masterror::__private::flatten_telemetry(
+                        &mut __masterror_metadata_inner,
+                        #prefix,
+                        &(#value)
+                    );
+                }
             }
         });
         quote! {
@@ -243,6 +317,43 @@ pub fn metadata_attach_tokens() -> TokenStream {
     }
 }
 
+/// Generates static tag attachment tokens.
+///
+/// Each tag becomes a string field with `masterror::FieldRedaction::None` so
+/// it is always exposed as-is. Tags are attached after telemetry metadata.
+///
+/// # Arguments
+///
+/// * `tags` - Tags declared with `tags("name" = "value", ...)`
+///
+/// # Returns
+///
+/// A `TokenStream` attaching every tag, or empty if none are configured.
+///
+/// # Examples
+///
+/// ```ignore
+/// // For tags("subsystem" = "billing"):
+/// // Generates:
+/// __masterror_error = __masterror_error.with_field(
+///     masterror::field::str("subsystem", "billing")
+///         .with_redaction(masterror::FieldRedaction::None)
+/// );
+/// ```
+pub fn tags_tokens(tags: &[TagSpec]) -> TokenStream {
+    let inserts = tags.iter().map(|tag| {
+        let name = &tag.name;
+        let value = &tag.value;
+        quote! {
+            __masterror_error = __masterror_error.with_field(
+                masterror::field::str(#name, #value)
+                    .with_redaction(masterror::FieldRedaction::None)
+            );
+        }
+    });
+    quote!(#(#inserts)*)
+}
+
 /// Generates redaction policy application tokens.
 ///
 /// Creates code to apply redaction policies to the error message and specific
@@ -285,6 +396,100 @@ pub fn redact_tokens(spec: &RedactSpec) -> TokenStream {
     }
 }
 
+/// Generates retry advice attachment tokens.
+///
+/// Integer literals are passed to `with_retry_after_secs` directly so they
+/// infer as `u64`; any other expression goes through a hidden helper
+/// accepting `u32`, `u64` or `Duration`.
+///
+/// # Arguments
+///
+/// * `retry_after` - Optional `retry_after = ...` expression
+///
+/// # Returns
+///
+/// A `TokenStream` attaching the retry advice, or empty if not configured.
+///
+/// # Examples
+///
+/// ```ignore
+/// // For retry_after = 30:
+/// // Generates:
+/// __masterror_error = __masterror_error.with_retry_after_secs(30);
+/// ```
+pub fn retry_after_tokens(retry_after: Option<&Expr>) -> TokenStream {
+    match retry_after {
+        None => TokenStream::new(),
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Int(secs),
+            ..
+        })) => quote!(
+            __masterror_error = __masterror_error.with_retry_after_secs(#secs);
+        ),
+        Some(expr) => quote!(
+            __masterror_error = __masterror_error
+                .with_retry_after_secs(masterror::__private::retry_after_secs((#expr)));
+        )
+    }
+}
+
+/// Generates HTTP status override tokens.
+///
+/// The literal was range-checked while parsing the attribute.
+///
+/// # Arguments
+///
+/// * `status` - Optional `status = ...` literal
+///
+/// # Returns
+///
+/// A `TokenStream` overriding the status, or empty if not configured.
+///
+/// # Examples
+///
+/// ```ignore
+/// // For status = 418:
+/// // Generates:
+/// __masterror_error = __masterror_error.with_status(418);
+/// ```
+pub fn status_tokens(status: Option<&LitInt>) -> TokenStream {
+    match status {
+        None => TokenStream::new(),
+        Some(status) => quote!(
+            __masterror_error = __masterror_error.with_status(#status);
+        )
+    }
+}
+
+/// Generates `WWW-Authenticate` challenge attachment tokens.
+///
+/// The expression is passed to `with_www_authenticate`, so any value
+/// implementing `Into<String>` is accepted.
+///
+/// # Arguments
+///
+/// * `challenge` - Optional `www_authenticate = ...` expression
+///
+/// # Returns
+///
+/// A `TokenStream` attaching the challenge, or empty if not configured.
+///
+/// # Examples
+///
+/// ```ignore
+/// // For www_authenticate = "Bearer":
+/// // Generates:
+/// __masterror_error = __masterror_error.with_www_authenticate(("Bearer"));
+/// ```
+pub fn www_authenticate_tokens(challenge: Option<&Expr>) -> TokenStream {
+    match challenge {
+        None => TokenStream::new(),
+        Some(expr) => quote!(
+            __masterror_error = __masterror_error.with_www_authenticate((#expr));
+        )
+    }
+}
+
 /// Generates field redaction policy tokens.
 ///
 /// Converts a field redaction kind to its corresponding enum variant.
@@ -329,7 +534,10 @@ mod tests {
     #[test]
     fn test_telemetry_initialization_with_entries() {
         use syn::parse_quote;
-        let entries: Vec<Expr> = vec![parse_quote!(trace_id()), parse_quote!(span_id())];
+        let entries = vec![
+            TelemetrySpec::Field(parse_quote!(trace_id())),
+            TelemetrySpec::Field(parse_quote!(span_id())),
+        ];
         let result = telemetry_initialization(&entries);
         let result_str = result.to_string();
         assert!(result_str.contains("__masterror_metadata_inner"));
@@ -338,6 +546,19 @@ mod tests {
         assert!(result_str.contains("is_empty"));
     }
 
+    #[test]
+    fn test_telemetry_initialization_with_serialize() {
+        use syn::parse_quote;
+        let entries = vec![TelemetrySpec::Serialize {
+            value:  parse_quote!(context),
+            prefix: Some(parse_quote!("ctx"))
+        }];
+        let result = telemetry_initialization(&entries).to_string();
+        assert!(result.contains("flatten_telemetry"));
+        assert!(result.contains("Some (\"ctx\")"));
+        assert!(result.contains("& (context)"));
+    }
+
     #[test]
     fn test_metadata_attach_tokens() {
         let result = metadata_attach_tokens();
@@ -347,6 +568,43 @@ mod tests {
         assert!(result_str.contains("with_metadata"));
     }
 
+    #[test]
+    fn test_tags_tokens() {
+        use proc_macro2::Span;
+        use syn::LitStr;
+        assert!(tags_tokens(&[]).is_empty());
+        let tags = [TagSpec {
+            name:  LitStr::new("subsystem", Span::call_site()),
+            value: LitStr::new("billing", Span::call_site())
+        }];
+        let result_str = tags_tokens(&tags).to_string();
+        assert!(result_str.contains("with_field"));
+        assert!(result_str.contains("\"subsystem\" , \"billing\""));
+        assert!(result_str.contains("FieldRedaction :: None"));
+    }
+
+    #[test]
+    fn test_status_tokens() {
+        use syn::parse_quote;
+        assert!(status_tokens(None).is_empty());
+        let status: LitInt = parse_quote!(418);
+        let expected = quote!(__masterror_error = __masterror_error.with_status(418););
+        assert_eq!(
+            status_tokens(Some(&status)).to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn test_www_authenticate_tokens() {
+        use syn::parse_quote;
+        assert!(www_authenticate_tokens(None).is_empty());
+        let expr: Expr = parse_quote!("Bearer realm=\"api\"");
+        let result_str = www_authenticate_tokens(Some(&expr)).to_string();
+        assert!(result_str.contains("with_www_authenticate"));
+        assert!(result_str.contains("Bearer realm"));
+    }
+
     #[test]
     fn test_field_redaction_tokens_all_variants() {
         let none = field_redaction_tokens(FieldRedactionKind::None);
@@ -397,6 +655,33 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_source_detail_tokens() {
+        use proc_macro2::Span;
+        use quote::format_ident;
+        use syn::parse_quote;
+
+        use crate::input::{Field, FieldAttrs};
+        let mut attrs = FieldAttrs::default();
+        attrs.source = Some(parse_quote!(#[source]));
+        let field = Field {
+            ident: Some(format_ident!("inner")),
+            member: syn::Member::Named(format_ident!("inner")),
+            ty: parse_quote!(Option<std::io::Error>),
+            index: 0,
+            attrs,
+            span: Span::call_site()
+        };
+        let bound = vec![BoundField {
+            field:   &field,
+            binding: format_ident!("inner")
+        }];
+        assert!(source_detail_tokens(false, &bound).is_empty());
+        let result_str = source_detail_tokens(true, &bound).to_string();
+        assert!(result_str.contains("source_as_detail"));
+        assert!(result_str.contains("if let Some"));
+    }
+
     #[test]
     fn test_backtrace_attachment_tokens_none() {
         let fields = Fields::Unit;
@@ -15,8 +15,9 @@
 //! The binding system ensures that all error fields are properly extracted
 //! and available for attachment to the resulting `masterror::Error`.
 
-use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote};
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::Expr;
 
 use crate::input::{Field, Fields, VariantData};
 
@@ -236,6 +237,56 @@ pub fn binding_ident(field: &Field) -> Ident {
         .unwrap_or_else(|| format_ident!("__field{}", field.index, span = field.span))
 }
 
+/// Checks whether an attribute expression refers to any field binding.
+///
+/// Identifiers that follow `.` or `::` are member or path segments and never
+/// count as bindings, so `AppCode::Timeout` or `self.code` on a field named
+/// `code` are treated as static.
+///
+/// # Arguments
+///
+/// * `expr` - The attribute expression, e.g. the value of `code = ...`
+/// * `fields` - The fields bound while converting the value
+///
+/// # Returns
+///
+/// `true` when the expression can only be evaluated per instance.
+///
+/// # Examples
+///
+/// ```ignore
+/// // struct Error { provider: Provider }
+/// // `code = provider.code()`     -> true
+/// // `code = AppCode::Timeout`    -> false
+/// ```
+pub fn references_bindings(expr: &Expr, fields: &Fields) -> bool {
+    let bindings: Vec<Ident> = fields.iter().map(binding_ident).collect();
+    !bindings.is_empty() && tokens_reference(expr.to_token_stream(), &bindings)
+}
+
+fn tokens_reference(tokens: TokenStream, bindings: &[Ident]) -> bool {
+    let mut after_accessor = false;
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                if !after_accessor && bindings.contains(&ident) {
+                    return true;
+                }
+                after_accessor = false;
+            }
+            TokenTree::Punct(punct) => after_accessor = matches!(punct.as_char(), '.' | ':'),
+            TokenTree::Group(group) => {
+                if tokens_reference(group.stream(), bindings) {
+                    return true;
+                }
+                after_accessor = false;
+            }
+            TokenTree::Literal(_) => after_accessor = false
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use proc_macro2::Span;
@@ -417,4 +468,38 @@ mod tests {
         assert!(result_str.contains("field1"));
         assert!(result_str.contains("field2"));
     }
+
+    #[test]
+    fn test_references_bindings_detects_field_usage() {
+        let field = create_test_field(Some(format_ident!("provider")), 0);
+        let fields = Fields::Named(vec![field]);
+        assert!(references_bindings(&parse_quote!(provider.code()), &fields));
+        assert!(references_bindings(
+            &parse_quote!(lookup(&provider)),
+            &fields
+        ));
+        assert!(!references_bindings(
+            &parse_quote!(AppCode::Timeout),
+            &fields
+        ));
+        assert!(!references_bindings(
+            &parse_quote!(Codes::provider),
+            &fields
+        ));
+        assert!(!references_bindings(
+            &parse_quote!(Provider.provider),
+            &fields
+        ));
+    }
+
+    #[test]
+    fn test_references_bindings_unnamed_and_unit() {
+        let field = create_test_field(None, 0);
+        let fields = Fields::Unnamed(vec![field]);
+        assert!(references_bindings(&parse_quote!(__field0.code()), &fields));
+        assert!(!references_bindings(
+            &parse_quote!(AppCode::Internal),
+            &Fields::Unit
+        ));
+    }
 }
@@ -11,6 +11,10 @@
 //! - Message initialization from Display implementations
 //! - Field destructuring and binding
 //! - Code and category assignment
+//! - Static tags from `tags(...)`
+//! - Retry advice from `retry_after = ...`
+//! - HTTP status override from `status = ...`
+//! - `WWW-Authenticate` challenge from `www_authenticate = ...`
 //! - Integration with attachment and metadata systems
 //!
 //! The conversion process ensures all error information is properly transferred
@@ -22,8 +26,9 @@ use syn::Error;
 
 use super::{
     attachment::{
-        backtrace_attachment_tokens, metadata_attach_tokens, redact_tokens,
-        source_attachment_tokens, telemetry_initialization
+        backtrace_attachment_tokens, metadata_attach_tokens, redact_tokens, retry_after_tokens,
+        source_attachment_tokens, source_detail_tokens, status_tokens, tags_tokens,
+        telemetry_initialization, www_authenticate_tokens
     },
     binding::{bind_struct_fields, bind_variant_fields, field_usage_tokens}
 };
@@ -69,7 +74,12 @@ pub fn struct_conversion_impl(
     let field_usage = field_usage_tokens(&bound_fields);
     let telemetry_init = telemetry_initialization(&spec.telemetry);
     let metadata_attach = metadata_attach_tokens();
+    let tags_tokens = tags_tokens(&spec.tags);
     let redact_tokens = redact_tokens(&spec.redact);
+    let retry_tokens = retry_after_tokens(spec.retry_after.as_ref());
+    let status_tokens = status_tokens(spec.status.as_ref());
+    let challenge_tokens = www_authenticate_tokens(spec.www_authenticate.as_ref());
+    let source_detail = source_detail_tokens(spec.source_as_detail, &bound_fields);
     let source_tokens = source_attachment_tokens(&bound_fields);
     let backtrace_tokens = backtrace_attachment_tokens(&data.fields, &bound_fields);
     quote! {
@@ -85,7 +95,12 @@ pub fn struct_conversion_impl(
                 };
                 __masterror_error = __masterror_error.with_code((#code));
                 #metadata_attach
+                #tags_tokens
                 #redact_tokens
+                #retry_tokens
+                #status_tokens
+                #challenge_tokens
+                #source_detail
                 #source_tokens
                 #backtrace_tokens
                 __masterror_error
@@ -134,7 +149,12 @@ pub fn enum_conversion_impl(input: &ErrorInput, variants: &[VariantData]) -> Tok
         let field_usage = field_usage_tokens(&bound_fields);
         let telemetry_init = telemetry_initialization(&spec.telemetry);
         let metadata_attach = metadata_attach_tokens();
+        let tags_tokens = tags_tokens(&spec.tags);
         let redact_tokens = redact_tokens(&spec.redact);
+        let retry_tokens = retry_after_tokens(spec.retry_after.as_ref());
+        let status_tokens = status_tokens(spec.status.as_ref());
+        let challenge_tokens = www_authenticate_tokens(spec.www_authenticate.as_ref());
+        let source_detail = source_detail_tokens(spec.source_as_detail, &bound_fields);
         let source_tokens = source_attachment_tokens(&bound_fields);
         let backtrace_tokens = backtrace_attachment_tokens(&variant.fields, &bound_fields);
         message_arms.push(enum_message_arm(ident, variant, spec.expose_message));
@@ -148,7 +168,12 @@ pub fn enum_conversion_impl(input: &ErrorInput, variants: &[VariantData]) -> Tok
                 };
                 __masterror_error = __masterror_error.with_code((#code));
                 #metadata_attach
+                #tags_tokens
                 #redact_tokens
+                #retry_tokens
+                #status_tokens
+                #challenge_tokens
+                #source_detail
                 #source_tokens
                 #backtrace_tokens
                 __masterror_error
@@ -13,12 +13,16 @@
 //!
 //! For struct types, it generates single mapping constants. For enum types,
 //! it generates arrays of mappings corresponding to each variant.
+//!
+//! A `code = ...` expression that reads fields cannot be evaluated in a
+//! constant, so the mappings fall back to the category's canonical code.
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Expr, ExprPath, Index};
+use syn::{Expr, ExprPath, Index, parse_quote};
 
-use crate::input::{ErrorInput, MasterrorSpec, VariantData};
+use super::binding::references_bindings;
+use crate::input::{ErrorInput, Fields, MasterrorSpec, VariantData};
 
 /// Generates protocol mapping constants for struct error types.
 ///
@@ -30,6 +34,7 @@ use crate::input::{ErrorInput, MasterrorSpec, VariantData};
 /// # Arguments
 ///
 /// * `input` - The parsed error type definition
+/// * `fields` - The struct's field definitions
 /// * `spec` - Masterror specification with mapping configurations
 ///
 /// # Returns
@@ -54,16 +59,20 @@ use crate::input::{ErrorInput, MasterrorSpec, VariantData};
 ///     pub const PROBLEM_MAPPING: Option<masterror::mapping::ProblemMapping> = None;
 /// }
 /// ```
-pub fn struct_mapping_impl(input: &ErrorInput, spec: &MasterrorSpec) -> TokenStream {
+pub fn struct_mapping_impl(
+    input: &ErrorInput,
+    fields: &Fields,
+    spec: &MasterrorSpec
+) -> TokenStream {
     let ident = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-    let code = &spec.code;
+    let code = mapping_code(spec, fields);
     let category = &spec.category;
     let grpc_mapping =
-        mapping_option_tokens(spec.map_grpc.as_ref(), code, category, MappingKind::Grpc);
+        mapping_option_tokens(spec.map_grpc.as_ref(), &code, category, MappingKind::Grpc);
     let problem_mapping = mapping_option_tokens(
         spec.map_problem.as_ref(),
-        code,
+        &code,
         category,
         MappingKind::Problem
     );
@@ -125,7 +134,7 @@ pub fn enum_mapping_impl(input: &ErrorInput, variants: &[VariantData]) -> TokenS
         .iter()
         .map(|variant| {
             let spec = variant.masterror.as_ref().expect("presence checked");
-            let code = &spec.code;
+            let code = mapping_code(spec, &variant.fields);
             let category = &spec.category;
             quote!(masterror::mapping::HttpMapping::new((#code), (#category)))
         })
@@ -134,7 +143,7 @@ pub fn enum_mapping_impl(input: &ErrorInput, variants: &[VariantData]) -> TokenS
         .iter()
         .filter_map(|variant| {
             let spec = variant.masterror.as_ref().expect("presence checked");
-            let code = &spec.code;
+            let code = mapping_code(spec, &variant.fields);
             let category = &spec.category;
             spec.map_grpc.as_ref().map(
                 |expr| quote!(masterror::mapping::GrpcMapping::new((#code), (#category), (#expr)))
@@ -145,7 +154,7 @@ pub fn enum_mapping_impl(input: &ErrorInput, variants: &[VariantData]) -> TokenS
         .iter()
         .filter_map(|variant| {
             let spec = variant.masterror.as_ref().expect("presence checked");
-            let code = &spec.code;
+            let code = mapping_code(spec, &variant.fields);
             let category = &spec.category;
             spec.map_problem.as_ref().map(|expr| {
                 quote!(masterror::mapping::ProblemMapping::new((#code), (#category), (#expr)))
@@ -175,6 +184,28 @@ pub fn enum_mapping_impl(input: &ErrorInput, variants: &[VariantData]) -> TokenS
     }
 }
 
+/// Selects the code expression used in mapping constants.
+///
+/// Static codes are used verbatim. Codes computed from fields fall back to
+/// `AppCode::from_kind` of the category, which is const-evaluable.
+///
+/// # Arguments
+///
+/// * `spec` - Masterror specification with code and category
+/// * `fields` - Fields bound during conversion
+///
+/// # Returns
+///
+/// An `Expr` valid in a `const` initializer.
+fn mapping_code(spec: &MasterrorSpec, fields: &Fields) -> Expr {
+    if references_bindings(&spec.code, fields) {
+        let category = &spec.category;
+        parse_quote!(masterror::AppCode::from_kind(#category))
+    } else {
+        spec.code.clone()
+    }
+}
+
 /// Represents the type of protocol mapping being generated.
 #[derive(Clone, Copy)]
 enum MappingKind {
@@ -25,7 +25,9 @@ pub enum TemplateSegmentSpec {
 pub struct TemplatePlaceholderSpec {
     pub span:       Span,
     pub identifier: TemplateIdentifierSpec,
-    pub formatter:  TemplateFormatter
+    pub formatter:  TemplateFormatter,
+    /// Text rendered for `None` by `{name?}` / `{name?=fallback}`.
+    pub optional:   Option<String>
 }
 
 #[derive(Debug, Clone)]
@@ -58,7 +60,8 @@ pub fn parse_display_template(lit: LitStr) -> Result<DisplayTemplate, Error> {
                 segments.push(TemplateSegmentSpec::Placeholder(TemplatePlaceholderSpec {
                     span,
                     identifier,
-                    formatter: placeholder.formatter().clone()
+                    formatter: placeholder.formatter().clone(),
+                    optional: placeholder.optional_fallback().map(str::to_owned)
                 }));
             }
         }
@@ -73,7 +76,15 @@ fn placeholder_span(lit: &LitStr, range: core::ops::Range<usize>) -> Span {
 }
 
 fn template_error(lit: &LitStr, error: TemplateError) -> Error {
-    let message = error.to_string();
+    let message = match &error {
+        TemplateError::InvalidFormatter {
+            span
+        } => match formatter_spec(&lit.value(), span.clone()) {
+            Some(spec) => format!("{error} `{spec}`"),
+            None => error.to_string()
+        },
+        _ => error.to_string()
+    };
     let span = match &error {
         TemplateError::UnmatchedClosingBrace {
             index
@@ -100,6 +111,14 @@ fn template_error(lit: &LitStr, error: TemplateError) -> Error {
     Error::new(span.unwrap_or_else(|| lit.span()), message)
 }
 
+/// Extracts the text after `:` from the placeholder covering `range`.
+fn formatter_spec(value: &str, range: core::ops::Range<usize>) -> Option<String> {
+    let placeholder = value.get(range)?;
+    let body = placeholder.strip_prefix('{')?.strip_suffix('}')?;
+    let (_, spec) = body.split_once(':')?;
+    Some(spec.trim().to_owned())
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
@@ -461,7 +480,7 @@ mod tests {
         };
         let syn_error = template_error(&lit, error);
         let msg = syn_error.to_string();
-        assert!(msg.contains("unsupported formatter") || msg.contains("Invalid"));
+        assert!(msg.contains("unsupported formatter `@`"));
     }
 
     /// Verifies that `placeholder_span` doesn't panic for valid ranges.
@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: MIT
 
 use core::{fmt, ops::Range};
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeSet};
 
 mod parser;
 
@@ -78,6 +78,95 @@ impl<'a> ErrorTemplate<'a> {
     }
 }
 
+/// Validates an `#[error("...")]` template without going through the derive.
+///
+/// Returns a [`TemplateReport`] describing the referenced placeholders, or
+/// the first [`TemplateError`] with byte offsets into `input`.
+///
+/// # Examples
+///
+/// ```
+/// use masterror_template::template::{TemplateError, validate};
+///
+/// let report = validate("{code}: {message}").expect("valid template");
+/// assert_eq!(
+///     report.named().into_iter().collect::<Vec<_>>(),
+///     ["code", "message"]
+/// );
+///
+/// assert_eq!(
+///     validate("{code").unwrap_err(),
+///     TemplateError::UnterminatedPlaceholder {
+///         start: 0
+///     }
+/// );
+/// ```
+pub fn validate(input: &str) -> Result<TemplateReport<'_>, TemplateError> {
+    let template = ErrorTemplate::parse(input)?;
+    let placeholders = template.placeholders().cloned().collect();
+    Ok(TemplateReport {
+        placeholders
+    })
+}
+
+/// Placeholders referenced by a template accepted by [`validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateReport<'a> {
+    placeholders: Vec<TemplatePlaceholder<'a>>
+}
+
+impl<'a> TemplateReport<'a> {
+    /// Returns the placeholders in order of appearance.
+    pub fn placeholders(&self) -> &[TemplatePlaceholder<'a>] {
+        &self.placeholders
+    }
+
+    /// Returns the named arguments referenced by placeholders or by
+    /// `name$` width/precision counts.
+    pub fn named(&self) -> BTreeSet<&str> {
+        self.references()
+            .filter_map(|identifier| match identifier {
+                TemplateIdentifier::Named(name) => Some(name),
+                TemplateIdentifier::Positional(_) | TemplateIdentifier::Implicit(_) => None
+            })
+            .collect()
+    }
+
+    /// Returns the explicit positional indices referenced by placeholders or
+    /// by `N$` width/precision counts.
+    pub fn positional(&self) -> BTreeSet<usize> {
+        self.references()
+            .filter_map(|identifier| match identifier {
+                TemplateIdentifier::Positional(index) => Some(index),
+                TemplateIdentifier::Named(_) | TemplateIdentifier::Implicit(_) => None
+            })
+            .collect()
+    }
+
+    /// Returns the number of implicit (`{}`) placeholders.
+    pub fn implicit_count(&self) -> usize {
+        self.placeholders
+            .iter()
+            .filter(|placeholder| {
+                matches!(placeholder.identifier, TemplateIdentifier::Implicit(_))
+            })
+            .count()
+    }
+
+    fn references(&self) -> impl Iterator<Item = TemplateIdentifier<'_>> {
+        self.placeholders.iter().flat_map(|placeholder| {
+            let formatter = &placeholder.formatter;
+            [
+                Some(placeholder.identifier.clone()),
+                formatter.width_argument(),
+                formatter.precision_argument()
+            ]
+            .into_iter()
+            .flatten()
+        })
+    }
+}
+
 /// A lazily formatted view over a template.
 #[derive(Debug)]
 pub struct DisplayWith<'a, 't, F>
@@ -119,7 +208,8 @@ pub enum TemplateSegment<'a> {
 pub struct TemplatePlaceholder<'a> {
     span:       Range<usize>,
     identifier: TemplateIdentifier<'a>,
-    formatter:  TemplateFormatter
+    formatter:  TemplateFormatter,
+    optional:   Option<&'a str>
 }
 
 impl<'a> TemplatePlaceholder<'a> {
@@ -138,6 +228,27 @@ impl<'a> TemplatePlaceholder<'a> {
     pub fn formatter(&self) -> &TemplateFormatter {
         &self.formatter
     }
+
+    /// Returns the text rendered for `None` when the placeholder unwraps an
+    /// `Option` (`{name?}` or `{name?=fallback}`).
+    ///
+    /// `{name?}` yields an empty fallback; regular placeholders return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use masterror_template::template::ErrorTemplate;
+    ///
+    /// let template = ErrorTemplate::parse("{user?=anonymous} {id?:>4} {code}").expect("parse");
+    /// let fallbacks: Vec<_> = template
+    ///     .placeholders()
+    ///     .map(|placeholder| placeholder.optional_fallback())
+    ///     .collect();
+    /// assert_eq!(fallbacks, [Some("anonymous"), Some(""), None]);
+    /// ```
+    pub const fn optional_fallback(&self) -> Option<&'a str> {
+        self.optional
+    }
 }
 
 /// Placeholder identifier parsed from the template.
@@ -437,6 +548,48 @@ impl TemplateFormatter {
         }
     }
 
+    /// Returns the argument supplying the width when the display spec uses a
+    /// `width$` or `1$` count reference (for example `{value:0width$}`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use masterror_template::template::{TemplateFormatter, TemplateIdentifier};
+    ///
+    /// let formatter = TemplateFormatter::from_format_spec("0width$").expect("spec");
+    ///
+    /// assert_eq!(
+    ///     formatter.width_argument(),
+    ///     Some(TemplateIdentifier::Named("width"))
+    /// );
+    /// ```
+    pub fn width_argument(&self) -> Option<TemplateIdentifier<'_>> {
+        self.display_spec()
+            .and_then(|spec| parser::parse_count_arguments(spec).width)
+    }
+
+    /// Returns the argument supplying the precision when the display spec
+    /// uses a `.prec$` or `.1$` count reference (for example
+    /// `{value:.prec$}`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use masterror_template::template::{TemplateFormatter, TemplateIdentifier};
+    ///
+    /// let formatter = TemplateFormatter::from_format_spec(">8.1$").expect("spec");
+    ///
+    /// assert_eq!(formatter.width_argument(), None);
+    /// assert_eq!(
+    ///     formatter.precision_argument(),
+    ///     Some(TemplateIdentifier::Positional(1))
+    /// );
+    /// ```
+    pub fn precision_argument(&self) -> Option<TemplateIdentifier<'_>> {
+        self.display_spec()
+            .and_then(|spec| parser::parse_count_arguments(spec).precision)
+    }
+
     /// Indicates whether a display formatter carries additional formatting
     /// parameters.
     pub fn has_display_spec(&self) -> bool {
@@ -610,12 +763,20 @@ impl fmt::Display for TemplateError {
             Self::UnmatchedClosingBrace {
                 index
             } => {
-                write!(f, "unmatched closing brace at byte {}", index)
+                write!(
+                    f,
+                    "unmatched closing brace at byte {}; use `}}}}` to render a literal `}}`",
+                    index
+                )
             }
             Self::UnterminatedPlaceholder {
                 start
             } => {
-                write!(f, "placeholder starting at byte {} is not closed", start)
+                write!(
+                    f,
+                    "placeholder starting at byte {} is not closed; add a matching `}}` or use `{{{{` to render a literal `{{`",
+                    start
+                )
             }
             Self::NestedPlaceholder {
                 index
@@ -914,6 +1075,20 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn brace_errors_suggest_escapes() {
+        let stray = ErrorTemplate::parse("a } b").expect_err("should fail");
+        assert_eq!(
+            stray.to_string(),
+            "unmatched closing brace at byte 2; use `}}` to render a literal `}`"
+        );
+        let unclosed = ErrorTemplate::parse("id {unclosed").expect_err("should fail");
+        assert_eq!(
+            unclosed.to_string(),
+            "placeholder starting at byte 3 is not closed; add a matching `}` or use `{{` to render a literal `{`"
+        );
+    }
+
     #[test]
     fn rejects_invalid_identifier() {
         let err = ErrorTemplate::parse("{invalid-name}").expect_err("should fail");
@@ -941,4 +1116,56 @@ mod tests {
         );
         assert_eq!(rendered, "418: I'm a teapot");
     }
+
+    #[test]
+    fn validate_accepts_good_template() {
+        let report = validate("{code:>width$}: {message} ({0:.1$})").expect("valid");
+        assert_eq!(report.placeholders().len(), 3);
+        assert_eq!(report.named(), BTreeSet::from(["code", "message", "width"]));
+        assert_eq!(report.positional(), BTreeSet::from([0, 1]));
+        assert_eq!(report.implicit_count(), 0);
+    }
+
+    #[test]
+    fn validate_reports_mixed_placeholders() {
+        let report = validate("{a} {0} {}").expect("valid");
+        let identifiers: Vec<_> = report
+            .placeholders()
+            .iter()
+            .map(|placeholder| placeholder.identifier().clone())
+            .collect();
+        assert_eq!(
+            identifiers,
+            [
+                named("a"),
+                TemplateIdentifier::Positional(0),
+                TemplateIdentifier::Implicit(0)
+            ]
+        );
+        assert_eq!(report.named(), BTreeSet::from(["a"]));
+        assert_eq!(report.positional(), BTreeSet::from([0]));
+        assert_eq!(report.implicit_count(), 1);
+        let spans: Vec<_> = report
+            .placeholders()
+            .iter()
+            .map(TemplatePlaceholder::span)
+            .collect();
+        assert_eq!(spans, [0..3, 4..7, 8..10]);
+    }
+
+    #[test]
+    fn validate_reports_error_offsets() {
+        assert_eq!(
+            validate("ok {a} }"),
+            Err(TemplateError::UnmatchedClosingBrace {
+                index: 7
+            })
+        );
+        assert_eq!(
+            validate("{a:%}"),
+            Err(TemplateError::InvalidFormatter {
+                span: 0..5
+            })
+        );
+    }
 }
@@ -122,7 +122,8 @@ fn build_placeholder<'a>(
             identifier,
             formatter: TemplateFormatter::Display {
                 spec: None
-            }
+            },
+            optional: None
         });
     }
     let trimmed = body.trim();
@@ -131,22 +132,27 @@ fn build_placeholder<'a>(
             start
         });
     }
-    let (identifier, formatter) = split_placeholder(trimmed, span.clone(), implicit_counter)?;
+    let (identifier, formatter, optional) =
+        split_placeholder(trimmed, span.clone(), implicit_counter)?;
     Ok(TemplatePlaceholder {
         span,
         identifier,
-        formatter
+        formatter,
+        optional
     })
 }
 
+type PlaceholderParts<'a> = (TemplateIdentifier<'a>, TemplateFormatter, Option<&'a str>);
+
 fn split_placeholder<'a>(
     body: &'a str,
     span: Range<usize>,
     implicit_counter: &mut usize
-) -> Result<(TemplateIdentifier<'a>, TemplateFormatter), TemplateError> {
+) -> Result<PlaceholderParts<'a>, TemplateError> {
     let mut parts = body.splitn(2, ':');
-    let identifier_text = parts.next().unwrap_or("").trim();
-    let identifier = parse_identifier(identifier_text, span.clone(), implicit_counter)?;
+    let (identifier_text, optional) =
+        split_optional_suffix(parts.next().unwrap_or("").trim(), &span)?;
+    let identifier = parse_identifier(identifier_text.trim(), span.clone(), implicit_counter)?;
     let formatter = match parts.next().map(str::trim) {
         None => TemplateFormatter::Display {
             spec: None
@@ -158,7 +164,26 @@ fn split_placeholder<'a>(
         }
         Some(spec) => parse_formatter(spec, span.clone())?
     };
-    Ok((identifier, formatter))
+    Ok((identifier, formatter, optional))
+}
+
+/// Splits the `?` / `?=fallback` suffix that unwraps `Option` values.
+fn split_optional_suffix<'a>(
+    text: &'a str,
+    span: &Range<usize>
+) -> Result<(&'a str, Option<&'a str>), TemplateError> {
+    let Some((identifier, suffix)) = text.split_once('?') else {
+        return Ok((text, None));
+    };
+    if suffix.is_empty() {
+        return Ok((identifier, Some("")));
+    }
+    match suffix.strip_prefix('=') {
+        Some(fallback) => Ok((identifier, Some(fallback))),
+        None => Err(TemplateError::InvalidIdentifier {
+            span: span.clone()
+        })
+    }
 }
 
 fn parse_formatter(spec: &str, span: Range<usize>) -> Result<TemplateFormatter, TemplateError> {
@@ -193,6 +218,68 @@ pub(super) fn parse_formatter_spec(spec: &str) -> Option<TemplateFormatter> {
     })
 }
 
+/// Width and precision arguments referenced by a display spec.
+pub(super) struct CountArguments<'a> {
+    pub(super) width:     Option<TemplateIdentifier<'a>>,
+    pub(super) precision: Option<TemplateIdentifier<'a>>
+}
+
+/// Extracts `name$` / `index$` count references following the
+/// `[[fill]align][sign]['#']['0'][width]['.' precision]` grammar.
+pub(super) fn parse_count_arguments(spec: &str) -> CountArguments<'_> {
+    let mut rest = skip_fill_and_align(spec);
+    if let Some(after) = rest.strip_prefix(['+', '-']) {
+        rest = after;
+    }
+    if let Some(after) = rest.strip_prefix('#') {
+        rest = after;
+    }
+    if let Some(after) = rest.strip_prefix('0')
+        && !after.starts_with('$')
+    {
+        rest = after;
+    }
+    let (width, rest) = parse_count_reference(rest);
+    let precision = rest
+        .strip_prefix('.')
+        .and_then(|after| parse_count_reference(after).0);
+    CountArguments {
+        width,
+        precision
+    }
+}
+
+fn skip_fill_and_align(spec: &str) -> &str {
+    let mut chars = spec.char_indices();
+    if let (Some(_), Some((index, align))) = (chars.next(), chars.next())
+        && matches!(align, '<' | '>' | '^')
+    {
+        return &spec[index + align.len_utf8()..];
+    }
+    match spec.chars().next() {
+        Some(align @ ('<' | '>' | '^')) => &spec[align.len_utf8()..],
+        _ => spec
+    }
+}
+
+fn parse_count_reference(text: &str) -> (Option<TemplateIdentifier<'_>>, &str) {
+    let end = text
+        .find(|ch: char| ch != '_' && !ch.is_ascii_alphanumeric())
+        .unwrap_or(text.len());
+    let (candidate, rest) = text.split_at(end);
+    match rest.strip_prefix('$') {
+        Some(after) if !candidate.is_empty() => {
+            let identifier = if candidate.bytes().all(|byte| byte.is_ascii_digit()) {
+                candidate.parse().ok().map(TemplateIdentifier::Positional)
+            } else {
+                Some(TemplateIdentifier::Named(candidate))
+            };
+            (identifier, after)
+        }
+        _ => (None, rest)
+    }
+}
+
 fn detect_alternate_flag(prefix: &str) -> Option<bool> {
     let mut rest = prefix;
     if rest.len() >= 2 {
@@ -555,6 +642,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_optional_suffix_and_fallback() {
+        let cases = [
+            ("{user?}", TemplateIdentifier::Named("user"), Some(""), None),
+            (
+                "{user?=n/a}",
+                TemplateIdentifier::Named("user"),
+                Some("n/a"),
+                None
+            ),
+            (
+                "{0?=-:>4}",
+                TemplateIdentifier::Positional(0),
+                Some("-"),
+                Some(">4")
+            ),
+            ("{?}", TemplateIdentifier::Implicit(0), Some(""), None),
+            ("{user}", TemplateIdentifier::Named("user"), None, None)
+        ];
+        for (source, identifier, fallback, spec) in cases {
+            let segments = parse_template(source).expect("template parsed");
+            let placeholder = match segments.first() {
+                Some(TemplateSegment::Placeholder(placeholder)) => placeholder,
+                other => panic!("unexpected segments for {source:?}: {other:?}")
+            };
+            assert_eq!(placeholder.identifier(), &identifier, "case: {source}");
+            assert_eq!(placeholder.optional_fallback(), fallback, "case: {source}");
+            assert_eq!(
+                placeholder.formatter().display_spec(),
+                spec,
+                "case: {source}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_optional_suffix() {
+        for source in ["{user?x}", "{us?er}", "{user??}"] {
+            let err = parse_template(source).expect_err("expected identifier error");
+            assert!(
+                matches!(err, TemplateError::InvalidIdentifier { .. }),
+                "case: {source}"
+            );
+        }
+    }
+
     #[test]
     fn rejects_whitespace_only_placeholders() {
         let err = parse_template("{   }").expect_err("should fail");
@@ -565,4 +698,30 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn parses_count_argument_references() {
+        let cases = [
+            ("0width$", Some(TemplateIdentifier::Named("width")), None),
+            (".prec$", None, Some(TemplateIdentifier::Named("prec"))),
+            (
+                "*>+#0pad$.digits$",
+                Some(TemplateIdentifier::Named("pad")),
+                Some(TemplateIdentifier::Named("digits"))
+            ),
+            ("0$", Some(TemplateIdentifier::Positional(0)), None),
+            (
+                ">1$.2$",
+                Some(TemplateIdentifier::Positional(1)),
+                Some(TemplateIdentifier::Positional(2))
+            ),
+            ("08.3", None, None),
+            (">8", None, None)
+        ];
+        for (spec, width, precision) in cases {
+            let counts = parse_count_arguments(spec);
+            assert_eq!(counts.width, width, "width of {spec:?}");
+            assert_eq!(counts.precision, precision, "precision of {spec:?}");
+        }
+    }
 }
@@ -70,20 +70,39 @@
 //! Constructors and framework integrations call it automatically, so manual
 //! usage is rarely required.
 
+mod conflict;
 mod constructors;
 mod context;
 mod core;
+#[cfg(feature = "serde_json")]
+mod flatten;
 mod inline_vec;
 mod metadata;
+mod metadata_limit;
 pub(crate) mod redaction;
+mod retry_after;
+#[cfg(feature = "std")]
+mod sampling;
 
-pub use core::{AppError, AppResult, DisplayMode, Error, ErrorChain, MessageEditPolicy};
+pub use core::{
+    AppError, AppErrorParts, AppResult, DisplayMode, Error, ErrorChain, ErrorDisplay,
+    MessageEditPolicy, StringError
+};
 #[cfg(all(test, feature = "backtrace"))]
 pub(crate) use core::{reset_backtrace_preference, set_backtrace_preference_override};
 
+pub use conflict::ConflictReason;
 pub use context::Context;
-pub(crate) use metadata::duration_to_string;
-pub use metadata::{Field, FieldRedaction, FieldValue, Metadata, field};
+#[cfg(feature = "serde_json")]
+pub(crate) use flatten::flatten_into;
+#[cfg(feature = "span-fields")]
+pub(crate) use metadata::intern_name;
+pub use metadata::{Field, FieldRedaction, FieldValue, Metadata, MetadataDiff, field};
+pub(crate) use metadata::{duration_to_string, non_finite_label};
+pub use metadata_limit::{MetadataLimit, MetadataOverflow, metadata_limit, set_metadata_limit};
+pub(crate) use retry_after::retry_after_seconds;
+#[cfg(feature = "std")]
+pub use sampling::{ErrorSampler, EveryNth, error_sampler, set_error_sampler};
 
 #[cfg(test)]
 mod tests;
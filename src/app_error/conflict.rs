@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Sub-reasons for [`AppErrorKind::Conflict`](crate::AppErrorKind::Conflict)
+//! errors.
+//!
+//! The reason travels as the `conflict.reason` metadata field, so the coarse
+//! `Conflict` kind and its HTTP mapping stay unchanged while clients can still
+//! branch on the cause. Use [`AppError::conflict_reason`] to build such an
+//! error and [`AppError::conflict_cause`] to read the reason back.
+//!
+//! [`AppError::conflict_reason`]: crate::AppError::conflict_reason
+//! [`AppError::conflict_cause`]: crate::AppError::conflict_cause
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Metadata key holding the [`ConflictReason`] of a conflict error.
+pub(crate) const CONFLICT_REASON_KEY: &str = "conflict.reason";
+
+/// Why a request conflicts with the current state of a resource.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::ConflictReason;
+///
+/// assert_eq!(ConflictReason::VersionMismatch.as_str(), "version_mismatch");
+/// assert_eq!(
+///     ConflictReason::from_name("duplicate"),
+///     Some(ConflictReason::Duplicate)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConflictReason {
+    /// The client's version or ETag no longer matches the stored one.
+    VersionMismatch,
+    /// A resource with the same unique key already exists.
+    Duplicate,
+    /// The resource is in a state that does not allow the operation.
+    StateConflict
+}
+
+impl ConflictReason {
+    /// Stable snake_case name stored in the `conflict.reason` field.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::VersionMismatch => "version_mismatch",
+            Self::Duplicate => "duplicate",
+            Self::StateConflict => "state_conflict"
+        }
+    }
+
+    /// Parse a reason from its [`as_str`](Self::as_str) name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "version_mismatch" => Some(Self::VersionMismatch),
+            "duplicate" => Some(Self::Duplicate),
+            "state_conflict" => Some(Self::StateConflict),
+            _ => None
+        }
+    }
+}
+
+impl Display for ConflictReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
@@ -20,11 +20,20 @@
 //! enabling flexible message construction from string literals, owned strings,
 //! or pre-built `Cow` instances.
 
-use alloc::{borrow::Cow, boxed::Box};
+use alloc::{borrow::Cow, boxed::Box, format, vec::Vec};
 use core::error::Error as CoreError;
 
-use super::core::AppError;
+#[cfg(feature = "serde_json")]
+use serde_json::{Value as JsonValue, json};
+
+use super::{
+    conflict::{CONFLICT_REASON_KEY, ConflictReason},
+    core::AppError,
+    metadata::field
+};
 use crate::AppErrorKind;
+#[cfg(feature = "serde_json")]
+use crate::MessageEditPolicy;
 
 impl AppError {
     // --- Canonical constructors (keep in sync with AppErrorKind) -------------
@@ -90,6 +99,23 @@ impl AppError {
         Self::with(AppErrorKind::Conflict, msg)
     }
 
+    /// Build a `Conflict` error tagged with a [`ConflictReason`].
+    ///
+    /// The reason is stored as the `conflict.reason` metadata field and can be
+    /// read back with [`conflict_cause`](Self::conflict_cause).
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind, ConflictReason};
+    ///
+    /// let err = AppError::conflict_reason("stale revision", ConflictReason::VersionMismatch);
+    /// assert_eq!(err.kind, AppErrorKind::Conflict);
+    /// assert_eq!(err.conflict_cause(), Some(ConflictReason::VersionMismatch));
+    /// ```
+    pub fn conflict_reason(msg: impl Into<Cow<'static, str>>, reason: ConflictReason) -> Self {
+        Self::with(AppErrorKind::Conflict, msg)
+            .with_field(field::str(CONFLICT_REASON_KEY, reason.as_str()))
+    }
+
     /// Build a `BadRequest` error.
     ///
     /// ```rust
@@ -102,6 +128,61 @@ impl AppError {
         Self::with(AppErrorKind::BadRequest, msg)
     }
 
+    /// Build a `MethodNotAllowed` error listing the `allowed` methods.
+    ///
+    /// The methods are stored through [`with_allow`](Self::with_allow) and
+    /// emitted by the Axum, Actix and Tower responders as the `Allow` header.
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    ///
+    /// let err = AppError::method_not_allowed(&["GET", "HEAD"]);
+    /// assert_eq!(err.kind, AppErrorKind::MethodNotAllowed);
+    /// assert_eq!(err.allow.as_deref(), Some("GET, HEAD"));
+    /// ```
+    pub fn method_not_allowed(allowed: &[&str]) -> Self {
+        Self::bare(AppErrorKind::MethodNotAllowed).with_allow(allowed)
+    }
+
+    /// Build a `PayloadTooLarge` error.
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    ///
+    /// let err = AppError::payload_too_large("body exceeds 1 MiB");
+    /// assert_eq!(err.kind, AppErrorKind::PayloadTooLarge);
+    /// assert_eq!(err.kind.http_status(), 413);
+    /// ```
+    pub fn payload_too_large(msg: impl Into<Cow<'static, str>>) -> Self {
+        Self::with(AppErrorKind::PayloadTooLarge, msg)
+    }
+
+    /// Build an `UnsupportedMediaType` error.
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    ///
+    /// let err = AppError::unsupported_media_type("expected application/json");
+    /// assert_eq!(err.kind, AppErrorKind::UnsupportedMediaType);
+    /// assert_eq!(err.kind.http_status(), 415);
+    /// ```
+    pub fn unsupported_media_type(msg: impl Into<Cow<'static, str>>) -> Self {
+        Self::with(AppErrorKind::UnsupportedMediaType, msg)
+    }
+
+    /// Build a `Locked` error.
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    ///
+    /// let err = AppError::locked("document is being edited");
+    /// assert_eq!(err.kind, AppErrorKind::Locked);
+    /// assert_eq!(err.kind.http_status(), 423);
+    /// ```
+    pub fn locked(msg: impl Into<Cow<'static, str>>) -> Self {
+        Self::with(AppErrorKind::Locked, msg)
+    }
+
     /// Build a `RateLimited` error.
     ///
     /// ```rust
@@ -114,6 +195,19 @@ impl AppError {
         Self::with(AppErrorKind::RateLimited, msg)
     }
 
+    /// Build a `RateLimited` error advising a retry after `secs` seconds.
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    ///
+    /// let err = AppError::rate_limited_after("quota exhausted", 30);
+    /// assert_eq!(err.kind, AppErrorKind::RateLimited);
+    /// assert_eq!(err.retry.map(|r| r.after_seconds), Some(30));
+    /// ```
+    pub fn rate_limited_after(msg: impl Into<Cow<'static, str>>, secs: u64) -> Self {
+        Self::rate_limited(msg).with_retry_after_secs(secs)
+    }
+
     /// Build a `TelegramAuth` error.
     ///
     /// ```rust
@@ -354,4 +448,91 @@ impl AppError {
     ) -> Self {
         Self::bare(kind).with_boxed_source(source)
     }
+
+    /// Combine several failures (e.g. from concurrently joined tasks) into a
+    /// single error.
+    ///
+    /// The most severe error wins: errors whose effective
+    /// [`status`](Self::status) is server-side (5xx) outrank client-side (4xx)
+    /// ones and ties go to the earliest error. The result takes the winner's
+    /// kind, keeping a `with_status` override when it has one. It carries an
+    /// `errors.count` metadata field and, with the `serde_json` feature, an
+    /// `errors` array in `details` listing each sub-error's `status`, `code`
+    /// and public `message`. Redactable sub-errors contribute their kind label
+    /// instead of the message. Empty input yields a bare `Internal` error.
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind, FieldValue};
+    ///
+    /// let err = AppError::aggregate(vec![
+    ///     AppError::not_found("missing user"),
+    ///     AppError::service("connection reset"),
+    ///     AppError::timeout("upstream slow"),
+    /// ]);
+    /// assert_eq!(err.kind, AppErrorKind::Service);
+    /// assert_eq!(err.message.as_deref(), Some("3 errors occurred"));
+    /// assert_eq!(
+    ///     err.metadata().get("errors.count"),
+    ///     Some(&FieldValue::U64(3))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn aggregate(errors: Vec<AppError>) -> Self {
+        let count = errors.len() as u64;
+        let Some((kind, status)) = most_severe(&errors) else {
+            return Self::bare(AppErrorKind::Internal).with_field(field::u64("errors.count", 0));
+        };
+        let message = if count == 1 {
+            Cow::Borrowed("1 error occurred")
+        } else {
+            Cow::Owned(format!("{count} errors occurred"))
+        };
+        let mut err = Self::with(kind, message).with_field(field::u64("errors.count", count));
+        if status != kind.http_status() {
+            err = err.with_status(status);
+        }
+        #[cfg(feature = "serde_json")]
+        let err = err.with_details_json(aggregate_details(&errors));
+        err
+    }
+}
+
+/// Pick the kind and effective status of the most severe error, preferring
+/// the earliest on ties.
+fn most_severe(errors: &[AppError]) -> Option<(AppErrorKind, u16)> {
+    errors
+        .iter()
+        .map(|error| (error.kind, error.status()))
+        .reduce(|current, candidate| {
+            if severity_rank(candidate.1) > severity_rank(current.1) {
+                candidate
+            } else {
+                current
+            }
+        })
+}
+
+/// Rank statuses by class so server errors outrank client errors.
+const fn severity_rank(status: u16) -> u16 {
+    status / 100
+}
+
+#[cfg(feature = "serde_json")]
+fn aggregate_details(errors: &[AppError]) -> JsonValue {
+    let entries = errors
+        .iter()
+        .map(|error| {
+            let message = if matches!(error.edit_policy, MessageEditPolicy::Redact) {
+                Cow::Borrowed(error.kind.label())
+            } else {
+                error.render_message()
+            };
+            json!({
+                "status": error.status(),
+                "code": error.code.as_str(),
+                "message": message
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({ "errors": entries })
 }
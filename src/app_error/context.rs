@@ -230,7 +230,9 @@ impl Context {
         error.code = code;
         if !fields.is_empty() {
             Self::apply_field_redactions(&mut fields, &field_policies);
-            error.metadata.extend(fields);
+            for field in fields {
+                error.metadata.insert_limited(field);
+            }
         } else if !field_policies.is_empty() {
             for &(name, redaction) in &field_policies {
                 error = error.redact_field(name, redaction);
@@ -36,6 +36,13 @@ pub mod error;
 /// - Metadata access
 pub mod introspection;
 
+/// Exhaustive destructuring into and reconstruction from components.
+///
+/// Provides [`AppErrorParts`] together with `Error::into_parts` and
+/// `Error::from_parts` for adapters that map errors to and from external
+/// representations.
+pub mod parts;
+
 /// Telemetry integration (metrics and tracing).
 ///
 /// Handles emission of metrics and tracing events when errors are created
@@ -64,9 +71,10 @@ pub mod display;
 
 #[cfg(all(test, feature = "backtrace"))]
 pub use backtrace::{reset_backtrace_preference, set_backtrace_preference_override};
-pub use display::DisplayMode;
+pub use display::{DisplayMode, ErrorDisplay};
 pub use error::{AppError, AppResult, Error};
-pub use types::{ErrorChain, MessageEditPolicy};
+pub use parts::AppErrorParts;
+pub use types::{ErrorChain, MessageEditPolicy, StringError};
 
 #[cfg(test)]
 mod tests {
@@ -117,6 +125,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn error_with_help_is_independent_of_message() {
+        let err = Error::new(AppErrorKind::Unauthorized, "token expired")
+            .with_help("Try regenerating your token.");
+        assert_eq!(err.help(), Some("Try regenerating your token."));
+        assert_eq!(err.message.as_deref(), Some("token expired"));
+        let err = Error::bare(AppErrorKind::Unauthorized).with_help("Log in again.");
+        assert_eq!(err.help(), Some("Log in again."));
+        assert!(err.message.is_none());
+    }
+
     #[test]
     fn error_with_field_adds_metadata() {
         use crate::field;
@@ -143,6 +162,71 @@ mod tests {
         assert_eq!(err.edit_policy, MessageEditPolicy::Redact);
     }
 
+    #[test]
+    fn error_status_class_client_kinds() {
+        for kind in [
+            AppErrorKind::NotFound,
+            AppErrorKind::Validation,
+            AppErrorKind::Unauthorized,
+            AppErrorKind::PayloadTooLarge,
+            AppErrorKind::RateLimited
+        ] {
+            let err = Error::bare(kind);
+            assert!(err.is_client_error(), "{kind:?}");
+            assert!(!err.is_server_error(), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn error_status_class_server_kinds() {
+        for kind in [
+            AppErrorKind::Internal,
+            AppErrorKind::NotImplemented,
+            AppErrorKind::DependencyUnavailable,
+            AppErrorKind::Timeout,
+            AppErrorKind::Database
+        ] {
+            let err = Error::bare(kind);
+            assert!(err.is_server_error(), "{kind:?}");
+            assert!(!err.is_client_error(), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn error_context_str_prepends_to_message() {
+        let err =
+            Error::new(AppErrorKind::NotFound, "user missing").context_str("loading profile");
+        assert_eq!(
+            err.message.as_deref(),
+            Some("loading profile: user missing")
+        );
+    }
+
+    #[test]
+    fn error_context_str_uses_label_for_bare_error() {
+        let err = Error::bare(AppErrorKind::Timeout).context_str("fetching quotes");
+        assert_eq!(
+            err.message.as_deref(),
+            Some("fetching quotes: Operation timed out")
+        );
+    }
+
+    #[test]
+    fn error_context_str_preserves_kind_and_code() {
+        use crate::AppCode;
+        let err = Error::new(AppErrorKind::Conflict, "duplicate")
+            .with_code(AppCode::UserAlreadyExists)
+            .context_str("registering user");
+        assert_eq!(err.kind, AppErrorKind::Conflict);
+        assert_eq!(err.code, AppCode::UserAlreadyExists);
+    }
+
+    #[test]
+    fn error_context_str_ignores_empty_context() {
+        let err = Error::bare(AppErrorKind::Internal).context_str("");
+        assert!(err.message.is_none());
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn error_with_source_attaches_source() {
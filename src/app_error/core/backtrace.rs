@@ -2,11 +2,13 @@
 //
 // SPDX-License-Identifier: MIT
 
+#[cfg(feature = "backtrace")]
+use alloc::string::String;
 #[cfg(feature = "backtrace")]
 use alloc::sync::Arc;
 #[cfg(feature = "backtrace")]
 use std::{
-    backtrace::Backtrace,
+    backtrace::{Backtrace, BacktraceStatus},
     env,
     sync::atomic::{AtomicU8, Ordering as AtomicOrdering}
 };
@@ -21,6 +23,10 @@ const BACKTRACE_STATE_DISABLED: u8 = 2;
 #[cfg(feature = "backtrace")]
 static BACKTRACE_STATE: AtomicU8 = AtomicU8::new(BACKTRACE_STATE_UNSET);
 
+/// Symbol prefixes treated as noise when rendering filtered backtraces.
+#[cfg(feature = "backtrace")]
+const NOISE_FRAME_PREFIXES: [&str; 4] = ["std::", "core::", "alloc::", "masterror::"];
+
 /// Captures a backtrace snapshot if enabled by environment configuration.
 ///
 /// Returns `Some(Arc<Backtrace>)` if backtrace capture is enabled via
@@ -87,6 +93,57 @@ fn detect_backtrace_preference() -> bool {
     }
 }
 
+/// Renders a captured backtrace without standard library and crate frames.
+///
+/// Returns `None` when the backtrace is disabled or unsupported on the
+/// current platform. Frame indices are kept as captured so filtered output
+/// can still be correlated with the full trace.
+#[cfg(feature = "backtrace")]
+pub(crate) fn render_filtered_backtrace(backtrace: &Backtrace) -> Option<String> {
+    if backtrace.status() != BacktraceStatus::Captured {
+        return None;
+    }
+    Some(filter_backtrace_frames(&backtrace.to_string()))
+}
+
+/// Drops frames whose symbol belongs to `std`, `core`, `alloc` or this crate.
+///
+/// Location lines (`at file:line`) follow the decision made for the frame
+/// header they belong to.
+#[cfg(feature = "backtrace")]
+fn filter_backtrace_frames(rendered: &str) -> String {
+    let mut filtered = String::with_capacity(rendered.len());
+    let mut keep = true;
+    for line in rendered.lines() {
+        if let Some(symbol) = frame_symbol(line) {
+            keep = !is_noise_frame(symbol);
+        }
+        if keep {
+            filtered.push_str(line);
+            filtered.push('\n');
+        }
+    }
+    filtered
+}
+
+/// Extracts the symbol from a `"  12: path::to::symbol"` frame header line.
+#[cfg(feature = "backtrace")]
+fn frame_symbol(line: &str) -> Option<&str> {
+    let (index, symbol) = line.trim_start().split_once(": ")?;
+    if index.is_empty() || !index.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    Some(symbol)
+}
+
+#[cfg(feature = "backtrace")]
+fn is_noise_frame(symbol: &str) -> bool {
+    let symbol = symbol.trim_start_matches('<');
+    NOISE_FRAME_PREFIXES
+        .iter()
+        .any(|prefix| symbol.starts_with(prefix))
+}
+
 /// Resets the backtrace preference cache to uninitialized state.
 ///
 /// This function is only available in test builds with the `backtrace` feature.
@@ -204,6 +261,44 @@ mod tests {
         let _ = result;
     }
 
+    #[test]
+    fn filter_frames_drops_std_core_and_crate_noise() {
+        let rendered = [
+            "   0: masterror::app_error::core::error::Error::new_raw",
+            "             at ./src/app_error/core/error.rs:10:5",
+            "   1: app::handlers::load_user",
+            "             at ./src/handlers.rs:42:9",
+            "   2: <alloc::boxed::Box<F> as core::ops::function::FnOnce<A>>::call_once",
+            "   3: std::rt::lang_start",
+            "   4: main"
+        ]
+        .join("\n");
+        let filtered = filter_backtrace_frames(&rendered);
+        assert!(filtered.contains("app::handlers::load_user"));
+        assert!(filtered.contains("at ./src/handlers.rs:42:9"));
+        assert!(filtered.contains("4: main"));
+        assert!(!filtered.contains("masterror::"));
+        assert!(!filtered.contains("error.rs"));
+        assert!(!filtered.contains("call_once"));
+        assert!(!filtered.contains("lang_start"));
+    }
+
+    #[test]
+    fn backtrace_string_renders_attached_backtrace() {
+        use crate::AppError;
+        let err = AppError::internal("boom").with_backtrace(Backtrace::force_capture());
+        let rendered = err.backtrace_string().expect("captured backtrace");
+        assert!(!rendered.contains("std::rt::lang_start"));
+        assert!(!rendered.contains("masterror::"));
+    }
+
+    #[test]
+    fn backtrace_string_is_none_for_disabled_backtrace() {
+        use crate::AppError;
+        let err = AppError::internal("boom").with_backtrace(Backtrace::disabled());
+        assert!(err.backtrace_string().is_none());
+    }
+
     #[test]
     fn reset_clears_state_and_override() {
         set_backtrace_preference_override(Some(true));
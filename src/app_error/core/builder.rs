@@ -3,24 +3,41 @@
 // SPDX-License-Identifier: MIT
 
 use alloc::{borrow::Cow, boxed::Box, string::String, sync::Arc};
-use core::error::Error as CoreError;
+use core::{error::Error as CoreError, time::Duration};
 #[cfg(feature = "backtrace")]
 use std::backtrace::Backtrace;
+#[cfg(feature = "std")]
+use std::time::Instant;
 
 #[cfg(feature = "serde_json")]
 use serde::Serialize;
 #[cfg(feature = "serde_json")]
-use serde_json::{Value as JsonValue, to_value};
+use serde_json::{Map as JsonMap, Value as JsonValue, to_value};
+#[cfg(feature = "tracing")]
+use tracing::Span;
+use uuid::Uuid;
 
 use super::{
     error::Error,
-    types::{CapturedBacktrace, ContextAttachment, MessageEditPolicy, StoredSource}
+    types::{CapturedBacktrace, ContextAttachment, MessageEditPolicy, StoredSource, StringError}
 };
 use crate::{
-    AppCode, AppErrorKind, RetryAdvice,
-    app_error::metadata::{Field, FieldRedaction, Metadata}
+    AppCode, AppErrorKind, RetryAdvice, Severity,
+    app_error::{
+        metadata::{
+            Field, FieldRedaction, FieldValue, Metadata, OPERATION_KEY, TENANT_ID_KEY, field
+        },
+        retry_after_seconds
+    },
+    metadata_limit
 };
 
+/// Custom labels accepted by [`Error::with_metric_label`].
+const MAX_METRIC_LABELS: usize = 4;
+
+/// Labels always set by the `error_total` counter.
+const RESERVED_METRIC_LABELS: [&str; 3] = ["code", "category", OPERATION_KEY];
+
 impl Error {
     /// Create a new [`Error`] with a kind and message.
     ///
@@ -76,6 +93,32 @@ impl Error {
         err
     }
 
+    /// Create a message-less error stamped with the time elapsed since
+    /// `start`.
+    ///
+    /// Shorthand for [`Error::bare`] followed by
+    /// [`with_elapsed`](Self::with_elapsed)`(start.elapsed())`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Instant;
+    ///
+    /// use masterror::{AppError, AppErrorKind, FieldValue};
+    ///
+    /// let start = Instant::now();
+    /// let err = AppError::from_elapsed(AppErrorKind::Timeout, start);
+    /// assert!(matches!(
+    ///     err.metadata().get("elapsed"),
+    ///     Some(FieldValue::Duration(_))
+    /// ));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_elapsed(kind: AppErrorKind, start: Instant) -> Self {
+        Self::bare(kind).with_elapsed(start.elapsed())
+    }
+
     /// Override the machine-readable [`AppCode`].
     ///
     /// # Examples
@@ -92,6 +135,57 @@ impl Error {
         self
     }
 
+    /// Reclassify the error under a different [`AppErrorKind`].
+    ///
+    /// Only the kind changes: the code, message, metadata and source stay as
+    /// they are. Transport status codes follow the kind, so the HTTP status
+    /// of the rendered response changes accordingly. Pair with
+    /// [`with_code`](Self::with_code) when the code should change too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppCode, AppError, AppErrorKind};
+    ///
+    /// let err = AppError::service("pool exhausted")
+    ///     .with_code(AppCode::new("DB_POOL_EXHAUSTED"))
+    ///     .with_kind(AppErrorKind::DependencyUnavailable);
+    /// assert_eq!(err.kind, AppErrorKind::DependencyUnavailable);
+    /// assert_eq!(err.code.as_str(), "DB_POOL_EXHAUSTED");
+    /// ```
+    #[must_use]
+    pub fn with_kind(mut self, kind: AppErrorKind) -> Self {
+        self.kind = kind;
+        self.mark_dirty();
+        self
+    }
+
+    /// Reclassify the error from an upstream HTTP status.
+    ///
+    /// The kind is resolved via [`TryFrom<u16>`] for [`AppErrorKind`] and
+    /// falls back to [`AppErrorKind::Internal`] for statuses outside the
+    /// taxonomy. The raw status is recorded as `upstream.status`; message,
+    /// code and existing metadata are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind, FieldValue};
+    ///
+    /// let err = AppError::service("billing lookup failed").with_category_from_status(404);
+    /// assert_eq!(err.kind, AppErrorKind::NotFound);
+    /// assert_eq!(
+    ///     err.metadata().get("upstream.status"),
+    ///     Some(&FieldValue::U64(404))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_category_from_status(self, status: u16) -> Self {
+        let kind = AppErrorKind::try_from(status).unwrap_or(AppErrorKind::Internal);
+        self.with_kind(kind)
+            .with_field(field::u64("upstream.status", u64::from(status)))
+    }
+
     /// Attach retry advice to the error.
     ///
     /// When mapped to HTTP, this becomes the `Retry-After` header.
@@ -112,6 +206,33 @@ impl Error {
         self
     }
 
+    /// Attach retry advice parsed from an upstream `Retry-After` header value.
+    ///
+    /// Accepts a number of seconds or an HTTP-date in any RFC 9110 form
+    /// (`Sun, 06 Nov 1994 08:49:37 GMT`, the RFC 850 and asctime variants),
+    /// which is converted into the delay from now; dates in the past yield
+    /// zero seconds. Dates require the `std` feature. Values
+    /// that cannot be parsed leave the error unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::service("upstream throttled").with_retry_after_header("30");
+    /// assert_eq!(err.retry.map(|r| r.after_seconds), Some(30));
+    ///
+    /// let err = AppError::service("upstream throttled").with_retry_after_header("soon");
+    /// assert!(err.retry.is_none());
+    /// ```
+    #[must_use]
+    pub fn with_retry_after_header(self, value: &str) -> Self {
+        match retry_after_seconds(value) {
+            Some(secs) => self.with_retry_after_secs(secs),
+            None => self
+        }
+    }
+
     /// Attach a `WWW-Authenticate` challenge string.
     ///
     /// # Examples
@@ -129,8 +250,200 @@ impl Error {
         self
     }
 
+    /// Attach the methods supported by the target resource.
+    ///
+    /// When mapped to HTTP, this becomes the `Allow` header. It is kept apart
+    /// from metadata, so redaction never removes it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    /// let err = AppError::bare(AppErrorKind::MethodNotAllowed).with_allow(&["GET", "HEAD"]);
+    /// assert_eq!(err.allow.as_deref(), Some("GET, HEAD"));
+    /// ```
+    #[must_use]
+    pub fn with_allow(mut self, methods: &[&str]) -> Self {
+        self.allow = Some(methods.join(", "));
+        self.mark_dirty();
+        self
+    }
+
+    /// Attach an RFC 6750 `Bearer` challenge as the `WWW-Authenticate` value.
+    ///
+    /// Present parameters are emitted in the order `realm`, `error`,
+    /// `error_description` as quoted strings with `"` and `\` escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::unauthorized("token expired").with_bearer_challenge(
+    ///     Some("api"),
+    ///     Some("invalid_token"),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     err.www_authenticate.as_deref(),
+    ///     Some(r#"Bearer realm="api", error="invalid_token""#)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_bearer_challenge(
+        self,
+        realm: Option<&str>,
+        error: Option<&str>,
+        error_description: Option<&str>
+    ) -> Self {
+        let mut challenge = String::from("Bearer");
+        let params = [
+            ("realm", realm),
+            ("error", error),
+            ("error_description", error_description)
+        ];
+        for (index, (name, value)) in params
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .enumerate()
+        {
+            challenge.push_str(if index == 0 { " " } else { ", " });
+            challenge.push_str(name);
+            challenge.push_str("=\"");
+            for ch in value.chars() {
+                if matches!(ch, '"' | '\\') {
+                    challenge.push('\\');
+                }
+                challenge.push(ch);
+            }
+            challenge.push('"');
+        }
+        self.with_www_authenticate(challenge)
+    }
+
+    /// Attach a user-facing remediation hint.
+    ///
+    /// Unlike the message, the hint is treated as client-safe: it is exposed
+    /// as the `help` member of [`ProblemJson`](crate::ProblemJson) and on a
+    /// `Help:` line of the local display even when the error is redactable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::unauthorized("token expired").with_help("Try regenerating your token.");
+    /// assert_eq!(err.help(), Some("Try regenerating your token."));
+    /// ```
+    #[must_use]
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self.mark_dirty();
+        self
+    }
+
+    /// Set the client language used to localize generic labels.
+    ///
+    /// [`ProblemJson`](crate::ProblemJson) renders its `title`, and
+    /// [`ErrorResponse`](crate::ErrorResponse) its fallback message for bare
+    /// or redacted errors, through
+    /// [`AppErrorKind::localized_label`](crate::AppErrorKind::localized_label).
+    /// Messages supplied by the caller are never translated, and internal
+    /// renderings such as [`render_message`](Self::render_message), `Debug`
+    /// and [`context_str`](Self::context_str) keep the English label.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind, ErrorResponse};
+    ///
+    /// let err = AppError::bare(AppErrorKind::NotFound).with_locale("ru");
+    /// assert_eq!(err.locale(), Some("ru"));
+    /// assert_eq!(ErrorResponse::from(err).message, "Не найдено");
+    /// ```
+    #[must_use]
+    pub fn with_locale(mut self, lang: &str) -> Self {
+        self.locale = Some(String::from(lang));
+        self
+    }
+
+    /// Attach a sanitized message presented to clients.
+    ///
+    /// Client-facing conversions ([`ErrorResponse`](crate::ErrorResponse),
+    /// [`ProblemJson`](crate::ProblemJson) and, with the `tonic` feature,
+    /// `tonic::Status`) render it in place of the message, even when the error
+    /// is redactable. Logs and every [`DisplayMode`](crate::DisplayMode) keep
+    /// the detailed internal message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, ErrorResponse};
+    ///
+    /// let err = AppError::database_with_message("deadlock on table `orders` (tx 8812)")
+    ///     .with_public_message("Please try again.");
+    /// assert_eq!(err.public_message(), Some("Please try again."));
+    /// let resp = ErrorResponse::from(&err);
+    /// assert_eq!(resp.message, "Please try again.");
+    /// ```
+    #[must_use]
+    pub fn with_public_message(mut self, message: impl Into<String>) -> Self {
+        self.public_message = Some(message.into());
+        self.mark_dirty();
+        self
+    }
+
+    /// Override the [`Severity`] derived from the error kind.
+    ///
+    /// With the `tracing` feature the severity selects the level of the event
+    /// emitted by [`log`](Self::log).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, Severity};
+    ///
+    /// let err = AppError::not_found("probe for /healthz").with_severity(Severity::Debug);
+    /// assert_eq!(err.severity(), Severity::Debug);
+    /// ```
+    #[must_use]
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self.mark_dirty();
+        self
+    }
+
+    /// Override the HTTP status derived from the error kind.
+    ///
+    /// [`ErrorResponse`](crate::ErrorResponse),
+    /// [`ProblemJson`](crate::ProblemJson) and the Axum/Actix responders use
+    /// it instead of [`AppErrorKind::http_status`]. The kind, code and
+    /// severity are unchanged. Values outside `100..=599` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, ErrorResponse};
+    ///
+    /// let err = AppError::validation("item 3 rejected").with_status(200);
+    /// assert_eq!(err.status(), 200);
+    /// assert_eq!(ErrorResponse::from(&err).status, 200);
+    /// assert_eq!(AppError::validation("x").with_status(1000).status(), 422);
+    /// ```
+    #[must_use]
+    pub fn with_status(mut self, status: u16) -> Self {
+        if (100..600).contains(&status) {
+            self.status = Some(status);
+            self.mark_dirty();
+        }
+        self
+    }
+
     /// Attach additional metadata to the error.
     ///
+    /// Honors the process-wide [`MetadataLimit`](crate::MetadataLimit) when
+    /// one is configured via [`set_metadata_limit`](crate::set_metadata_limit).
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -141,11 +454,34 @@ impl Error {
     /// ```
     #[must_use]
     pub fn with_field(mut self, field: Field) -> Self {
-        self.metadata.insert(field);
+        self.metadata.insert_limited(field);
         self.mark_dirty();
         self
     }
 
+    /// Reserve room for `additional` more metadata fields.
+    ///
+    /// Handlers that always attach more than four fields can call this first
+    /// so the metadata spills to the heap once, sized for every field, rather
+    /// than reallocating halfway through the builder chain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, field};
+    ///
+    /// let err = AppError::service("checkout failed")
+    ///     .with_metadata_capacity(6)
+    ///     .with_field(field::str("order_id", "o-1"))
+    ///     .with_field(field::u64("items", 3));
+    /// assert_eq!(err.metadata().len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_metadata_capacity(mut self, additional: usize) -> Self {
+        self.metadata.reserve(additional);
+        self
+    }
+
     /// Extend metadata from an iterator of fields.
     ///
     /// # Examples
@@ -158,11 +494,231 @@ impl Error {
     /// ```
     #[must_use]
     pub fn with_fields(mut self, fields: impl IntoIterator<Item = Field>) -> Self {
-        self.metadata.extend(fields);
+        for field in fields {
+            self.metadata.insert_limited(field);
+        }
+        self.mark_dirty();
+        self
+    }
+
+    /// Attach a request identifier as the `request_id` metadata field.
+    ///
+    /// The UUID is stored as [`FieldValue::Uuid`], so it renders in the
+    /// canonical hyphenated form without an intermediate `to_string()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, FieldValue};
+    /// use uuid::Uuid;
+    ///
+    /// let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+    /// let err = AppError::service("upstream timeout").with_request_id(id);
+    /// assert_eq!(
+    ///     err.metadata().get("request_id"),
+    ///     Some(&FieldValue::Uuid(id))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_request_id(mut self, id: Uuid) -> Self {
+        self.metadata
+            .insert_limited(crate::field::uuid("request_id", id));
+        self.mark_dirty();
+        self
+    }
+
+    /// Tag the error with the tenant it occurred for, stored as the canonical
+    /// `tenant_id` metadata field.
+    ///
+    /// The field is not redacted and is included in the tracing event emitted
+    /// by [`log`](Self::log). Read it back with
+    /// [`tenant_id`](Self::tenant_id).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::forbidden("plan limit reached").with_tenant("acme");
+    /// assert_eq!(err.tenant_id(), Some("acme"));
+    /// ```
+    #[must_use]
+    pub fn with_tenant(self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.with_field(field::str(TENANT_ID_KEY, id))
+    }
+
+    /// Tag the error with the operation that failed, stored as the canonical
+    /// `operation` metadata field.
+    ///
+    /// With the `metrics` feature the name is also added as the `operation`
+    /// label of the `error_total` counter. Use a fixed set of names such as
+    /// route templates or RPC methods rather than raw paths; past 64 distinct
+    /// names per process further ones are counted under `other`. Read it back
+    /// with [`operation`](Self::operation).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::timeout("upstream slow").with_operation("GET /orders/{id}");
+    /// assert_eq!(err.operation(), Some("GET /orders/{id}"));
+    /// ```
+    #[must_use]
+    pub fn with_operation(self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.with_field(field::str(OPERATION_KEY, name))
+    }
+
+    /// Add a custom label to the `error_total` counter.
+    ///
+    /// Labels are kept apart from metadata and read only by the `metrics`
+    /// feature, so they never reach logs or client payloads. Use values from a
+    /// small fixed set such as regions or tiers. Setting an existing key
+    /// replaces its value; the built-in `code`, `category` and `operation`
+    /// keys are ignored, and keys past the first four are dropped to keep
+    /// counter cardinality bounded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::service("upstream down").with_metric_label("region", "eu-west");
+    /// assert_eq!(
+    ///     err.metric_labels().collect::<Vec<_>>(),
+    ///     [("region", "eu-west")]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_metric_label(
+        mut self,
+        key: &'static str,
+        value: impl Into<Cow<'static, str>>
+    ) -> Self {
+        if RESERVED_METRIC_LABELS.contains(&key) {
+            return self;
+        }
+        let value = value.into();
+        if let Some(slot) = self
+            .metric_labels
+            .iter_mut()
+            .find(|(existing, _)| *existing == key)
+        {
+            slot.1 = value;
+        } else if self.metric_labels.len() < MAX_METRIC_LABELS {
+            self.metric_labels.push((key, value));
+        } else {
+            return self;
+        }
+        self.mark_dirty();
+        self
+    }
+
+    /// Record how long the failed operation ran as the `elapsed` metadata
+    /// field.
+    ///
+    /// The value is stored as [`FieldValue::Duration`], so each display mode
+    /// and transport renders it with its usual duration formatting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    ///
+    /// use masterror::{AppError, FieldValue};
+    ///
+    /// let err = AppError::timeout("query timed out").with_elapsed(Duration::from_millis(1500));
+    /// assert_eq!(
+    ///     err.metadata().get("elapsed"),
+    ///     Some(&FieldValue::Duration(Duration::from_millis(1500)))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.metadata
+            .insert_limited(crate::field::duration("elapsed", elapsed));
+        self.mark_dirty();
+        self
+    }
+
+    /// Attach the identity and recorded fields of `span` as metadata.
+    ///
+    /// Stores the span name under `span`, its target under `span.target` and
+    /// its subscriber-assigned id under `span.id`. With the `span-fields`
+    /// feature, each value recorded on the span is stored as
+    /// `span.field.<name>` when the subscriber is a registry running
+    /// `span_fields::SpanFieldsLayer`; otherwise only the identity is kept.
+    /// Disabled spans, and spans created without an active subscriber,
+    /// leave the error unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "span-fields")]
+    /// # {
+    /// use masterror::{AppError, FieldValue, span_fields::SpanFieldsLayer};
+    /// use tracing::info_span;
+    /// use tracing_subscriber::layer::SubscriberExt;
+    ///
+    /// let subscriber = tracing_subscriber::registry().with(SpanFieldsLayer::new());
+    /// tracing::subscriber::with_default(subscriber, || {
+    ///     let span = info_span!("charge_card", gateway = "stripe");
+    ///     let err = AppError::service("gateway declined").with_span(&span);
+    ///     assert_eq!(
+    ///         err.metadata().get("span"),
+    ///         Some(&FieldValue::Str("charge_card".into()))
+    ///     );
+    ///     assert_eq!(
+    ///         err.metadata().get("span.field.gateway"),
+    ///         Some(&FieldValue::Str("stripe".into()))
+    ///     );
+    /// });
+    /// # }
+    /// ```
+    #[must_use]
+    #[cfg(feature = "tracing")]
+    pub fn with_span(mut self, span: &Span) -> Self {
+        let Some(metadata) = span.metadata() else {
+            return self;
+        };
+        self.metadata
+            .insert_limited(crate::field::str("span", metadata.name()));
+        self.metadata
+            .insert_limited(crate::field::str("span.target", metadata.target()));
+        if let Some(id) = span.id() {
+            self.metadata
+                .insert_limited(crate::field::u64("span.id", id.into_u64()));
+        }
+        #[cfg(feature = "span-fields")]
+        for field in crate::span_fields::recorded_fields(span) {
+            self.metadata.insert_limited(field);
+        }
         self.mark_dirty();
         self
     }
 
+    /// Attach the identity of [`Span::current`] as metadata.
+    ///
+    /// See [`with_span`](Self::with_span) for the stored fields. Outside of
+    /// any entered span this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "tracing")]
+    /// # {
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::internal("no span entered").with_current_span();
+    /// assert!(err.metadata().get("span").is_none());
+    /// # }
+    /// ```
+    #[must_use]
+    #[cfg(feature = "tracing")]
+    pub fn with_current_span(self) -> Self {
+        self.with_span(&Span::current())
+    }
+
     /// Override the redaction policy for a stored metadata field.
     ///
     /// # Examples
@@ -181,8 +737,164 @@ impl Error {
         self
     }
 
+    /// Override the redaction policy for every stored metadata field.
+    ///
+    /// Values are kept intact; only their public rendering changes. Fields
+    /// attached afterwards keep their own policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, FieldRedaction, field};
+    ///
+    /// let err = AppError::internal("boom")
+    ///     .with_field(field::str("user_id", "42"))
+    ///     .with_field(field::u64("attempt", 3))
+    ///     .redact_all_fields(FieldRedaction::Redact);
+    /// assert_eq!(
+    ///     err.metadata().redaction("user_id"),
+    ///     Some(FieldRedaction::Redact)
+    /// );
+    /// assert_eq!(
+    ///     err.metadata().redaction("attempt"),
+    ///     Some(FieldRedaction::Redact)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn redact_all_fields(mut self, redaction: FieldRedaction) -> Self {
+        self.metadata.set_redaction_all(redaction);
+        self.mark_dirty();
+        self
+    }
+
+    /// Rewrite metadata values and redaction policies in place.
+    ///
+    /// The closure is invoked once per field with its name, value and
+    /// redaction policy, letting callers scrub PII by key pattern right
+    /// before the error is emitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, FieldRedaction, FieldValue, field};
+    ///
+    /// let mut err = AppError::internal("boom")
+    ///     .with_field(field::str("contact_email", "alice@example.com"))
+    ///     .with_field(field::u64("attempt", 3));
+    /// err.scrub(|name, value, redaction| {
+    ///     if name.contains("email") {
+    ///         *value = FieldValue::Str("".into());
+    ///         *redaction = FieldRedaction::Redact;
+    ///     }
+    /// });
+    /// assert_eq!(
+    ///     err.metadata().redaction("contact_email"),
+    ///     Some(FieldRedaction::Redact)
+    /// );
+    /// assert_eq!(
+    ///     err.metadata().redaction("attempt"),
+    ///     Some(FieldRedaction::None)
+    /// );
+    /// ```
+    pub fn scrub(&mut self, f: impl FnMut(&str, &mut FieldValue, &mut FieldRedaction)) {
+        self.metadata.scrub(f);
+        self.mark_dirty();
+    }
+
+    /// Remove a metadata field and return its value.
+    ///
+    /// The field's redaction policy is removed along with it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, FieldValue, field};
+    ///
+    /// let mut err = AppError::internal("boom").with_field(field::str("session", "s-1"));
+    /// assert_eq!(
+    ///     err.remove_field("session"),
+    ///     Some(FieldValue::Str("s-1".into()))
+    /// );
+    /// assert!(!err.metadata().contains_key("session"));
+    /// ```
+    pub fn remove_field(&mut self, key: &str) -> Option<FieldValue> {
+        let removed = self.metadata.remove(key);
+        if removed.is_some() {
+            self.mark_dirty();
+        }
+        removed
+    }
+
+    /// Rename metadata fields using `(from, to)` pairs.
+    ///
+    /// Normalizes keys from different subsystems (`req_id` vs `request_id`)
+    /// before logging. Values and redaction policies move with the field.
+    /// Pairs whose source is absent or whose target already exists are
+    /// skipped, so an existing value is never overwritten. Pairs are applied
+    /// in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, FieldValue, field};
+    ///
+    /// let err = AppError::internal("boom")
+    ///     .with_field(field::str("req_id", "r-1"))
+    ///     .rename_fields(&[("req_id", "request_id"), ("uid", "user_id")]);
+    /// assert_eq!(
+    ///     err.metadata().get("request_id"),
+    ///     Some(&FieldValue::Str("r-1".into()))
+    /// );
+    /// assert!(!err.metadata().contains_key("req_id"));
+    /// ```
+    #[must_use]
+    pub fn rename_fields(mut self, renames: &[(&str, &'static str)]) -> Self {
+        let mut renamed = false;
+        for &(from, to) in renames {
+            renamed |= self.metadata.rename(from, to);
+        }
+        if renamed {
+            self.mark_dirty();
+        }
+        self
+    }
+
+    /// Assert that a metadata field is present.
+    ///
+    /// Intended for adapter invariants: in debug builds a missing key panics
+    /// with a message naming it, which surfaces telemetry wiring mistakes
+    /// early. Release builds skip the check and return the error unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds when `key` is absent from the metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, field};
+    ///
+    /// let err = AppError::service("upstream failed")
+    ///     .with_field(field::str("upstream", "billing"))
+    ///     .ensure_field("upstream");
+    /// assert!(err.metadata().contains_key("upstream"));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn ensure_field(self, key: &str) -> Self {
+        debug_assert!(
+            self.metadata.contains_key(key),
+            "AppError metadata is missing required field `{key}`"
+        );
+        self
+    }
+
     /// Replace metadata entirely.
     ///
+    /// Honors the process-wide [`MetadataLimit`](crate::MetadataLimit): when
+    /// `metadata` holds more fields than allowed, they are re-applied oldest
+    /// first under the configured overflow policy.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -193,7 +905,10 @@ impl Error {
     /// ```
     #[must_use]
     pub fn with_metadata(mut self, metadata: Metadata) -> Self {
-        self.metadata = metadata;
+        self.metadata = match metadata_limit() {
+            Some(limit) => metadata.into_bounded(limit),
+            None => metadata
+        };
         self.mark_dirty();
         self
     }
@@ -215,6 +930,91 @@ impl Error {
         self
     }
 
+    /// Mark the message as redactable when `cond` holds.
+    ///
+    /// Lets one builder chain pick the policy at runtime, e.g. redacting only
+    /// in production. When `cond` is `false` the current policy is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, DisplayMode, MessageEditPolicy};
+    ///
+    /// let in_prod = DisplayMode::current() == DisplayMode::Prod;
+    /// let err = AppError::internal("db password rejected").redactable_if(in_prod);
+    /// assert_eq!(err.edit_policy == MessageEditPolicy::Redact, in_prod);
+    /// ```
+    #[must_use]
+    pub fn redactable_if(self, cond: bool) -> Self {
+        if cond { self.redactable() } else { self }
+    }
+
+    /// Mark the message as safe to expose as-is.
+    ///
+    /// Sets [`MessageEditPolicy::Preserve`], undoing an earlier
+    /// [`redactable`](Self::redactable).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, MessageEditPolicy};
+    ///
+    /// let err = AppError::internal("cache warming")
+    ///     .redactable()
+    ///     .plain_message();
+    /// assert_eq!(err.edit_policy, MessageEditPolicy::Preserve);
+    /// ```
+    #[must_use]
+    pub fn plain_message(mut self) -> Self {
+        self.edit_policy = MessageEditPolicy::Preserve;
+        self.mark_dirty();
+        self
+    }
+
+    /// Prepend a human-readable context string to the message.
+    ///
+    /// Produces `"{ctx}: {message}"`, falling back to the kind label for
+    /// message-less errors. Kind, code, metadata and source stay untouched;
+    /// use [`with_context`](Self::with_context) to attach a source error
+    /// instead. The combined message is built with a single allocation, and
+    /// an empty `ctx` leaves the error unchanged without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    ///
+    /// let err = AppError::not_found("user missing").context_str("loading profile");
+    /// assert_eq!(
+    ///     err.message.as_deref(),
+    ///     Some("loading profile: user missing")
+    /// );
+    ///
+    /// let bare = AppError::bare(AppErrorKind::Timeout).context_str("fetching quotes");
+    /// assert_eq!(
+    ///     bare.message.as_deref(),
+    ///     Some("fetching quotes: Operation timed out")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn context_str(mut self, ctx: impl Into<Cow<'static, str>>) -> Self {
+        let ctx = ctx.into();
+        if ctx.is_empty() {
+            return self;
+        }
+        let combined = {
+            let rest = self.render_message();
+            let mut combined = String::with_capacity(ctx.len() + 2 + rest.len());
+            combined.push_str(&ctx);
+            combined.push_str(": ");
+            combined.push_str(&rest);
+            combined
+        };
+        self.message = Some(Cow::Owned(combined));
+        self.mark_dirty();
+        self
+    }
+
     /// Attach upstream diagnostics using [`with_source`](Self::with_source) or
     /// an existing [`Arc`].
     ///
@@ -247,6 +1047,35 @@ impl Error {
         }
     }
 
+    /// Prepend `msg` to the message and attach `source` in one step.
+    ///
+    /// Equivalent to [`context_str`](Self::context_str) followed by
+    /// [`with_context`](Self::with_context).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::service("upstream failed")
+    ///     .with_source_context("loading invoices", std::io::Error::other("reset by peer"));
+    /// assert_eq!(
+    ///     err.message.as_deref(),
+    ///     Some("loading invoices: upstream failed")
+    /// );
+    /// assert!(err.is::<std::io::Error>());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_source_context(
+        self,
+        msg: impl Into<Cow<'static, str>>,
+        source: impl CoreError + Send + Sync + 'static
+    ) -> Self {
+        self.context_str(msg).with_context(source)
+    }
+
     /// Attach an owned source error for diagnostics.
     ///
     /// The source stays exclusively owned by this error, so it can later be
@@ -272,6 +1101,27 @@ impl Error {
         self.with_boxed_source(Box::new(source))
     }
 
+    /// Attach a plain-text message as the source error.
+    ///
+    /// The message is wrapped in [`StringError`], so it shows up in
+    /// [`chain`](Self::chain) and [`root_cause`](Self::root_cause) and can be
+    /// recovered via [`downcast_ref`](Self::downcast_ref).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, StringError};
+    ///
+    /// let err = AppError::internal("sync failed").with_source_str("EIO from driver");
+    /// let root = err.root_cause();
+    /// assert_eq!(root.to_string(), "EIO from driver");
+    /// assert!(root.downcast_ref::<StringError>().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_source_str(self, msg: impl Into<String>) -> Self {
+        self.with_source(StringError::new(msg))
+    }
+
     /// Attach an already boxed source error without re-boxing.
     pub(crate) fn with_boxed_source(
         mut self,
@@ -312,6 +1162,77 @@ impl Error {
         self
     }
 
+    /// Replace, wrap or drop the primary source at a boundary.
+    ///
+    /// `f` receives the current source as a shared [`Arc`] and returns the
+    /// new one; `None` detaches it. `f` is not called when the error has no
+    /// source. The result is stored as a shared source, like
+    /// [`with_source_arc`](Self::with_source_arc), so it can no longer be
+    /// recovered by value through [`downcast`](Self::downcast).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::internal("db down")
+    ///     .with_source(std::io::Error::other("password=hunter2"))
+    ///     .map_source(|_| None);
+    /// assert!(err.source_ref().is_none());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn map_source(
+        mut self,
+        f: impl FnOnce(
+            Arc<dyn CoreError + Send + Sync + 'static>
+        ) -> Option<Arc<dyn CoreError + Send + Sync + 'static>>
+    ) -> Self {
+        if let Some(source) = self.source.take() {
+            self.source = f(source.into_shared()).map(StoredSource::Shared);
+            self.mark_dirty();
+        }
+        self
+    }
+
+    /// Attach an independent source error.
+    ///
+    /// Use it when several operations failed and none of them caused the
+    /// others. The first source becomes the primary one returned by
+    /// [`source`](core::error::Error::source); later ones are kept in
+    /// attachment order and visited by [`chain`](Self::chain) after the
+    /// primary chain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::service("replication failed")
+    ///     .with_additional_source(std::io::Error::other("replica a"))
+    ///     .with_additional_source(std::io::Error::other("replica b"));
+    ///
+    /// let chain: Vec<String> = err.chain().skip(1).map(|e| e.to_string()).collect();
+    /// assert_eq!(chain, ["replica a", "replica b"]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_additional_source(
+        mut self,
+        other: impl CoreError + Send + Sync + 'static
+    ) -> Self {
+        let other = StoredSource::Owned(Box::new(other));
+        if self.source.is_none() {
+            self.source = Some(other);
+        } else {
+            self.additional_sources.push(other);
+        }
+        self.mark_dirty();
+        self
+    }
+
     /// Attach a captured backtrace.
     ///
     /// # Examples
@@ -413,6 +1334,86 @@ impl Error {
         Ok(self.with_details_json(details))
     }
 
+    /// Serialize and attach structured details, keeping the error intact if
+    /// serialization fails.
+    ///
+    /// Unlike [`with_details`](Self::with_details), a failure leaves
+    /// `details` unset and records the reason in the `details.error` metadata
+    /// field instead of replacing the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "serde_json")]
+    /// # {
+    /// use masterror::{AppError, AppErrorKind};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Extra {
+    ///     reason: &'static str
+    /// }
+    ///
+    /// let err = AppError::conflict("duplicate").with_details_or_skip(Extra {
+    ///     reason: "email taken"
+    /// });
+    /// assert_eq!(err.kind, AppErrorKind::Conflict);
+    /// assert!(err.details.is_some());
+    /// # }
+    /// ```
+    #[must_use]
+    #[cfg(feature = "serde_json")]
+    pub fn with_details_or_skip<T>(self, payload: T) -> Self
+    where
+        T: Serialize
+    {
+        match to_value(payload) {
+            Ok(details) => self.with_details_json(details),
+            Err(err) => self.with_field(field::str("details.error", err.to_string()))
+        }
+    }
+
+    /// Borrow the details as a JSON object for incremental updates.
+    ///
+    /// Missing details are initialized to an empty object. Existing
+    /// non-object details are preserved under the `"value"` key of the new
+    /// object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "serde_json")]
+    /// # {
+    /// use masterror::AppError;
+    /// use serde_json::json;
+    ///
+    /// let mut err = AppError::validation("invalid input");
+    /// err.details_object().insert("field".into(), json!("email"));
+    /// err.details_object().insert("reason".into(), json!("taken"));
+    /// assert_eq!(
+    ///     err.details,
+    ///     Some(json!({"field": "email", "reason": "taken"}))
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn details_object(&mut self) -> &mut JsonMap<String, JsonValue> {
+        self.mark_dirty();
+        let details = match self.details.take() {
+            None => JsonValue::Object(JsonMap::new()),
+            Some(JsonValue::Object(map)) => JsonValue::Object(map),
+            Some(other) => {
+                let mut map = JsonMap::new();
+                map.insert("value".into(), other);
+                JsonValue::Object(map)
+            }
+        };
+        match self.details.insert(details) {
+            JsonValue::Object(map) => map,
+            _ => unreachable!("details were just set to an object")
+        }
+    }
+
     /// Attach plain-text details for client payloads.
     ///
     /// The text is omitted from responses when the error is
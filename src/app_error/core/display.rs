@@ -5,16 +5,22 @@
 use alloc::string::{String, ToString};
 use core::{
     error::Error as CoreError,
-    fmt::{Formatter, Result as FmtResult},
+    fmt::{Display, Formatter, Result as FmtResult},
     sync::atomic::{AtomicU8, Ordering}
 };
 
 use super::error::Error;
 use crate::{
     FieldRedaction, FieldValue, MessageEditPolicy, Metadata,
-    app_error::redaction::{REDACTED_PLACEHOLDER, hash_field_value, mask_last4_field_value}
+    app_error::{
+        metadata::non_finite_label,
+        redaction::{REDACTED_PLACEHOLDER, hash_field_value, mask_last4_field_value}
+    }
 };
 
+/// Fractional digits of float metadata in [`DisplayMode::Prod`] output.
+const PROD_FLOAT_PRECISION: usize = 6;
+
 /// Sentinel stored in [`CACHED_MODE`] while no mode has been detected yet.
 const MODE_CACHE_UNSET: u8 = 255;
 
@@ -60,7 +66,8 @@ pub enum DisplayMode {
     /// Selected when `MASTERROR_ENV` is `local`/`dev`/`development`, or for
     /// debug builds by default. Errors render as a multi-line
     /// human-readable report; the `colored` feature adds ANSI styling to
-    /// this layout only.
+    /// this layout only. [`ErrorDisplay::with_color`] overrides the styling
+    /// at runtime.
     Local = 1,
 
     /// Staging environment.
@@ -239,7 +246,109 @@ mod test_support {
     }
 }
 
+/// [`Display`] adapter rendering an error in an explicit [`DisplayMode`].
+///
+/// Returned by [`Error::display_as`]. Unlike the `Display` implementation of
+/// [`struct@crate::Error`], the layout does not depend on the detected
+/// environment. ANSI styling of the `Local` layout follows the `colored`
+/// feature by default and can be forced on or off with
+/// [`with_color`](Self::with_color); the JSON layouts never contain escape
+/// sequences.
+///
+/// # Examples
+///
+/// ```
+/// use masterror::{AppError, DisplayMode};
+///
+/// let err = AppError::not_found("missing user");
+/// let plain = err
+///     .display_as(DisplayMode::Local)
+///     .with_color(false)
+///     .to_string();
+/// assert!(plain.contains("Code: NOT_FOUND"));
+/// assert!(!plain.contains('\u{1b}'));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorDisplay<'a> {
+    error: &'a Error,
+    mode:  DisplayMode,
+    color: LocalColor
+}
+
+impl ErrorDisplay<'_> {
+    /// Force ANSI styling of the `Local` layout on or off, regardless of the
+    /// `colored` feature and terminal detection.
+    ///
+    /// Disable it when writing to files or other non-terminal sinks.
+    ///
+    /// ```
+    /// use masterror::{AppError, DisplayMode};
+    ///
+    /// let err = AppError::internal("boom");
+    /// let styled = err
+    ///     .display_as(DisplayMode::Local)
+    ///     .with_color(true)
+    ///     .to_string();
+    /// assert!(styled.contains('\u{1b}'));
+    /// ```
+    #[must_use]
+    pub fn with_color(mut self, enabled: bool) -> Self {
+        self.color = LocalColor::from(enabled);
+        self
+    }
+}
+
+impl Display for ErrorDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.mode {
+            DisplayMode::Prod => self.error.fmt_prod(f),
+            DisplayMode::Staging => self.error.fmt_staging(f),
+            DisplayMode::Local => self.error.fmt_local(f, self.color)
+        }
+    }
+}
+
 impl Error {
+    /// Render the error in the given [`DisplayMode`] instead of the detected
+    /// one.
+    ///
+    /// ```
+    /// use masterror::{AppError, DisplayMode};
+    ///
+    /// let err = AppError::not_found("missing user");
+    /// let json = err.display_as(DisplayMode::Prod).to_string();
+    /// assert!(json.starts_with(r#"{"kind":"NotFound""#));
+    /// ```
+    #[must_use]
+    pub fn display_as(&self, mode: DisplayMode) -> ErrorDisplay<'_> {
+        ErrorDisplay {
+            error: self,
+            mode,
+            color: LocalColor::Auto
+        }
+    }
+
+    /// Render the production compact JSON layout into a [`String`].
+    ///
+    /// Shorthand for `display_as(DisplayMode::Prod).to_string()`, independent
+    /// of the detected mode. Redactable messages and redacted metadata are
+    /// omitted or masked exactly as in [`DisplayMode::Prod`].
+    ///
+    /// ```
+    /// use masterror::{AppError, field};
+    ///
+    /// let err = AppError::not_found("missing user").with_field(field::u64("user_id", 7));
+    /// assert_eq!(
+    ///     err.to_compact_json(),
+    ///     r#"{"kind":"NotFound","code":"NOT_FOUND","message":"missing user","metadata":{"user_id":7}}"#
+    /// );
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn to_compact_json(&self) -> String {
+        self.display_as(DisplayMode::Prod).to_string()
+    }
+
     /// Formats the error as compact JSON (`kind`, `code`, optional `message`,
     /// redaction-aware metadata).
     ///
@@ -259,51 +368,47 @@ impl Error {
             write_json_escaped(f, msg.as_ref())?;
             write!(f, "\"")?;
         }
-        write_json_metadata_section(f, &self.metadata)?;
+        write_json_metadata_section(f, &self.metadata, Some(PROD_FLOAT_PRECISION))?;
         write!(f, "}}")
     }
 
     /// Formats the error as a multi-line human-readable report (kind, code,
-    /// message, source chain, redaction-aware metadata).
+    /// message, help, source chain, redaction-aware metadata).
     ///
     /// Selected by the `Display` implementation when [`DisplayMode::current`]
-    /// returns [`DisplayMode::Local`]. The `colored` feature applies ANSI
-    /// styling to this layout when the terminal supports it.
+    /// returns [`DisplayMode::Local`]. With [`LocalColor::Auto`] the
+    /// `colored` feature applies ANSI styling when the terminal supports it;
+    /// the other choices force styling on or off.
     ///
     /// # Arguments
     ///
     /// * `f` - Formatter to write output to
-    pub(crate) fn fmt_local(&self, f: &mut Formatter<'_>) -> FmtResult {
-        #[cfg(feature = "colored")]
-        use crate::colored::style;
-
+    /// * `color` - ANSI styling choice for the report
+    pub(crate) fn fmt_local(&self, f: &mut Formatter<'_>, color: LocalColor) -> FmtResult {
         writeln!(f, "Error: {}", self.kind)?;
-        #[cfg(feature = "colored")]
-        writeln!(f, "Code: {}", style::error_code(self.code.to_string()))?;
-        #[cfg(not(feature = "colored"))]
-        writeln!(f, "Code: {}", self.code)?;
+        write!(f, "Code: ")?;
+        write_styled(f, color, LocalStyle::Code, &self.code)?;
+        writeln!(f)?;
         if !matches!(self.edit_policy, MessageEditPolicy::Redact)
             && let Some(msg) = &self.message
         {
-            #[cfg(feature = "colored")]
-            writeln!(f, "Message: {}", style::error_message(msg))?;
-            #[cfg(not(feature = "colored"))]
-            writeln!(f, "Message: {}", msg)?;
+            write!(f, "Message: ")?;
+            write_styled(f, color, LocalStyle::Message, msg)?;
+            writeln!(f)?;
+        }
+        if let Some(help) = &self.help {
+            writeln!(f, "Help: {}", help)?;
         }
         if let Some(source) = &self.source {
             writeln!(f)?;
             let mut current: &dyn CoreError = source.as_dyn();
             let mut depth = 0;
             while depth < 10 {
-                #[cfg(feature = "colored")]
-                writeln!(
-                    f,
-                    "  {}: {}",
-                    style::source_context("Caused by"),
-                    style::source_context(current.to_string())
-                )?;
-                #[cfg(not(feature = "colored"))]
-                writeln!(f, "  Caused by: {}", current)?;
+                write!(f, "  ")?;
+                write_styled(f, color, LocalStyle::Source, &"Caused by")?;
+                write!(f, ": ")?;
+                write_styled(f, color, LocalStyle::Source, &current)?;
+                writeln!(f)?;
                 if let Some(next) = current.source() {
                     current = next;
                     depth += 1;
@@ -312,7 +417,7 @@ impl Error {
                 }
             }
         }
-        write_local_metadata_section(f, &self.metadata)
+        write_local_metadata_section(f, &self.metadata, color)
     }
 
     /// Formats the error as JSON with additional context (`source_chain` and
@@ -356,7 +461,7 @@ impl Error {
             }
             write!(f, "]")?;
         }
-        write_json_metadata_section(f, &self.metadata)?;
+        write_json_metadata_section(f, &self.metadata, None)?;
         write!(f, "}}")
     }
 }
@@ -378,7 +483,11 @@ enum LocalFieldValue<'a> {
 /// [`FieldRedaction::Last4`] as a masked value. Fields whose masking yields
 /// no value are omitted; if nothing remains, the section is skipped
 /// entirely.
-fn write_json_metadata_section(f: &mut Formatter<'_>, metadata: &Metadata) -> FmtResult {
+fn write_json_metadata_section(
+    f: &mut Formatter<'_>,
+    metadata: &Metadata,
+    float_precision: Option<usize>
+) -> FmtResult {
     let mut wrote_any = false;
     for (name, value, redaction) in metadata.iter_with_redaction() {
         let masked = match redaction {
@@ -396,7 +505,7 @@ fn write_json_metadata_section(f: &mut Formatter<'_>, metadata: &Metadata) -> Fm
         }
         write!(f, r#""{}":"#, name)?;
         match redaction {
-            FieldRedaction::None => write_metadata_value(f, value)?,
+            FieldRedaction::None => write_metadata_value(f, value, float_precision)?,
             FieldRedaction::Redact => write!(f, "\"{}\"", REDACTED_PLACEHOLDER)?,
             FieldRedaction::Hash => write!(f, "\"{}\"", hash_field_value(value))?,
             FieldRedaction::Last4 => {
@@ -416,7 +525,11 @@ fn write_json_metadata_section(f: &mut Formatter<'_>, metadata: &Metadata) -> Fm
 ///
 /// Applies the same policies as [`write_json_metadata_section`]; the header
 /// is skipped when every field is omitted.
-fn write_local_metadata_section(f: &mut Formatter<'_>, metadata: &Metadata) -> FmtResult {
+fn write_local_metadata_section(
+    f: &mut Formatter<'_>,
+    metadata: &Metadata,
+    color: LocalColor
+) -> FmtResult {
     let mut wrote_header = false;
     for (name, value, redaction) in metadata.iter_with_redaction() {
         let rendered = match redaction {
@@ -433,10 +546,9 @@ fn write_local_metadata_section(f: &mut Formatter<'_>, metadata: &Metadata) -> F
             writeln!(f, "Context:")?;
             wrote_header = true;
         }
-        #[cfg(feature = "colored")]
-        write!(f, "  {}: ", crate::colored::style::metadata_key(name))?;
-        #[cfg(not(feature = "colored"))]
-        write!(f, "  {}: ", name)?;
+        write!(f, "  ")?;
+        write_styled(f, color, LocalStyle::MetadataKey, &name)?;
+        write!(f, ": ")?;
         match rendered {
             LocalFieldValue::Raw(value) => writeln!(f, "{}", value)?,
             LocalFieldValue::Placeholder => writeln!(f, "{}", REDACTED_PLACEHOLDER)?,
@@ -446,6 +558,73 @@ fn write_local_metadata_section(f: &mut Formatter<'_>, metadata: &Metadata) -> F
     Ok(())
 }
 
+/// ANSI styling choice for the local layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LocalColor {
+    /// Style through the `colored` feature, honouring terminal detection;
+    /// plain text when the feature is disabled.
+    Auto,
+    /// Always emit ANSI escape sequences.
+    Always,
+    /// Never emit ANSI escape sequences.
+    Never
+}
+
+impl From<bool> for LocalColor {
+    fn from(enabled: bool) -> Self {
+        if enabled { Self::Always } else { Self::Never }
+    }
+}
+
+/// Role of a styled fragment in the local layout.
+#[derive(Debug, Clone, Copy)]
+enum LocalStyle {
+    Code,
+    Message,
+    Source,
+    MetadataKey
+}
+
+impl LocalStyle {
+    /// SGR parameter matching the palette of [`crate::colored::style`].
+    const fn sgr(self) -> &'static str {
+        match self {
+            Self::Code => "36",
+            Self::Message => "97",
+            Self::Source => "2",
+            Self::MetadataKey => "32"
+        }
+    }
+
+    #[cfg(feature = "colored")]
+    fn auto(self, text: String) -> String {
+        use crate::colored::style;
+        match self {
+            Self::Code => style::error_code(text),
+            Self::Message => style::error_message(text),
+            Self::Source => style::source_context(text),
+            Self::MetadataKey => style::metadata_key(text)
+        }
+    }
+}
+
+/// Writes a fragment of the local layout with the requested styling.
+fn write_styled(
+    f: &mut Formatter<'_>,
+    color: LocalColor,
+    style: LocalStyle,
+    text: &dyn Display
+) -> FmtResult {
+    match color {
+        #[cfg(feature = "colored")]
+        LocalColor::Auto => write!(f, "{}", style.auto(text.to_string())),
+        #[cfg(not(feature = "colored"))]
+        LocalColor::Auto => write!(f, "{}", text),
+        LocalColor::Always => write!(f, "\u{1b}[{}m{}\u{1b}[0m", style.sgr(), text),
+        LocalColor::Never => write!(f, "{}", text)
+    }
+}
+
 /// Writes a string with JSON escaping.
 fn write_json_escaped(f: &mut Formatter<'_>, s: &str) -> FmtResult {
     for ch in s.chars() {
@@ -463,7 +642,14 @@ fn write_json_escaped(f: &mut Formatter<'_>, s: &str) -> FmtResult {
 }
 
 /// Writes a metadata field value in JSON format.
-fn write_metadata_value(f: &mut Formatter<'_>, value: &FieldValue) -> FmtResult {
+///
+/// Floats use `float_precision` fractional digits when given; `NaN` and
+/// infinities are written as the strings `"NaN"`, `"inf"` and `"-inf"`.
+fn write_metadata_value(
+    f: &mut Formatter<'_>,
+    value: &FieldValue,
+    float_precision: Option<usize>
+) -> FmtResult {
     use crate::app_error::metadata::FieldValue;
     match value {
         FieldValue::Str(s) => {
@@ -473,13 +659,11 @@ fn write_metadata_value(f: &mut Formatter<'_>, value: &FieldValue) -> FmtResult
         }
         FieldValue::I64(v) => write!(f, "{}", v),
         FieldValue::U64(v) => write!(f, "{}", v),
-        FieldValue::F64(v) => {
-            if v.is_finite() {
-                write!(f, "{}", v)
-            } else {
-                write!(f, "null")
-            }
-        }
+        FieldValue::F64(v) => match (non_finite_label(*v), float_precision) {
+            (Some(label), _) => write!(f, "\"{}\"", label),
+            (None, Some(precision)) => write!(f, "{:.*}", precision, v),
+            (None, None) => write!(f, "{}", v)
+        },
         FieldValue::Bool(v) => write!(f, "{}", v),
         FieldValue::Uuid(v) => write!(f, "\"{}\"", v),
         FieldValue::Duration(v) => {
@@ -574,6 +758,102 @@ mod tests {
         assert!(output.contains("missing user"));
     }
 
+    #[test]
+    fn display_as_ignores_detected_mode() {
+        let _guard = force_display_mode(DisplayMode::Local);
+        let error = AppError::not_found("missing user");
+        let output = error.display_as(DisplayMode::Prod).to_string();
+        assert!(output.starts_with(r#"{"kind":"NotFound""#), "{output}");
+        let output = error.display_as(DisplayMode::Staging).to_string();
+        assert!(output.starts_with(r#"{"kind":"NotFound""#), "{output}");
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn to_compact_json_matches_prod_layout_and_masks_redacted_fields() {
+        let _guard = force_display_mode(DisplayMode::Local);
+        let error = AppError::unauthorized("token expired")
+            .with_field(field::str("user", "alice"))
+            .with_field(field::str("password", "hunter2"));
+        let output = error.to_compact_json();
+        assert_eq!(output, error.display_as(DisplayMode::Prod).to_string());
+        let json: serde_json::Value = serde_json::from_str(&output).expect("valid json");
+        assert_eq!(json["kind"], "Unauthorized");
+        assert_eq!(json["code"], "UNAUTHORIZED");
+        assert_eq!(json["message"], "token expired");
+        assert_eq!(json["metadata"]["user"], "alice");
+        assert_eq!(json["metadata"]["password"], REDACTED_PLACEHOLDER);
+        assert!(!output.contains("hunter2"));
+        let redacted = AppError::internal("db password leaked").redactable();
+        let json: serde_json::Value =
+            serde_json::from_str(&redacted.to_compact_json()).expect("valid json");
+        assert!(json.get("message").is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_as_local_with_color_emits_ansi_escapes() {
+        use std::io::Error as IoError;
+        let error = AppError::service("downstream")
+            .with_field(field::str("request_id", "abc"))
+            .with_source(IoError::other("timeout"));
+        let output = error
+            .display_as(DisplayMode::Local)
+            .with_color(true)
+            .to_string();
+        assert!(output.contains("\u{1b}[36mSERVICE\u{1b}[0m"), "{output}");
+        assert!(output.contains("\u{1b}[97mdownstream\u{1b}[0m"), "{output}");
+        assert!(output.contains("\u{1b}[2mtimeout\u{1b}[0m"), "{output}");
+        assert!(output.contains("\u{1b}[32mrequest_id\u{1b}[0m"), "{output}");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_as_local_without_color_is_plain() {
+        use std::io::Error as IoError;
+        let error = AppError::service("downstream")
+            .with_field(field::str("request_id", "abc"))
+            .with_source(IoError::other("timeout"));
+        let output = error
+            .display_as(DisplayMode::Local)
+            .with_color(false)
+            .to_string();
+        assert!(!output.contains('\u{1b}'), "{output}");
+        assert!(output.contains("Code: SERVICE"));
+        assert!(output.contains("Message: downstream"));
+        assert!(output.contains("  Caused by: timeout"));
+        assert!(output.contains("  request_id: abc"));
+    }
+
+    #[test]
+    fn fmt_local_renders_help_line() {
+        let error = AppError::unauthorized("token expired")
+            .redactable()
+            .with_help("Try regenerating your token.");
+        let output = error
+            .display_as(DisplayMode::Local)
+            .with_color(false)
+            .to_string();
+        assert!(
+            output.contains("Help: Try regenerating your token.\n"),
+            "{output}"
+        );
+        assert!(!output.contains("token expired"));
+        let output = AppError::internal("boom")
+            .display_as(DisplayMode::Local)
+            .to_string();
+        assert!(!output.contains("Help:"));
+    }
+
+    #[test]
+    fn display_as_json_layouts_ignore_color() {
+        let error = AppError::internal("boom");
+        for mode in [DisplayMode::Prod, DisplayMode::Staging] {
+            let output = error.display_as(mode).with_color(true).to_string();
+            assert!(!output.contains('\u{1b}'), "{output}");
+        }
+    }
+
     #[test]
     fn fmt_prod_outputs_json() {
         let error = AppError::not_found("User not found");
@@ -718,9 +998,50 @@ mod tests {
 
     #[test]
     fn fmt_prod_handles_infinity_in_metadata() {
-        let error = AppError::internal("Error").with_field(field::f64("ratio", f64::INFINITY));
+        let error = AppError::internal("Error")
+            .with_field(field::f64("ratio", f64::INFINITY))
+            .with_field(field::f64("floor", f64::NEG_INFINITY))
+            .with_field(field::f64("latency_ms", f64::NAN));
+        for output in [
+            format!("{}", error.fmt_prod_wrapper()),
+            format!("{}", error.fmt_staging_wrapper())
+        ] {
+            assert!(output.contains(r#""ratio":"inf""#), "{output}");
+            assert!(output.contains(r#""floor":"-inf""#), "{output}");
+            assert!(output.contains(r#""latency_ms":"NaN""#), "{output}");
+        }
+    }
+
+    #[test]
+    fn fmt_prod_uses_fixed_float_precision() {
+        let error = AppError::internal("Error")
+            .with_field(field::f64("latency_ms", 12.5))
+            .with_field(field::f64("ratio", 1.0 / 3.0))
+            .with_field(field::f64("bytes", 1e21));
         let output = format!("{}", error.fmt_prod_wrapper());
-        assert!(output.contains("null"));
+        assert!(output.contains(r#""latency_ms":12.500000"#), "{output}");
+        assert!(output.contains(r#""ratio":0.333333"#), "{output}");
+        assert!(
+            output.contains(r#""bytes":1000000000000000000000.000000"#),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn fmt_staging_and_local_keep_shortest_float() {
+        let error = AppError::internal("Error")
+            .with_field(field::f64("latency_ms", 12.5))
+            .with_field(field::f64("bytes", 1e21))
+            .with_field(field::f64("ratio", f64::NAN));
+        let staging = format!("{}", error.fmt_staging_wrapper());
+        assert!(staging.contains(r#""latency_ms":12.5,"#), "{staging}");
+        assert!(
+            staging.contains(r#""bytes":1000000000000000000000"#),
+            "{staging}"
+        );
+        let local = format!("{}", error.fmt_local_wrapper());
+        assert!(local.contains("12.5"), "{local}");
+        assert!(local.contains("NaN"), "{local}");
     }
 
     #[test]
@@ -733,6 +1054,18 @@ mod tests {
         assert!(output.contains(r#""nanos":500000000"#));
     }
 
+    #[test]
+    fn elapsed_field_renders_in_every_display_mode() {
+        use core::time::Duration;
+        let error = AppError::timeout("slow query").with_elapsed(Duration::from_millis(1500));
+        let prod = format!("{}", error.fmt_prod_wrapper());
+        assert!(prod.contains(r#""elapsed":{"secs":1,"nanos":500000000}"#));
+        let staging = format!("{}", error.fmt_staging_wrapper());
+        assert!(staging.contains(r#""elapsed":{"secs":1,"nanos":500000000}"#));
+        let local = format!("{}", error.fmt_local_wrapper());
+        assert!(local.contains("elapsed: 1.5s"));
+    }
+
     #[test]
     fn fmt_prod_formats_bool_metadata() {
         let error = AppError::internal("Error").with_field(field::bool("active", true));
@@ -969,7 +1302,7 @@ mod tests {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             match self.mode {
                 FormatterMode::Prod => self.error.fmt_prod(f),
-                FormatterMode::Local => self.error.fmt_local(f),
+                FormatterMode::Local => self.error.fmt_local(f, LocalColor::Auto),
                 FormatterMode::Staging => self.error.fmt_staging(f)
             }
         }
@@ -4,10 +4,10 @@
 
 #[cfg(feature = "backtrace")]
 use alloc::sync::Arc;
-use alloc::{borrow::Cow, boxed::Box, string::String};
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
 use core::{
     error::Error as CoreError,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     ops::{Deref, DerefMut},
     sync::atomic::AtomicBool
 };
@@ -20,17 +20,21 @@ use serde_json::Value as JsonValue;
 #[cfg(not(feature = "backtrace"))]
 use super::types::CapturedBacktrace;
 use super::{
-    display::DisplayMode,
-    types::{MessageEditPolicy, StoredSource}
+    display::{DisplayMode, LocalColor},
+    introspection::source_message,
+    types::{ErrorChain, MessageEditPolicy, StoredSource}
+};
+use crate::{
+    AppCode, AppErrorKind, RetryAdvice, Severity,
+    app_error::metadata::{Metadata, RedactedMetadata}
 };
-use crate::{AppCode, AppErrorKind, RetryAdvice, app_error::metadata::Metadata};
 
 /// Internal representation of error state.
 ///
 /// This structure holds all error data including code, kind, message,
 /// metadata, and optional diagnostic information. It is boxed within
-/// [`Error`] to minimize stack size.
-#[derive(Debug)]
+/// [`Error`] to minimize stack size. Its `Debug` output applies the same
+/// redaction as [`Error`]'s.
 #[doc(hidden)]
 pub struct ErrorInner {
     /// Stable machine-readable error code.
@@ -47,6 +51,20 @@ pub struct ErrorInner {
     pub retry:                  Option<RetryAdvice>,
     /// Optional authentication challenge for `WWW-Authenticate`.
     pub www_authenticate:       Option<String>,
+    /// Optional list of allowed methods for the `Allow` header.
+    pub allow:                  Option<String>,
+    /// Optional client-safe remediation hint.
+    pub help:                   Option<String>,
+    /// Optional sanitized message shown to clients instead of `message`.
+    pub public_message:         Option<String>,
+    /// Optional severity overriding the default of `kind`.
+    pub severity:               Option<Severity>,
+    /// Optional HTTP status overriding the default of `kind`.
+    pub status:                 Option<u16>,
+    /// Optional client language used to localize generic labels.
+    pub locale:                 Option<String>,
+    /// Extra low-cardinality labels for the `error_total` counter.
+    pub metric_labels:          Vec<(&'static str, Cow<'static, str>)>,
     /// Optional structured details exposed to clients.
     #[cfg(feature = "serde_json")]
     pub details:                Option<JsonValue>,
@@ -54,6 +72,10 @@ pub struct ErrorInner {
     #[cfg(not(feature = "serde_json"))]
     pub details:                Option<String>,
     pub source:                 Option<StoredSource>,
+    /// Independent sources attached with
+    /// [`Error::with_additional_source`], visited by [`Error::chain`] after
+    /// the primary chain.
+    pub additional_sources:     Vec<StoredSource>,
     #[cfg(feature = "backtrace")]
     pub backtrace:              Option<Arc<Backtrace>>,
     #[cfg(feature = "backtrace")]
@@ -77,7 +99,6 @@ pub struct ErrorInner {
 /// let err = AppError::new(AppErrorKind::BadRequest, "invalid payload");
 /// assert_eq!(err.kind, AppErrorKind::BadRequest);
 /// ```
-#[derive(Debug)]
 pub struct Error {
     pub(super) inner: Box<ErrorInner>
 }
@@ -101,8 +122,81 @@ impl Display for Error {
         match DisplayMode::current() {
             DisplayMode::Prod => self.fmt_prod(f),
             DisplayMode::Staging => self.fmt_staging(f),
-            DisplayMode::Local => self.fmt_local(f)
+            DisplayMode::Local => self.fmt_local(f, LocalColor::Auto)
+        }
+    }
+}
+
+/// Metadata values are rendered through their redaction policies and the
+/// source chain is reduced to messages, so `dbg!` and `{:?}` never print
+/// secrets attached as redacted fields.
+///
+/// ```rust
+/// use masterror::{AppError, field};
+///
+/// let err = AppError::unauthorized("login failed")
+///     .with_field(field::str("user", "alice"))
+///     .with_field(field::str("password", "hunter2"));
+/// let debug = format!("{err:?}");
+/// assert!(debug.contains("alice"));
+/// assert!(debug.contains("[redacted]"));
+/// assert!(!debug.contains("hunter2"));
+/// assert!(!format!("{:?}", *err).contains("hunter2"));
+/// ```
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.inner.fmt_redacted("AppError", f)
+    }
+}
+
+impl Debug for ErrorInner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_redacted("ErrorInner", f)
+    }
+}
+
+impl ErrorInner {
+    fn fmt_redacted(&self, name: &str, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct(name)
+            .field("code", &self.code)
+            .field("kind", &self.kind)
+            .field("message", &self.message)
+            .field("metadata", &RedactedMetadata(&self.metadata))
+            .field("edit_policy", &self.edit_policy)
+            .field("retry", &self.retry)
+            .field("www_authenticate", &self.www_authenticate)
+            .field("allow", &self.allow)
+            .field("help", &self.help)
+            .field("public_message", &self.public_message)
+            .field("severity", &self.severity)
+            .field("status", &self.status)
+            .field("locale", &self.locale)
+            .field("details", &self.details)
+            .field("metric_labels", &self.metric_labels)
+            .field("sources", &SourceMessages(self))
+            .finish_non_exhaustive()
+    }
+}
+
+/// Messages of every source in [`Error::chain`] after the error itself,
+/// outermost first.
+struct SourceMessages<'a>(&'a ErrorInner);
+
+impl Debug for SourceMessages<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let inner = self.0;
+        let mut pending = Vec::new();
+        if !inner.additional_sources.is_empty() {
+            pending.push(inner.additional_sources.as_slice());
         }
+        let sources = ErrorChain {
+            current: inner
+                .source
+                .as_ref()
+                .map(|source| source.as_dyn() as &(dyn CoreError + 'static)),
+            pending
+        };
+        f.debug_list().entries(sources.map(source_message)).finish()
     }
 }
 
@@ -113,15 +207,18 @@ impl CoreError for Error {
             .map(|source| source.as_dyn() as &(dyn CoreError + 'static))
     }
 
+    /// Forwards the request to the source first so that a backtrace captured
+    /// by a domain error (for example a derived `#[backtrace]` field) wins
+    /// over the one captured when the [`Error`] was constructed.
     #[cfg(masterror_has_error_generic_member_access)]
     fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        if let Some(source) = self.source.as_ref() {
+            crate::provide::ThiserrorProvide::thiserror_provide(source.as_dyn(), request);
+        }
         #[cfg(feature = "backtrace")]
         if let Some(backtrace) = self.backtrace.as_deref() {
             request.provide_ref::<Backtrace>(backtrace);
         }
-        if let Some(source) = self.source.as_ref() {
-            crate::provide::ThiserrorProvide::thiserror_provide(source.as_dyn(), request);
-        }
     }
 }
 
@@ -177,8 +274,16 @@ impl Error {
                 edit_policy: MessageEditPolicy::Preserve,
                 retry: None,
                 www_authenticate: None,
+                allow: None,
+                help: None,
+                public_message: None,
+                severity: None,
+                status: None,
+                locale: None,
+                metric_labels: Vec::new(),
                 details: None,
                 source: None,
+                additional_sources: Vec::new(),
                 #[cfg(feature = "backtrace")]
                 backtrace: None,
                 #[cfg(feature = "backtrace")]
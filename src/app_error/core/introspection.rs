@@ -2,19 +2,36 @@
 //
 // SPDX-License-Identifier: MIT
 
-use alloc::{borrow::Cow, boxed::Box};
-use core::error::Error as CoreError;
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec
+};
+use core::{error::Error as CoreError, iter::Take};
 
+#[cfg(feature = "serde_json")]
+use serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue, json};
+use sha2::{Digest, Sha256};
 #[cfg(feature = "backtrace")]
 use {alloc::sync::Arc, std::backtrace::Backtrace};
 
 #[cfg(feature = "backtrace")]
-use super::backtrace::capture_backtrace_snapshot;
+use super::backtrace::{capture_backtrace_snapshot, render_filtered_backtrace};
 use super::{
     error::Error,
     types::{CapturedBacktrace, ErrorChain, StoredSource}
 };
-use crate::app_error::metadata::Metadata;
+#[cfg(feature = "serde_json")]
+use crate::app_error::{duration_to_string, non_finite_label};
+use crate::{
+    AppErrorKind, ConflictReason, FieldRedaction, FieldValue, Severity,
+    app_error::{
+        conflict::CONFLICT_REASON_KEY,
+        metadata::{Metadata, OPERATION_KEY, TENANT_ID_KEY, contains_ascii_case_insensitive}
+    }
+};
 
 impl Error {
     /// Borrow the attached metadata.
@@ -33,6 +50,307 @@ impl Error {
         &self.metadata
     }
 
+    /// Keys of unredacted metadata fields whose name looks sensitive.
+    ///
+    /// A field is reported when its name contains any of `patterns`,
+    /// compared ASCII case-insensitively, and its policy is
+    /// [`FieldRedaction::None`]. Field names such as `password` are redacted
+    /// by default, so a hit usually means a policy was overridden or a
+    /// sensitive value was stored under an unexpected name. Empty patterns
+    /// are ignored. Keys are returned in metadata order.
+    ///
+    /// Intended for tests and audits that fail when the result is non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, FieldRedaction, field};
+    ///
+    /// let err = AppError::unauthorized("login failed")
+    ///     .with_field(field::str("user.password", "hunter2").with_redaction(FieldRedaction::None))
+    ///     .with_field(field::str("user.name", "alice"));
+    /// assert_eq!(
+    ///     err.audit_unredacted_sensitive(&["password", "token", "secret"]),
+    ///     ["user.password"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn audit_unredacted_sensitive(&self, patterns: &[&str]) -> Vec<&'static str> {
+        self.metadata
+            .iter_with_redaction()
+            .filter(|(name, _, redaction)| {
+                *redaction == FieldRedaction::None
+                    && patterns.iter().any(|pattern| {
+                        !pattern.is_empty() && contains_ascii_case_insensitive(name, pattern)
+                    })
+            })
+            .map(|(name, ..)| name)
+            .collect()
+    }
+
+    /// Public metadata fields as a flat JSON object.
+    ///
+    /// Only fields with [`FieldRedaction::None`] are included; redacted,
+    /// hashed and masked fields are omitted entirely rather than
+    /// transformed. Numbers and booleans stay native JSON values, while
+    /// durations, UUIDs and IP addresses use their display form (for example
+    /// `"1.5s"`). Non-finite floats become the strings `"NaN"`, `"inf"` and
+    /// `"-inf"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    ///
+    /// use masterror::{AppError, field};
+    /// use serde_json::json;
+    ///
+    /// let err = AppError::timeout("slow")
+    ///     .with_field(field::secret("api_secret", "s3cr3t"))
+    ///     .with_field(field::duration("elapsed", Duration::from_millis(1500)))
+    ///     .with_field(field::u64("attempt", 2));
+    /// assert_eq!(
+    ///     err.metadata_json(),
+    ///     json!({ "attempt": 2, "elapsed": "1.5s" })
+    /// );
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn metadata_json(&self) -> JsonValue {
+        let mut object = JsonMap::new();
+        for (name, value, redaction) in self.metadata.iter_with_redaction() {
+            if matches!(redaction, FieldRedaction::None) {
+                object.insert(name.into(), public_json_value(value));
+            }
+        }
+        JsonValue::Object(object)
+    }
+
+    /// Full diagnostic dump for bug reports and internal tooling.
+    ///
+    /// The report holds the kind, code, message, status, severity, details,
+    /// retry hint, the messages of the whole source chain, the rendered
+    /// backtrace (with the `backtrace` feature) and every metadata field as
+    /// `{"value": .., "redaction": ..}`. Values are included regardless of
+    /// their [`FieldRedaction`] policy, so the report must never be sent to
+    /// clients; use [`metadata_json`](Self::metadata_json) or
+    /// [`ProblemJson`](crate::ProblemJson) for public payloads.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, field};
+    /// use serde_json::json;
+    ///
+    /// let err = AppError::internal("charge failed")
+    ///     .with_field(field::secret("card", "4111"))
+    ///     .with_context(std::io::Error::other("socket closed"));
+    /// let report = err.debug_report();
+    /// assert_eq!(report["kind"], "Internal");
+    /// assert_eq!(
+    ///     report["metadata"]["card"],
+    ///     json!({"value": "4111", "redaction": "redact"})
+    /// );
+    /// assert_eq!(report["sources"], json!(["socket closed"]));
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn debug_report(&self) -> JsonValue {
+        let mut metadata = JsonMap::new();
+        for (name, value, redaction) in self.metadata.iter_with_redaction() {
+            metadata.insert(
+                name.into(),
+                json!({
+                    "value": public_json_value(value),
+                    "redaction": redaction_label(redaction)
+                })
+            );
+        }
+        let sources: Vec<String> = self.chain().skip(1).map(source_message).collect();
+        #[cfg(feature = "backtrace")]
+        let backtrace = self.backtrace_string();
+        #[cfg(not(feature = "backtrace"))]
+        let backtrace: Option<String> = None;
+        json!({
+            "kind": self.kind.name(),
+            "code": self.code.as_str(),
+            "message": self.message.as_deref(),
+            "status": self.status(),
+            "severity": self.severity().as_str(),
+            "details": self.details,
+            "retry_after_seconds": self.retry.map(|retry| retry.after_seconds),
+            "metadata": metadata,
+            "sources": sources,
+            "backtrace": backtrace
+        })
+    }
+
+    /// Borrow the remediation hint attached via
+    /// [`with_help`](Self::with_help).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::rate_limited("slow down").with_help("Retry in a minute.");
+    /// assert_eq!(err.help(), Some("Retry in a minute."));
+    /// assert_eq!(AppError::internal("boom").help(), None);
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    /// Borrow the client language set via
+    /// [`with_locale`](Self::with_locale).
+    #[must_use]
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Kind label in the client language, or in English without a locale.
+    pub(crate) fn localized_label(&self) -> &'static str {
+        match self.locale.as_deref() {
+            Some(lang) => self.kind.localized_label(lang),
+            None => self.kind.label()
+        }
+    }
+
+    /// Borrow the client-facing message attached via
+    /// [`with_public_message`](Self::with_public_message).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::internal("pool exhausted").with_public_message("Service busy.");
+    /// assert_eq!(err.public_message(), Some("Service busy."));
+    /// assert_eq!(err.message.as_deref(), Some("pool exhausted"));
+    /// ```
+    #[must_use]
+    pub fn public_message(&self) -> Option<&str> {
+        self.public_message.as_deref()
+    }
+
+    /// Severity of this error.
+    ///
+    /// Returns the override set by [`with_severity`](Self::with_severity), or
+    /// the default [`AppErrorKind::severity`] of the error kind.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, Severity};
+    ///
+    /// assert_eq!(AppError::internal("boom").severity(), Severity::Critical);
+    /// assert_eq!(AppError::validation("bad").severity(), Severity::Warning);
+    /// ```
+    #[must_use]
+    pub fn severity(&self) -> Severity {
+        self.severity.unwrap_or_else(|| self.kind.severity())
+    }
+
+    /// HTTP status returned to clients.
+    ///
+    /// Returns the override set by [`with_status`](Self::with_status), or
+    /// [`AppErrorKind::http_status`] of the error kind.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// assert_eq!(AppError::not_found("missing").status(), 404);
+    /// assert_eq!(
+    ///     AppError::not_found("missing").with_status(410).status(),
+    ///     410
+    /// );
+    /// ```
+    #[must_use]
+    pub fn status(&self) -> u16 {
+        self.status.unwrap_or_else(|| self.kind.http_status())
+    }
+
+    /// Tenant recorded by [`with_tenant`](Self::with_tenant).
+    ///
+    /// Returns `None` when the `tenant_id` field is missing or is not a
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::not_found("no such invoice").with_tenant("acme");
+    /// assert_eq!(err.tenant_id(), Some("acme"));
+    /// assert_eq!(AppError::internal("boom").tenant_id(), None);
+    /// ```
+    #[must_use]
+    pub fn tenant_id(&self) -> Option<&str> {
+        match self.metadata.get(TENANT_ID_KEY)? {
+            FieldValue::Str(id) => Some(id),
+            _ => None
+        }
+    }
+
+    /// Operation recorded by [`with_operation`](Self::with_operation).
+    ///
+    /// Returns `None` when the `operation` field is missing or is not a
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::conflict("stale version").with_operation("update_order");
+    /// assert_eq!(err.operation(), Some("update_order"));
+    /// assert_eq!(AppError::internal("boom").operation(), None);
+    /// ```
+    #[must_use]
+    pub fn operation(&self) -> Option<&str> {
+        match self.metadata.get(OPERATION_KEY)? {
+            FieldValue::Str(name) => Some(name),
+            _ => None
+        }
+    }
+
+    /// Custom counter labels added with
+    /// [`with_metric_label`](Self::with_metric_label), in insertion order.
+    pub fn metric_labels(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.metric_labels
+            .iter()
+            .map(|(key, value)| (*key, value.as_ref()))
+    }
+
+    /// [`ConflictReason`] recorded by
+    /// [`conflict_reason`](Self::conflict_reason).
+    ///
+    /// Returns `None` for errors of other kinds and for conflicts without a
+    /// recognised `conflict.reason` field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, ConflictReason};
+    ///
+    /// let err = AppError::conflict_reason("email taken", ConflictReason::Duplicate);
+    /// assert_eq!(err.conflict_cause(), Some(ConflictReason::Duplicate));
+    /// assert_eq!(AppError::conflict("busy").conflict_cause(), None);
+    /// ```
+    #[must_use]
+    pub fn conflict_cause(&self) -> Option<ConflictReason> {
+        if self.kind != AppErrorKind::Conflict {
+            return None;
+        }
+        match self.metadata.get(CONFLICT_REASON_KEY)? {
+            FieldValue::Str(name) => ConflictReason::from_name(name),
+            _ => None
+        }
+    }
+
     /// Borrow the backtrace, capturing it lazily when the `backtrace` feature
     /// is enabled.
     ///
@@ -56,6 +374,33 @@ impl Error {
         self.capture_backtrace()
     }
 
+    /// Render the backtrace as a string with `std`, `core`, `alloc` and
+    /// `masterror` frames removed.
+    ///
+    /// Uses the same capture rules as [`backtrace`](Self::backtrace), so the
+    /// `RUST_BACKTRACE` preference is respected for lazily captured traces.
+    /// Returns `None` when no backtrace was captured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "backtrace")]
+    /// # {
+    /// use std::backtrace::Backtrace;
+    ///
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::internal("test").with_backtrace(Backtrace::force_capture());
+    /// let rendered = err.backtrace_string().expect("captured");
+    /// assert!(!rendered.contains("std::rt::lang_start"));
+    /// # }
+    /// ```
+    #[cfg(feature = "backtrace")]
+    #[must_use]
+    pub fn backtrace_string(&self) -> Option<String> {
+        self.backtrace().and_then(render_filtered_backtrace)
+    }
+
     /// Returns a shared Arc reference to the backtrace.
     ///
     /// Internal method for efficient backtrace sharing between errors.
@@ -112,6 +457,130 @@ impl Error {
         }
     }
 
+    /// Whether the error maps to a 4xx HTTP status.
+    ///
+    /// Computed from [`status`](Self::status), so an override set with
+    /// `with_status` is honored while custom codes attached via `with_code`
+    /// do not affect the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// assert!(AppError::rate_limited("slow down").is_client_error());
+    /// assert!(!AppError::internal("boom").is_client_error());
+    /// ```
+    #[must_use]
+    pub fn is_client_error(&self) -> bool {
+        (400..500).contains(&self.status())
+    }
+
+    /// Whether the error maps to a 5xx HTTP status.
+    ///
+    /// Like [`is_client_error`](Self::is_client_error), this follows
+    /// [`status`](Self::status).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// assert!(AppError::dependency_unavailable("cache down").is_server_error());
+    /// assert!(!AppError::not_found("missing").is_server_error());
+    /// ```
+    #[must_use]
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&self.status())
+    }
+
+    /// Assert that the error has the given kind and return it unchanged.
+    ///
+    /// Works like [`Result::expect`] for kinds, so tests can keep chaining
+    /// assertions on the same error.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the kind differs. The message names the expected and
+    /// actual kinds together with the code and the rendered message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    ///
+    /// let err = AppError::not_found("no such user").expect_kind(AppErrorKind::NotFound);
+    /// assert_eq!(err.render_message(), "no such user");
+    /// ```
+    #[track_caller]
+    #[must_use]
+    pub fn expect_kind(self, kind: AppErrorKind) -> Self {
+        if self.kind != kind {
+            panic!(
+                "expected error kind {kind:?}, found {:?} (code: {}, message: {:?})",
+                self.kind,
+                self.code,
+                self.render_message()
+            );
+        }
+        self
+    }
+
+    /// Stable grouping key for error trackers.
+    ///
+    /// Derived from the kind, the code and the message with every run of
+    /// ASCII digits collapsed, so `"user 42 not found"` and
+    /// `"user 43 not found"` group together. Metadata, details and sources
+    /// are excluded because they usually carry volatile per-request values.
+    /// The value is the first eight bytes of a SHA-256 digest and stays the
+    /// same across processes and releases.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, field};
+    ///
+    /// let first = AppError::not_found("user 42 not found").with_field(field::str("request_id", "a"));
+    /// let second = AppError::not_found("user 7 not found").with_field(field::str("request_id", "b"));
+    /// assert_eq!(first.fingerprint(), second.fingerprint());
+    /// assert_ne!(
+    ///     first.fingerprint(),
+    ///     AppError::conflict("user 42 not found").fingerprint()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = Sha256::new();
+        hasher.update(self.kind.name().as_bytes());
+        hasher.update([0]);
+        hasher.update(self.code.as_str().as_bytes());
+        hasher.update([0]);
+        if let Some(message) = self.message.as_deref() {
+            update_message_template(&mut hasher, message);
+        }
+        let digest = hasher.finalize();
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(prefix)
+    }
+
+    /// [`fingerprint`](Self::fingerprint) as 16 lowercase hex digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::internal("boom");
+    /// let hex = err.fingerprint_hex();
+    /// assert_eq!(hex.len(), 16);
+    /// assert_eq!(u64::from_str_radix(&hex, 16), Ok(err.fingerprint()));
+    /// ```
+    #[must_use]
+    pub fn fingerprint_hex(&self) -> String {
+        format!("{:016x}", self.fingerprint())
+    }
+
     /// Emit telemetry (`tracing` event, metrics counter, backtrace capture).
     ///
     /// Downstream code can call this to guarantee telemetry after mutating the
@@ -129,6 +598,24 @@ impl Error {
         self.emit_telemetry();
     }
 
+    /// Emit telemetry like [`log`](Self::log), but only when `pred` holds.
+    ///
+    /// Useful to keep noisy client errors out of logs and metrics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::not_found("missing");
+    /// err.log_if(AppError::is_server_error);
+    /// ```
+    pub fn log_if(&self, pred: impl FnOnce(&Self) -> bool) {
+        if pred(self) {
+            self.emit_telemetry();
+        }
+    }
+
     /// Returns an iterator over the error chain, starting with this error.
     ///
     /// The iterator yields references to each error in the source chain,
@@ -153,15 +640,81 @@ impl Error {
     #[must_use]
     pub fn chain(&self) -> ErrorChain<'_> {
         ErrorChain {
-            current: Some(self as &(dyn CoreError + 'static))
+            current: Some(self as &(dyn CoreError + 'static)),
+            pending: Vec::new()
         }
     }
 
+    /// Borrow this error as a `std::error::Error` trait object.
+    ///
+    /// Useful for APIs that take `&(dyn Error + 'static)`, such as the error
+    /// field of `tracing` events, where `&AppError` is not coerced
+    /// automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let err = AppError::internal("db down").with_context(std::io::Error::other("disk offline"));
+    /// let dyn_err = err.as_dyn_error();
+    /// assert_eq!(
+    ///     dyn_err.source().map(ToString::to_string).as_deref(),
+    ///     Some("disk offline")
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn as_dyn_error(&self) -> &(dyn std::error::Error + 'static) {
+        self
+    }
+
+    /// Like [`chain`](Self::chain), but stops after at most `max` sources.
+    ///
+    /// The error itself is always yielded first, so the iterator produces at
+    /// most `max + 1` items. Use it to bound diagnostics over chains of
+    /// unknown depth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// let inner = AppError::timeout("upstream stalled");
+    /// let middle = AppError::service("retry exhausted").with_context(inner);
+    /// let outer = AppError::internal("request failed").with_context(middle);
+    ///
+    /// assert_eq!(outer.chain_limited(1).count(), 2);
+    /// assert_eq!(outer.chain_limited(0).count(), 1);
+    /// ```
+    pub fn chain_limited(&self, max: usize) -> Take<ErrorChain<'_>> {
+        self.chain().take(max.saturating_add(1))
+    }
+
+    /// Number of errors in the source chain, excluding this error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// assert_eq!(AppError::internal("boom").cause_count(), 0);
+    ///
+    /// let wrapped = AppError::internal("boom").with_context(AppError::timeout("slow"));
+    /// assert_eq!(wrapped.cause_count(), 1);
+    /// ```
+    #[must_use]
+    pub fn cause_count(&self) -> usize {
+        self.chain().skip(1).count()
+    }
+
     /// Returns the lowest-level source error in the chain.
     ///
     /// This traverses the error source chain until it finds an error with no
     /// further source, then returns a reference to it. If this error has no
-    /// source, it returns a reference to itself.
+    /// source, it returns a reference to itself. Sources attached via
+    /// [`with_additional_source`](Self::with_additional_source) are not
+    /// considered.
     ///
     /// # Examples
     ///
@@ -180,9 +733,11 @@ impl Error {
     /// ```
     #[must_use]
     pub fn root_cause(&self) -> &(dyn CoreError + 'static) {
-        self.chain()
-            .last()
-            .expect("chain always has at least one error")
+        let mut current: &(dyn CoreError + 'static) = self;
+        while let Some(next) = current.source() {
+            current = next;
+        }
+        current
     }
 
     /// Check whether the attached source error is of a concrete type.
@@ -215,6 +770,36 @@ impl Error {
         self.source_ref().is_some_and(|source| source.is::<E>())
     }
 
+    /// Check whether any error in the source chain is of a concrete type.
+    ///
+    /// Unlike [`is`](Self::is), which inspects only the immediate source, this
+    /// walks every nested source down to the root cause. The error itself is
+    /// not considered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// use masterror::AppError;
+    ///
+    /// let io_err = IoError::new(ErrorKind::TimedOut, "read timed out");
+    /// let inner = AppError::timeout("upstream stalled").with_context(io_err);
+    /// let outer = AppError::service("request failed").with_context(inner);
+    ///
+    /// assert!(!outer.is::<IoError>());
+    /// assert!(outer.root_cause_is::<IoError>());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn root_cause_is<E>(&self) -> bool
+    where
+        E: CoreError + 'static
+    {
+        self.chain().skip(1).any(|error| error.is::<E>())
+    }
+
     /// Attempt to take ownership of the source error as a concrete type.
     ///
     /// Succeeds when the immediate source (not this error itself, and not the
@@ -394,3 +979,60 @@ impl Error {
         Box::new(self)
     }
 }
+
+/// Feed `message` into `hasher` with each run of ASCII digits replaced by `#`.
+fn update_message_template(hasher: &mut Sha256, message: &str) {
+    let bytes = message.as_bytes();
+    let mut start = 0;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if bytes[idx].is_ascii_digit() {
+            hasher.update(&bytes[start..idx]);
+            hasher.update(b"#");
+            while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                idx += 1;
+            }
+            start = idx;
+        } else {
+            idx += 1;
+        }
+    }
+    hasher.update(&bytes[start..]);
+}
+
+/// Message of a chain entry; nested [`Error`]s contribute their own message
+/// rather than the full formatted report.
+pub(super) fn source_message(source: &(dyn CoreError + 'static)) -> String {
+    match source.downcast_ref::<Error>() {
+        Some(error) => error.render_message().into_owned(),
+        None => source.to_string()
+    }
+}
+
+#[cfg(feature = "serde_json")]
+const fn redaction_label(redaction: FieldRedaction) -> &'static str {
+    match redaction {
+        FieldRedaction::None => "none",
+        FieldRedaction::Redact => "redact",
+        FieldRedaction::Hash => "hash",
+        FieldRedaction::Last4 => "last4"
+    }
+}
+
+#[cfg(feature = "serde_json")]
+fn public_json_value(value: &FieldValue) -> JsonValue {
+    match value {
+        FieldValue::Str(value) => JsonValue::String(value.as_ref().into()),
+        FieldValue::I64(value) => JsonValue::from(*value),
+        FieldValue::U64(value) => JsonValue::from(*value),
+        FieldValue::F64(value) => match non_finite_label(*value) {
+            Some(label) => JsonValue::String(label.into()),
+            None => JsonNumber::from_f64(*value).map_or(JsonValue::Null, JsonValue::Number)
+        },
+        FieldValue::Bool(value) => JsonValue::Bool(*value),
+        FieldValue::Duration(value) => JsonValue::String(duration_to_string(*value)),
+        FieldValue::Uuid(value) => JsonValue::String(value.to_string()),
+        FieldValue::Ip(value) => JsonValue::String(value.to_string()),
+        FieldValue::Json(value) => value.clone()
+    }
+}
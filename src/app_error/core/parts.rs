@@ -0,0 +1,164 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use alloc::{borrow::Cow, string::String, sync::Arc, vec::Vec};
+use core::error::Error as CoreError;
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as JsonValue;
+
+use super::{
+    error::Error,
+    types::{MessageEditPolicy, StoredSource}
+};
+use crate::{AppCode, AppErrorKind, RetryAdvice, Severity, app_error::metadata::Metadata};
+
+/// Every public component of an [`Error`], produced by
+/// [`Error::into_parts`] and consumed by [`Error::from_parts`].
+///
+/// Backtraces and telemetry state are not part of the representation; an
+/// error rebuilt with [`Error::from_parts`] captures its own.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{AppCode, AppError, AppErrorKind};
+///
+/// let parts = AppError::not_found("no such user").into_parts();
+/// assert_eq!(parts.kind, AppErrorKind::NotFound);
+/// assert_eq!(parts.code, AppCode::NotFound);
+/// assert_eq!(parts.message.as_deref(), Some("no such user"));
+/// ```
+#[derive(Debug)]
+pub struct AppErrorParts {
+    /// Semantic error category.
+    pub kind:               AppErrorKind,
+    /// Stable machine-readable error code.
+    pub code:               AppCode,
+    /// Optional, public-friendly message.
+    pub message:            Option<Cow<'static, str>>,
+    /// Structured metadata for telemetry.
+    pub metadata:           Metadata,
+    /// Optional retry advice rendered as `Retry-After`.
+    pub retry:              Option<RetryAdvice>,
+    /// Optional authentication challenge for `WWW-Authenticate`.
+    pub www_authenticate:   Option<String>,
+    /// Optional list of allowed methods for the `Allow` header.
+    pub allow:              Option<String>,
+    /// Policy describing whether the message can be redacted.
+    pub edit_policy:        MessageEditPolicy,
+    /// Optional client-safe remediation hint.
+    pub help:               Option<String>,
+    /// Optional sanitized message shown to clients.
+    pub public_message:     Option<String>,
+    /// Optional severity overriding the default of `kind`.
+    pub severity:           Option<Severity>,
+    /// Optional HTTP status overriding the default of `kind`.
+    pub status:             Option<u16>,
+    /// Optional client language used to localize generic labels.
+    pub locale:             Option<String>,
+    /// Extra labels for the `error_total` counter.
+    pub metric_labels:      Vec<(&'static str, Cow<'static, str>)>,
+    /// Optional structured details exposed to clients.
+    #[cfg(feature = "serde_json")]
+    pub details:            Option<JsonValue>,
+    /// Optional textual details when JSON is unavailable.
+    #[cfg(not(feature = "serde_json"))]
+    pub details:            Option<String>,
+    /// Optional underlying cause.
+    ///
+    /// Owned sources are moved into a fresh [`Arc`], so an error rebuilt from
+    /// these parts lends the source out via
+    /// [`downcast_ref`](Error::downcast_ref) but no longer returns it by value
+    /// from [`downcast`](Error::downcast).
+    pub source:             Option<Arc<dyn CoreError + Send + Sync + 'static>>,
+    /// Independent sources attached with
+    /// [`with_additional_source`](Error::with_additional_source), in
+    /// attachment order.
+    pub additional_sources: Vec<Arc<dyn CoreError + Send + Sync + 'static>>
+}
+
+impl Error {
+    /// Split the error into all of its public components.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, field};
+    ///
+    /// let parts = AppError::timeout("upstream stalled")
+    ///     .with_retry_after_secs(5)
+    ///     .with_field(field::u64("attempt", 2))
+    ///     .into_parts();
+    /// assert_eq!(parts.retry.map(|advice| advice.after_seconds), Some(5));
+    /// assert_eq!(parts.metadata.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> AppErrorParts {
+        let inner = *self.inner;
+        AppErrorParts {
+            kind:               inner.kind,
+            code:               inner.code,
+            message:            inner.message,
+            metadata:           inner.metadata,
+            retry:              inner.retry,
+            www_authenticate:   inner.www_authenticate,
+            allow:              inner.allow,
+            edit_policy:        inner.edit_policy,
+            help:               inner.help,
+            public_message:     inner.public_message,
+            severity:           inner.severity,
+            status:             inner.status,
+            locale:             inner.locale,
+            metric_labels:      inner.metric_labels,
+            details:            inner.details,
+            source:             inner.source.map(StoredSource::into_shared),
+            additional_sources: inner
+                .additional_sources
+                .into_iter()
+                .map(StoredSource::into_shared)
+                .collect()
+        }
+    }
+
+    /// Rebuild an error from components produced by
+    /// [`into_parts`](Self::into_parts) or by an external representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppCode, AppError, AppErrorKind};
+    ///
+    /// let mut parts = AppError::internal("boom").into_parts();
+    /// parts.code = AppCode::new("PAYMENT_GATEWAY_DOWN");
+    /// let err = AppError::from_parts(parts);
+    /// assert_eq!(err.kind, AppErrorKind::Internal);
+    /// assert_eq!(err.code.as_str(), "PAYMENT_GATEWAY_DOWN");
+    /// ```
+    #[must_use]
+    pub fn from_parts(parts: AppErrorParts) -> Self {
+        let mut err = Self::new_raw(parts.kind, parts.message);
+        err.code = parts.code;
+        err.metadata = parts.metadata;
+        err.retry = parts.retry;
+        err.www_authenticate = parts.www_authenticate;
+        err.allow = parts.allow;
+        err.edit_policy = parts.edit_policy;
+        err.help = parts.help;
+        err.public_message = parts.public_message;
+        err.severity = parts.severity;
+        err.status = parts.status;
+        err.locale = parts.locale;
+        err.metric_labels = parts.metric_labels;
+        err.details = parts.details;
+        err.source = parts.source.map(StoredSource::Shared);
+        err.additional_sources = parts
+            .additional_sources
+            .into_iter()
+            .map(StoredSource::Shared)
+            .collect();
+        err.emit_telemetry();
+        err
+    }
+}
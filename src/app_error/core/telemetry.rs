@@ -2,8 +2,14 @@
 //
 // SPDX-License-Identifier: MIT
 
+#[cfg(feature = "metrics")]
+use alloc::{borrow::Cow, collections::BTreeSet};
 use core::sync::atomic::Ordering;
+#[cfg(feature = "metrics")]
+use std::sync::RwLock;
 
+#[cfg(feature = "metrics")]
+use metrics::Label;
 #[cfg(feature = "tracing")]
 use tracing::callsite::rebuild_interest_cache;
 #[cfg(feature = "tracing")]
@@ -16,6 +22,10 @@ use super::types::MessageEditPolicy;
 use super::{error::Error, types::CapturedBacktrace};
 #[cfg(any(feature = "metrics", feature = "tracing"))]
 use crate::AppErrorKind;
+#[cfg(feature = "tracing")]
+use crate::Severity;
+#[cfg(feature = "metrics")]
+use crate::app_error::metadata::intern_in;
 
 impl Error {
     /// Marks the error as dirty, requiring telemetry re-emission.
@@ -96,14 +106,19 @@ impl Error {
             let _ = self.capture_backtrace();
             #[cfg(feature = "metrics")]
             {
-                let code_label = self.code.as_str().to_owned();
-                let category_label = kind_label(self.kind).to_owned();
-                metrics::counter!(
-                    "error_total",
-                    "code" => code_label,
-                    "category" => category_label
-                )
-                .increment(1);
+                let mut labels = vec![
+                    Label::new("code", self.code.as_str().to_owned()),
+                    Label::new("category", kind_label(self.kind)),
+                ];
+                if let Some(operation) = self.operation() {
+                    labels.push(Label::new("operation", operation_label(operation)));
+                }
+                labels.extend(
+                    self.metric_labels
+                        .iter()
+                        .map(|(key, value)| Label::new(*key, value.clone().into_owned()))
+                );
+                metrics::counter!("error_total", labels).increment(1);
             }
         }
         #[cfg(feature = "tracing")]
@@ -113,8 +128,8 @@ impl Error {
     /// Flushes pending tracing events for this error.
     ///
     /// Emits a structured `tracing` event with error metadata if the tracing
-    /// dirty flag is set and the subscriber is interested in ERROR-level
-    /// events.
+    /// dirty flag is set and the subscriber is interested in events at the
+    /// level derived from the error [`Severity`].
     ///
     /// Only available when the `tracing` feature is enabled.
     #[cfg(feature = "tracing")]
@@ -122,9 +137,10 @@ impl Error {
         if !self.take_tracing_dirty() {
             return;
         }
-        if !tracing::event_enabled!(target: "masterror::error", Level::ERROR) {
+        let severity = self.severity();
+        if !event_enabled(severity) {
             rebuild_interest_cache();
-            if !tracing::event_enabled!(target: "masterror::error", Level::ERROR) {
+            if !event_enabled(severity) {
                 self.mark_tracing_dirty();
                 return;
             }
@@ -132,55 +148,71 @@ impl Error {
         let message = self.message.as_deref();
         let retry_seconds = self.retry.map(|value| value.after_seconds);
         let trace_id = log_mdc::get("trace_id", |value| value.map(str::to_owned));
-        event!(
-            target: "masterror::error",
-            Level::ERROR,
-            code = self.code.as_str(),
-            category = kind_label(self.kind),
-            message = message,
-            retry_seconds,
-            redactable = matches!(self.edit_policy, MessageEditPolicy::Redact),
-            metadata_len = self.metadata.len() as u64,
-            www_authenticate = self.www_authenticate.as_deref(),
-            trace_id = trace_id.as_deref(),
-            "app error constructed"
-        );
+        macro_rules! app_error_event {
+            ($level:expr) => {
+                event!(
+                    target: "masterror::error",
+                    $level,
+                    code = self.code.as_str(),
+                    category = kind_label(self.kind),
+                    severity = severity.as_str(),
+                    message = message,
+                    retry_seconds,
+                    redactable = matches!(self.edit_policy, MessageEditPolicy::Redact),
+                    metadata_len = self.metadata.len() as u64,
+                    www_authenticate = self.www_authenticate.as_deref(),
+                    trace_id = trace_id.as_deref(),
+                    tenant_id = self.tenant_id(),
+                    "app error constructed"
+                )
+            };
+        }
+        match severity {
+            Severity::Debug => app_error_event!(Level::DEBUG),
+            Severity::Info => app_error_event!(Level::INFO),
+            Severity::Warning => app_error_event!(Level::WARN),
+            Severity::Error | Severity::Critical => app_error_event!(Level::ERROR)
+        }
+    }
+}
+
+/// Distinct `operation` label values reported before further names are
+/// folded into `other`, keeping the counter cardinality bounded.
+#[cfg(feature = "metrics")]
+const MAX_OPERATION_LABELS: usize = 64;
+
+/// `operation` label for the `error_total` counter.
+///
+/// Known operations resolve under a shared read lock without allocating.
+#[cfg(feature = "metrics")]
+fn operation_label(operation: &str) -> Cow<'static, str> {
+    static SEEN: RwLock<BTreeSet<&'static str>> = RwLock::new(BTreeSet::new());
+    Cow::Borrowed(intern_in(&SEEN, operation, MAX_OPERATION_LABELS, "other"))
+}
+
+/// Whether the current subscriber records `masterror::error` events at the
+/// level of `severity`.
+#[cfg(feature = "tracing")]
+fn event_enabled(severity: Severity) -> bool {
+    match severity {
+        Severity::Debug => tracing::event_enabled!(target: "masterror::error", Level::DEBUG),
+        Severity::Info => tracing::event_enabled!(target: "masterror::error", Level::INFO),
+        Severity::Warning => tracing::event_enabled!(target: "masterror::error", Level::WARN),
+        Severity::Error | Severity::Critical => {
+            tracing::event_enabled!(target: "masterror::error", Level::ERROR)
+        }
     }
 }
 
 /// Converts error kind to a static label for telemetry.
 ///
-/// Returns a string representation of the error category for use in metrics
-/// and tracing.
+/// Uses the canonical [`AppErrorKind::name`], so metrics and tracing labels
+/// match the names accepted by `AppErrorKind::from_str`.
 ///
 /// # Arguments
 ///
 /// * `kind` - The error kind to convert
 #[cfg(any(feature = "metrics", feature = "tracing"))]
-pub(crate) fn kind_label(kind: AppErrorKind) -> &'static str {
-    match kind {
-        AppErrorKind::NotFound => "NotFound",
-        AppErrorKind::Validation => "Validation",
-        AppErrorKind::Conflict => "Conflict",
-        AppErrorKind::Unauthorized => "Unauthorized",
-        AppErrorKind::Forbidden => "Forbidden",
-        AppErrorKind::NotImplemented => "NotImplemented",
-        AppErrorKind::Internal => "Internal",
-        AppErrorKind::BadRequest => "BadRequest",
-        AppErrorKind::TelegramAuth => "TelegramAuth",
-        AppErrorKind::InvalidJwt => "InvalidJwt",
-        AppErrorKind::Database => "Database",
-        AppErrorKind::Service => "Service",
-        AppErrorKind::Config => "Config",
-        AppErrorKind::Turnkey => "Turnkey",
-        AppErrorKind::Timeout => "Timeout",
-        AppErrorKind::Network => "Network",
-        AppErrorKind::RateLimited => "RateLimited",
-        AppErrorKind::DependencyUnavailable => "DependencyUnavailable",
-        AppErrorKind::Serialization => "Serialization",
-        AppErrorKind::Deserialization => "Deserialization",
-        AppErrorKind::ExternalApi => "ExternalApi",
-        AppErrorKind::Queue => "Queue",
-        AppErrorKind::Cache => "Cache"
-    }
+pub(crate) const fn kind_label(kind: AppErrorKind) -> &'static str {
+    kind.name()
 }
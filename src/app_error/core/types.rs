@@ -2,8 +2,13 @@
 //
 // SPDX-License-Identifier: MIT
 
-use alloc::{boxed::Box, sync::Arc};
-use core::error::Error as CoreError;
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::{
+    error::Error as CoreError,
+    fmt::{self, Display, Formatter}
+};
+
+use super::error::Error;
 
 /// Attachments accepted by
 /// [`Error::with_context`](super::error::Error::with_context).
@@ -54,6 +59,14 @@ impl StoredSource {
         }
     }
 
+    /// Convert the stored source into a shared [`Arc`].
+    pub(crate) fn into_shared(self) -> Arc<dyn CoreError + Send + Sync + 'static> {
+        match self {
+            Self::Owned(source) => Arc::from(source),
+            Self::Shared(source) => source
+        }
+    }
+
     /// Mutably borrow the stored source when ownership is exclusive.
     ///
     /// Returns `None` for a `Shared` source whose [`Arc`] has other strong or
@@ -102,7 +115,8 @@ pub enum MessageEditPolicy {
 ///
 /// Created by [`Error::chain`](super::error::Error::chain). Walks through the
 /// error source chain using [`Error::source()`](CoreError::source) until
-/// reaching the root cause.
+/// reaching the root cause, then continues with the chains of sources attached
+/// via [`Error::with_additional_source`], depth first and in attachment order.
 ///
 /// # Examples
 ///
@@ -121,15 +135,74 @@ pub enum MessageEditPolicy {
 /// ```
 #[derive(Clone, Debug)]
 pub struct ErrorChain<'a> {
-    pub(super) current: Option<&'a (dyn CoreError + 'static)>
+    pub(super) current: Option<&'a (dyn CoreError + 'static)>,
+    pub(super) pending: Vec<&'a [StoredSource]>
+}
+
+impl<'a> ErrorChain<'a> {
+    /// Pop the next additional source, innermost error first.
+    fn next_additional(&mut self) -> Option<&'a (dyn CoreError + 'static)> {
+        loop {
+            let sources = self.pending.last_mut()?;
+            if let Some((first, rest)) = sources.split_first() {
+                *sources = rest;
+                return Some(first.as_dyn());
+            }
+            self.pending.pop();
+        }
+    }
 }
 
 impl<'a> Iterator for ErrorChain<'a> {
     type Item = &'a (dyn CoreError + 'static);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let current = self.current.take()?;
+        let current = match self.current.take() {
+            Some(current) => current,
+            None => self.next_additional()?
+        };
+        if let Some(error) = current.downcast_ref::<Error>()
+            && !error.additional_sources.is_empty()
+        {
+            self.pending.push(&error.additional_sources);
+        }
         self.current = current.source();
         Some(current)
     }
 }
+
+/// Plain-text source attached by
+/// [`Error::with_source_str`](super::error::Error::with_source_str).
+///
+/// Wraps causes that are only available as a message, such as strings
+/// returned across FFI boundaries, so they take part in
+/// [`chain`](super::error::Error::chain) and
+/// [`root_cause`](super::error::Error::root_cause) like any other source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringError {
+    message: String
+}
+
+impl StringError {
+    /// Wrap `message` as an error.
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into()
+        }
+    }
+
+    /// The wrapped message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for StringError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl CoreError for StringError {}
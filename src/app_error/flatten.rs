@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Flattening of `Serialize` values into [`Metadata`] fields.
+//!
+//! Backs `#[masterror(telemetry(serialize(...)))]`. Nested objects produce
+//! dotted keys (`ctx.user.id`), arrays produce indexed keys (`ctx.tags.0`)
+//! and `null` values are skipped. Metadata keys are `&'static str`, so
+//! generated keys are interned once per distinct name for the lifetime of the
+//! process. The interned set is capped; once it is full, new keys collapse
+//! into a single `…` field. Flatten values with a fixed shape rather than
+//! long arrays or maps keyed by user input.
+
+use serde::Serialize;
+use serde_json::{Number, Value as JsonValue};
+
+use super::metadata::{Field, FieldValue, Metadata, intern_name};
+
+/// Serialize `value` and insert every leaf as a field of `metadata`.
+///
+/// Scalars serialized at the top level are stored under `prefix` and dropped
+/// when no prefix is given. Values that fail to serialize are skipped, since
+/// telemetry must never prevent building the error.
+pub(crate) fn flatten_into<T>(metadata: &mut Metadata, prefix: Option<&str>, value: &T)
+where
+    T: Serialize + ?Sized
+{
+    if let Ok(json) = serde_json::to_value(value) {
+        let mut key = String::from(prefix.unwrap_or_default());
+        flatten_value(metadata, &mut key, json);
+    }
+}
+
+fn flatten_value(metadata: &mut Metadata, key: &mut String, value: JsonValue) {
+    let field_value = match value {
+        JsonValue::Null => return,
+        JsonValue::Bool(flag) => FieldValue::Bool(flag),
+        JsonValue::Number(number) => number_value(&number),
+        JsonValue::String(text) => FieldValue::Str(text.into()),
+        JsonValue::Array(items) => {
+            for (index, item) in items.into_iter().enumerate() {
+                with_segment(metadata, key, &index.to_string(), item);
+            }
+            return;
+        }
+        JsonValue::Object(entries) => {
+            for (name, item) in entries {
+                with_segment(metadata, key, &name, item);
+            }
+            return;
+        }
+    };
+    if !key.is_empty() {
+        metadata.insert(Field::new(intern_name(key), field_value));
+    }
+}
+
+fn with_segment(metadata: &mut Metadata, key: &mut String, segment: &str, value: JsonValue) {
+    let len = key.len();
+    if !key.is_empty() {
+        key.push('.');
+    }
+    key.push_str(segment);
+    flatten_value(metadata, key, value);
+    key.truncate(len);
+}
+
+fn number_value(number: &Number) -> FieldValue {
+    if let Some(value) = number.as_u64() {
+        FieldValue::U64(value)
+    } else if let Some(value) = number.as_i64() {
+        FieldValue::I64(value)
+    } else {
+        FieldValue::F64(number.as_f64().unwrap_or(f64::NAN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::flatten_into;
+    use crate::{FieldValue, Metadata};
+
+    #[test]
+    fn nested_objects_and_arrays_get_dotted_and_indexed_keys() {
+        let mut metadata = Metadata::new();
+        let value = json!({
+            "user": {"id": 7, "name": "alice"},
+            "tags": ["a", "b"],
+            "score": -1.5,
+            "missing": null
+        });
+        flatten_into(&mut metadata, Some("ctx"), &value);
+        assert_eq!(metadata.get("ctx.user.id"), Some(&FieldValue::U64(7)));
+        assert_eq!(
+            metadata.get("ctx.user.name"),
+            Some(&FieldValue::Str("alice".into()))
+        );
+        assert_eq!(
+            metadata.get("ctx.tags.0"),
+            Some(&FieldValue::Str("a".into()))
+        );
+        assert_eq!(
+            metadata.get("ctx.tags.1"),
+            Some(&FieldValue::Str("b".into()))
+        );
+        assert_eq!(metadata.get("ctx.score"), Some(&FieldValue::F64(-1.5)));
+        assert_eq!(metadata.get("ctx.missing"), None);
+        assert_eq!(metadata.len(), 5);
+    }
+
+    #[test]
+    fn scalars_need_a_prefix() {
+        let mut metadata = Metadata::new();
+        flatten_into(&mut metadata, None, &-3_i64);
+        assert!(metadata.is_empty());
+        flatten_into(&mut metadata, Some("attempt"), &-3_i64);
+        assert_eq!(metadata.get("attempt"), Some(&FieldValue::I64(-3)));
+    }
+}
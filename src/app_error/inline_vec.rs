@@ -37,12 +37,12 @@ const INLINE_CAPACITY: usize = 4;
 /// assert_eq!(vec.len(), 2);
 /// assert!(vec.is_inline()); // Still on stack
 /// ```
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct InlineVec<T> {
     storage: Storage<T>
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 enum Storage<T> {
     /// Inline storage for 0-4 elements using fixed arrays.
     ///
@@ -82,7 +82,7 @@ impl<T> InlineVec<T> {
     /// Returns `true` if the vector contains no elements.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        matches!(&self.storage, Storage::Empty)
+        self.len() == 0
     }
 
     /// Returns `true` if elements are stored inline (on stack).
@@ -112,6 +112,24 @@ impl<T> InlineVec<T> {
         };
     }
 
+    /// Reserves room for at least `additional` more elements.
+    ///
+    /// Does nothing while the result still fits inline; otherwise moves the
+    /// elements to a heap buffer sized for all of them, so the following
+    /// `additional` insertions do not reallocate.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len().saturating_add(additional);
+        match &mut self.storage {
+            Storage::Heap(vec) => vec.reserve(additional),
+            _ if required <= INLINE_CAPACITY => {}
+            _ => {
+                let mut vec = Vec::with_capacity(required);
+                vec.extend(core::mem::take(self));
+                self.storage = Storage::Heap(vec);
+            }
+        }
+    }
+
     /// Returns a reference to the element at the given index.
     #[must_use]
     pub fn get(&self, index: usize) -> Option<&T> {
@@ -187,6 +205,45 @@ impl<T> InlineVec<T> {
         };
     }
 
+    /// Removes and returns the element at the specified index, shifting
+    /// elements after it.
+    ///
+    /// Heap storage is kept once spilled, mirroring [`Vec::remove`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(index < len, "removal index out of bounds");
+
+        let (storage, removed) = match core::mem::take(&mut self.storage) {
+            Storage::Empty => unreachable!(),
+            Storage::One(a) => (Storage::Empty, a),
+            Storage::Two([a, b]) => match index {
+                0 => (Storage::One(b), a),
+                _ => (Storage::One(a), b)
+            },
+            Storage::Three([a, b, c]) => match index {
+                0 => (Storage::Two([b, c]), a),
+                1 => (Storage::Two([a, c]), b),
+                _ => (Storage::Two([a, b]), c)
+            },
+            Storage::Four([a, b, c, d]) => match index {
+                0 => (Storage::Three([b, c, d]), a),
+                1 => (Storage::Three([a, c, d]), b),
+                2 => (Storage::Three([a, b, d]), c),
+                _ => (Storage::Three([a, b, c]), d)
+            },
+            Storage::Heap(mut vec) => {
+                let removed = vec.remove(index);
+                (Storage::Heap(vec), removed)
+            }
+        };
+        self.storage = storage;
+        removed
+    }
+
     /// Returns an iterator over the elements.
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
@@ -229,6 +286,12 @@ impl<T> InlineVec<T> {
     }
 }
 
+impl<T: PartialEq> PartialEq for InlineVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
 impl<T> Default for InlineVec<T> {
     fn default() -> Self {
         Self::new()
@@ -441,6 +504,24 @@ mod tests {
         assert_eq!(&*vec, &[1, 2, 99, 3, 4]);
     }
 
+    #[test]
+    fn test_remove_inline() {
+        let mut vec: InlineVec<i32> = (1..=4).collect();
+        assert_eq!(vec.remove(1), 2);
+        assert_eq!(&*vec, &[1, 3, 4]);
+        assert_eq!(vec.remove(2), 4);
+        assert_eq!(vec.remove(0), 1);
+        assert_eq!(vec.remove(0), 3);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn test_remove_heap() {
+        let mut vec: InlineVec<i32> = (1..=5).collect();
+        assert_eq!(vec.remove(4), 5);
+        assert_eq!(&*vec, &[1, 2, 3, 4]);
+    }
+
     #[test]
     fn test_clone() {
         let mut vec: InlineVec<i32> = InlineVec::new();
@@ -504,6 +585,33 @@ mod tests {
         assert_eq!(vec1, vec2);
     }
 
+    #[test]
+    fn test_partial_eq_ignores_storage() {
+        let inline: InlineVec<i32> = (1..=2).collect();
+        let mut heap: InlineVec<i32> = (1..=5).collect();
+        heap.remove(4);
+        heap.remove(3);
+        heap.remove(2);
+        assert!(!heap.is_inline());
+        assert_eq!(inline, heap);
+    }
+
+    #[test]
+    fn test_reserve_moves_to_sized_heap_buffer() {
+        let mut vec: InlineVec<i32> = InlineVec::new();
+        vec.reserve(4);
+        assert!(vec.is_inline());
+        vec.push(1);
+        vec.reserve(5);
+        assert!(!vec.is_inline());
+        assert!(!vec.is_empty());
+        assert_eq!(&*vec, &[1]);
+        let mut reserved: InlineVec<i32> = InlineVec::new();
+        reserved.reserve(6);
+        assert!(reserved.is_empty());
+        assert_eq!(reserved, InlineVec::new());
+    }
+
     #[test]
     fn test_with_strings() {
         let mut vec: InlineVec<alloc::string::String> = InlineVec::new();
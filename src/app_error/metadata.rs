@@ -2,14 +2,97 @@
 //
 // SPDX-License-Identifier: MIT
 
-use alloc::{borrow::Cow, string::String};
+use alloc::{borrow::Cow, string::String, vec::Vec};
 use core::{
-    fmt::{Display, Formatter, Result as FmtResult, Write},
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
     net::IpAddr,
     time::Duration
 };
 
-use super::inline_vec::InlineVec;
+use super::{
+    inline_vec::InlineVec,
+    metadata_limit::{MetadataLimit, MetadataOverflow, metadata_limit},
+    redaction::{REDACTED_TEXT, hash_field_value, mask_last4_field_value}
+};
+
+/// Name of the counter field bumped by [`MetadataOverflow::Drop`].
+const DROPPED_FIELDS_KEY: &str = "metadata.dropped";
+
+/// Canonical metadata key written by `AppError::with_tenant`.
+pub(crate) const TENANT_ID_KEY: &str = "tenant_id";
+
+/// Canonical metadata key written by `AppError::with_operation`.
+pub(crate) const OPERATION_KEY: &str = "operation";
+
+/// Header names copied by [`Metadata::from_header_map`] that always carry
+/// credentials.
+#[cfg(feature = "http")]
+const SENSITIVE_HEADERS: [&str; 4] = [
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie"
+];
+
+/// Field names built at runtime, leaked once per distinct name.
+#[cfg(any(feature = "serde_json", feature = "http", feature = "span-fields"))]
+static INTERNED_NAMES: std::sync::RwLock<alloc::collections::BTreeSet<&'static str>> =
+    std::sync::RwLock::new(alloc::collections::BTreeSet::new());
+
+/// Distinct runtime field names kept before new names are folded together.
+#[cfg(any(feature = "serde_json", feature = "http", feature = "span-fields"))]
+const MAX_INTERNED_NAMES: usize = 1024;
+
+/// Field name used once [`MAX_INTERNED_NAMES`] distinct names exist.
+#[cfg(any(feature = "serde_json", feature = "http", feature = "span-fields"))]
+pub(crate) const OVERFLOW_NAME: &str = "…";
+
+/// Return a `'static` copy of a field name computed at runtime.
+///
+/// Each distinct name is allocated once and reused afterwards. At most
+/// [`MAX_INTERNED_NAMES`] names are leaked per process; once the set is full,
+/// unseen names map to [`OVERFLOW_NAME`], so keys derived from unbounded data
+/// cannot grow memory without limit. Known names are resolved under a shared
+/// read lock.
+#[cfg(any(feature = "serde_json", feature = "http", feature = "span-fields"))]
+pub(crate) fn intern_name(name: &str) -> &'static str {
+    intern_in(&INTERNED_NAMES, name, MAX_INTERNED_NAMES, OVERFLOW_NAME)
+}
+
+/// Intern `name` into `names`, returning `overflow` once `max` distinct names
+/// are stored.
+#[cfg(any(
+    feature = "serde_json",
+    feature = "http",
+    feature = "span-fields",
+    feature = "metrics"
+))]
+pub(crate) fn intern_in(
+    names: &std::sync::RwLock<alloc::collections::BTreeSet<&'static str>>,
+    name: &str,
+    max: usize,
+    overflow: &'static str
+) -> &'static str {
+    use std::sync::PoisonError;
+
+    if let Some(existing) = names
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+    {
+        return existing;
+    }
+    let mut names = names.write().unwrap_or_else(PoisonError::into_inner);
+    if let Some(existing) = names.get(name) {
+        return existing;
+    }
+    if names.len() >= max {
+        return overflow;
+    }
+    let leaked: &'static str = alloc::boxed::Box::leak(name.into());
+    names.insert(leaked);
+    leaked
+}
 
 /// Redaction policy associated with a metadata [`Field`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -35,7 +118,20 @@ use uuid::Uuid;
 /// callers to allocate temporary strings. Strings use [`Cow`] so `'static`
 /// literals avoid allocation while owned [`String`]s are supported when
 /// necessary.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Floats always render with a `.` decimal separator; `NaN` and infinities
+/// render as `"NaN"`, `"inf"` and `"-inf"`, also in JSON payloads where they
+/// have no numeric representation. Equality compares floats bitwise, so
+/// `NaN` equals itself and `0.0` differs from `-0.0`.
+///
+/// ```rust
+/// use masterror::FieldValue;
+///
+/// assert_eq!(FieldValue::F64(f64::NAN), FieldValue::F64(f64::NAN));
+/// assert_ne!(FieldValue::F64(0.0), FieldValue::F64(-0.0));
+/// assert_eq!(FieldValue::F64(f64::NEG_INFINITY).to_string(), "-inf");
+/// ```
+#[derive(Clone, Debug)]
 pub enum FieldValue {
     /// Human-readable string.
     Str(Cow<'static, str>),
@@ -58,6 +154,36 @@ pub enum FieldValue {
     Json(JsonValue)
 }
 
+impl PartialEq for FieldValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Str(left), Self::Str(right)) => left == right,
+            (Self::I64(left), Self::I64(right)) => left == right,
+            (Self::U64(left), Self::U64(right)) => left == right,
+            (Self::F64(left), Self::F64(right)) => left.to_bits() == right.to_bits(),
+            (Self::Bool(left), Self::Bool(right)) => left == right,
+            (Self::Uuid(left), Self::Uuid(right)) => left == right,
+            (Self::Duration(left), Self::Duration(right)) => left == right,
+            (Self::Ip(left), Self::Ip(right)) => left == right,
+            #[cfg(feature = "serde_json")]
+            (Self::Json(left), Self::Json(right)) => left == right,
+            _ => false
+        }
+    }
+}
+
+/// Label of a non-finite float (`NaN`, `inf` or `-inf`), or `None` when the
+/// value is finite.
+pub(crate) fn non_finite_label(value: f64) -> Option<&'static str> {
+    if value.is_nan() {
+        Some("NaN")
+    } else if value.is_infinite() {
+        Some(if value > 0.0 { "inf" } else { "-inf" })
+    } else {
+        None
+    }
+}
+
 impl Display for FieldValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -139,11 +265,19 @@ pub(crate) fn duration_to_string(duration: Duration) -> String {
 }
 
 /// Single metadata field – name plus value.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Field {
     name:      &'static str,
     value:     FieldValue,
-    redaction: FieldRedaction
+    redaction: FieldRedaction,
+    /// Insertion order assigned by [`Metadata`]; ignored by equality.
+    seq:       u32
+}
+
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.value == other.value && self.redaction == other.redaction
+    }
 }
 
 impl Field {
@@ -154,7 +288,8 @@ impl Field {
         Self {
             name,
             value,
-            redaction
+            redaction,
+            seq: 0
         }
     }
 
@@ -265,7 +400,7 @@ fn ends_with_ascii_case_insensitive(value: &str, suffix: &str) -> bool {
         )
 }
 
-fn contains_ascii_case_insensitive(haystack: &str, needle: &str) -> bool {
+pub(crate) fn contains_ascii_case_insensitive(haystack: &str, needle: &str) -> bool {
     if needle.is_empty() {
         return true;
     }
@@ -295,10 +430,18 @@ fn eq_ascii_case_insensitive_bytes(left: &[u8], right: &[u8]) -> bool {
 ///
 /// Most errors have 0-4 metadata fields. For these cases, all storage is
 /// inline (no heap allocation), saving ~100-200ns per error creation.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct Metadata {
     /// Fields stored sorted by name for binary search lookup.
-    fields: InlineVec<Field>
+    fields:   InlineVec<Field>,
+    /// Sequence number handed to the next inserted field.
+    next_seq: u32
+}
+
+impl PartialEq for Metadata {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields
+    }
 }
 
 impl Metadata {
@@ -306,7 +449,8 @@ impl Metadata {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            fields: InlineVec::new()
+            fields:   InlineVec::new(),
+            next_seq: 0
         }
     }
 
@@ -320,6 +464,54 @@ impl Metadata {
         meta
     }
 
+    /// Copy allowlisted request headers into metadata.
+    ///
+    /// Names in `allowlist` are matched case-insensitively and stored as
+    /// lowercase string fields; repeated headers are joined with `", "` and
+    /// headers missing from `headers` are skipped. `authorization`,
+    /// `proxy-authorization`, `cookie` and `set-cookie` are always marked
+    /// [`FieldRedaction::Redact`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use http::{HeaderMap, HeaderValue};
+    /// use masterror::{FieldRedaction, FieldValue, Metadata};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-request-id", HeaderValue::from_static("req-1"));
+    /// headers.insert("user-agent", HeaderValue::from_static("curl/8.0"));
+    ///
+    /// let meta = Metadata::from_header_map(&headers, &["X-Request-Id"]);
+    /// assert_eq!(
+    ///     meta.get("x-request-id"),
+    ///     Some(&FieldValue::Str("req-1".into()))
+    /// );
+    /// assert!(meta.get("user-agent").is_none());
+    /// ```
+    #[cfg(feature = "http")]
+    #[must_use]
+    pub fn from_header_map(headers: &http::HeaderMap, allowlist: &[&str]) -> Self {
+        let mut meta = Self::new();
+        for name in allowlist {
+            let name = name.to_ascii_lowercase();
+            let values: Vec<_> = headers
+                .get_all(name.as_str())
+                .iter()
+                .map(|value| String::from_utf8_lossy(value.as_bytes()))
+                .collect();
+            if values.is_empty() {
+                continue;
+            }
+            let mut field = field::str(intern_name(&name), values.join(", "));
+            if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                field.set_redaction(FieldRedaction::Redact);
+            }
+            meta.insert(field);
+        }
+        meta
+    }
+
     /// Number of fields stored in the metadata.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -332,11 +524,35 @@ impl Metadata {
         self.fields.is_empty()
     }
 
+    /// Reserve room for at least `additional` more fields.
+    ///
+    /// Up to four fields are stored inline; reserving beyond that allocates
+    /// the heap buffer once, up front, instead of when the fifth field is
+    /// inserted.
+    ///
+    /// ```rust
+    /// use masterror::{Metadata, field};
+    ///
+    /// let mut meta = Metadata::new();
+    /// meta.reserve(6);
+    /// meta.extend(["a", "b", "c", "d", "e", "f"].map(|name| field::u64(name, 1)));
+    /// assert_eq!(meta.len(), 6);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.fields.reserve(additional);
+    }
+
     /// Insert or replace a field and return the previous value.
     ///
-    /// Fields are kept sorted by name for efficient lookup.
-    pub fn insert(&mut self, field: Field) -> Option<FieldValue> {
+    /// Fields are kept sorted by name for efficient lookup. A standalone
+    /// container is unlimited: the process-wide [`MetadataLimit`] applies only
+    /// when fields reach an error through the `with_field` family or
+    /// `with_metadata`, not to direct inserts such as
+    /// `err.metadata.insert(..)`.
+    pub fn insert(&mut self, mut field: Field) -> Option<FieldValue> {
         let name = field.name;
+        field.seq = self.next_seq;
+        self.next_seq = self.next_seq.wrapping_add(1);
         match self.fields.binary_search_by_key(&name, |f| f.name) {
             Ok(idx) => {
                 // Replace existing field
@@ -351,7 +567,78 @@ impl Metadata {
         }
     }
 
+    /// Insert a field honoring the process-wide [`MetadataLimit`].
+    pub(crate) fn insert_limited(&mut self, field: Field) {
+        match metadata_limit() {
+            Some(limit) => self.insert_bounded(field, limit),
+            None => {
+                self.insert(field);
+            }
+        }
+    }
+
+    /// Insert a field, applying `limit` when the key is new and the field
+    /// count is already at the maximum.
+    pub(crate) fn insert_bounded(&mut self, field: Field, limit: MetadataLimit) {
+        let exists = self
+            .fields
+            .binary_search_by_key(&field.name, |f| f.name)
+            .is_ok();
+        if exists || field.name == DROPPED_FIELDS_KEY || self.bounded_len() < limit.max_fields {
+            self.insert(field);
+            return;
+        }
+        if matches!(limit.overflow, MetadataOverflow::ReplaceOldest)
+            && let Some(idx) = self.oldest_index()
+        {
+            self.fields.remove(idx);
+            self.insert(field);
+            return;
+        }
+        let dropped = match self.get(DROPPED_FIELDS_KEY) {
+            Some(FieldValue::U64(count)) => count.saturating_add(1),
+            _ => 1
+        };
+        self.insert(field::u64(DROPPED_FIELDS_KEY, dropped));
+    }
+
+    /// Re-insert every field oldest-first under `limit`, as if each had been
+    /// added through [`insert_bounded`](Self::insert_bounded).
+    pub(crate) fn into_bounded(self, limit: MetadataLimit) -> Self {
+        if self.bounded_len() <= limit.max_fields {
+            return self;
+        }
+        let next_seq = self.next_seq;
+        let mut fields: Vec<Field> = self.fields.into_iter().collect();
+        fields.sort_by_key(|field| core::cmp::Reverse(next_seq.wrapping_sub(field.seq)));
+        let mut bounded = Self::new();
+        for field in fields {
+            bounded.insert_bounded(field, limit);
+        }
+        bounded
+    }
+
+    /// Number of fields counted against a [`MetadataLimit`].
+    fn bounded_len(&self) -> usize {
+        self.fields.len() - usize::from(self.contains_key(DROPPED_FIELDS_KEY))
+    }
+
+    /// Index of the earliest inserted field, skipping the dropped counter.
+    fn oldest_index(&self) -> Option<usize> {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.name != DROPPED_FIELDS_KEY)
+            // Age survives sequence wrap-around as long as fewer than
+            // `u32::MAX` inserts separate the oldest and newest field.
+            .max_by_key(|(_, f)| self.next_seq.wrapping_sub(f.seq))
+            .map(|(idx, _)| idx)
+    }
+
     /// Extend metadata with additional fields.
+    ///
+    /// Like [`insert`](Self::insert), this ignores the process-wide
+    /// [`MetadataLimit`].
     pub fn extend(&mut self, fields: impl IntoIterator<Item = Field>) {
         for field in fields {
             self.insert(field);
@@ -376,6 +663,66 @@ impl Metadata {
             .map(|idx| &self.fields[idx])
     }
 
+    /// Whether a field with the given name is present.
+    #[must_use]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.fields.binary_search_by_key(&key, |f| f.name).is_ok()
+    }
+
+    /// Remove a field and return its value.
+    ///
+    /// The field's redaction policy is dropped together with the value, so a
+    /// later insert under the same name starts from its own policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{FieldValue, Metadata, field};
+    ///
+    /// let mut meta = Metadata::from_fields([field::secret("note", "abc"), field::u64("try", 1)]);
+    /// assert_eq!(meta.remove("note"), Some(FieldValue::Str("abc".into())));
+    /// assert!(!meta.contains_key("note"));
+    /// assert_eq!(meta.remove("note"), None);
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<FieldValue> {
+        self.fields
+            .binary_search_by_key(&key, |f| f.name)
+            .ok()
+            .map(|idx| self.fields.remove(idx).into_value())
+    }
+
+    /// Rename a field, keeping its value, redaction policy and insertion age.
+    ///
+    /// Returns `false` without changes when `from` is absent or `to` is
+    /// already present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{Metadata, field};
+    ///
+    /// let mut meta = Metadata::from_fields([field::str("req_id", "r-1")]);
+    /// assert!(meta.rename("req_id", "request_id"));
+    /// assert!(meta.contains_key("request_id"));
+    /// assert!(!meta.rename("req_id", "request_id"));
+    /// ```
+    pub fn rename(&mut self, from: &str, to: &'static str) -> bool {
+        if self.contains_key(to) {
+            return false;
+        }
+        let Ok(idx) = self.fields.binary_search_by_key(&from, |f| f.name) else {
+            return false;
+        };
+        let mut field = self.fields.remove(idx);
+        field.name = to;
+        let idx = self
+            .fields
+            .binary_search_by_key(&to, |f| f.name)
+            .unwrap_or_else(|idx| idx);
+        self.fields.insert(idx, field);
+        true
+    }
+
     /// Override the redaction policy for a specific field.
     pub fn set_redaction(&mut self, name: &'static str, redaction: FieldRedaction) {
         if let Ok(idx) = self.fields.binary_search_by_key(&name, |f| f.name) {
@@ -383,6 +730,40 @@ impl Metadata {
         }
     }
 
+    /// Override the redaction policy for every stored field.
+    ///
+    /// Only affects fields already present; later inserts keep their own
+    /// policy.
+    pub fn set_redaction_all(&mut self, redaction: FieldRedaction) {
+        for field in self.fields.iter_mut() {
+            field.set_redaction(redaction);
+        }
+    }
+
+    /// Visit every field with mutable access to its value and redaction.
+    ///
+    /// Field names are immutable so the sorted lookup order stays valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{FieldRedaction, FieldValue, Metadata, field};
+    ///
+    /// let mut meta = Metadata::from_fields([field::str("user_email", "a@b.c")]);
+    /// meta.scrub(|name, value, redaction| {
+    ///     if name.contains("email") {
+    ///         *value = FieldValue::Str("".into());
+    ///         *redaction = FieldRedaction::Redact;
+    ///     }
+    /// });
+    /// assert_eq!(meta.redaction("user_email"), Some(FieldRedaction::Redact));
+    /// ```
+    pub fn scrub(&mut self, mut f: impl FnMut(&str, &mut FieldValue, &mut FieldRedaction)) {
+        for field in self.fields.iter_mut() {
+            f(field.name, &mut field.value, &mut field.redaction);
+        }
+    }
+
     /// Retrieve the redaction policy for a field if present.
     #[must_use]
     pub fn redaction(&self, name: &'static str) -> Option<FieldRedaction> {
@@ -405,6 +786,198 @@ impl Metadata {
             .iter()
             .map(|f| (f.name, f.value(), f.redaction()))
     }
+
+    /// Compare against a later state of the metadata.
+    ///
+    /// `self` is treated as the old state and `other` as the new one. Keys
+    /// are compared by value only; redaction policy changes are not reported.
+    /// Every list in the returned [`MetadataDiff`] is sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{FieldValue, Metadata, field};
+    ///
+    /// let before = Metadata::from_fields([field::u64("attempt", 1), field::str("host", "a")]);
+    /// let after = Metadata::from_fields([field::u64("attempt", 2), field::bool("cached", true)]);
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.added, [("cached", FieldValue::Bool(true))]);
+    /// assert_eq!(diff.removed, [("host", FieldValue::Str("a".into()))]);
+    /// assert_eq!(
+    ///     diff.changed,
+    ///     [("attempt", FieldValue::U64(1), FieldValue::U64(2))]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Metadata) -> MetadataDiff {
+        let mut diff = MetadataDiff::default();
+        let mut old = self.fields.iter().peekable();
+        let mut new = other.fields.iter().peekable();
+        loop {
+            match (old.peek(), new.peek()) {
+                (Some(lhs), Some(rhs)) if lhs.name == rhs.name => {
+                    if lhs.value != rhs.value {
+                        diff.changed
+                            .push((lhs.name, lhs.value.clone(), rhs.value.clone()));
+                    }
+                    old.next();
+                    new.next();
+                }
+                (Some(lhs), Some(rhs)) if lhs.name < rhs.name => {
+                    diff.removed.push((lhs.name, lhs.value.clone()));
+                    old.next();
+                }
+                (_, Some(rhs)) => {
+                    diff.added.push((rhs.name, rhs.value.clone()));
+                    new.next();
+                }
+                (Some(lhs), None) => {
+                    diff.removed.push((lhs.name, lhs.value.clone()));
+                    old.next();
+                }
+                (None, None) => break
+            }
+        }
+        diff
+    }
+}
+
+/// Key-level difference between two [`Metadata`] states, produced by
+/// [`Metadata::diff`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetadataDiff {
+    /// Keys present only in the new state, with their values.
+    pub added:   Vec<(&'static str, FieldValue)>,
+    /// Keys present only in the old state, with their values.
+    pub removed: Vec<(&'static str, FieldValue)>,
+    /// Keys present in both states with different values, as
+    /// `(key, old, new)`.
+    pub changed: Vec<(&'static str, FieldValue, FieldValue)>
+}
+
+impl MetadataDiff {
+    /// Whether both states hold the same keys and values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Renders fields as a single `key=value` line separated by spaces.
+///
+/// Fields appear in the order they were last set. Redaction policies apply:
+/// `Redact` prints `[redacted]`, `Hash` prints the SHA-256 digest and `Last4`
+/// prints the masked value, omitting fields that cannot be masked. Text that
+/// is empty or contains whitespace, `=`, quotes or control characters is
+/// written as a quoted, escaped string so the line splits unambiguously.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{FieldRedaction, Metadata, field};
+///
+/// let meta = Metadata::from_fields([
+///     field::str("user", "alice"),
+///     field::u64("attempt", 2),
+///     field::str("query", "a b=c"),
+///     field::str("note", "internal").with_redaction(FieldRedaction::Redact)
+/// ]);
+/// assert_eq!(
+///     meta.to_string(),
+///     r#"user=alice attempt=2 query="a b=c" note=[redacted]"#
+/// );
+/// ```
+impl Display for Metadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // Fields are stored by name; walk them oldest-first by age without
+        // collecting. Sequence numbers are unique, so ages never tie.
+        let age = |field: &Field| self.next_seq.wrapping_sub(field.seq);
+        let mut previous = None;
+        let mut separator = "";
+        while let Some(field) = self
+            .fields
+            .iter()
+            .filter(|field| previous.is_none_or(|previous| age(field) < previous))
+            .max_by_key(|field| age(field))
+        {
+            previous = Some(age(field));
+            let masked = match field.redaction {
+                FieldRedaction::None => None,
+                FieldRedaction::Redact => {
+                    write!(f, "{separator}{}={REDACTED_TEXT}", field.name)?;
+                    separator = " ";
+                    continue;
+                }
+                FieldRedaction::Hash => Some(hash_field_value(&field.value)),
+                FieldRedaction::Last4 => match mask_last4_field_value(&field.value) {
+                    Some(text) => Some(text),
+                    None => continue
+                }
+            };
+            write!(f, "{separator}{}=", field.name)?;
+            separator = " ";
+            match (masked, &field.value) {
+                (Some(text), _) => write_display_text(f, &text)?,
+                (None, FieldValue::Str(text)) => write_display_text(f, text)?,
+                #[cfg(feature = "serde_json")]
+                (None, FieldValue::Json(value)) => {
+                    write_display_text(f, &alloc::string::ToString::to_string(value))?;
+                }
+                (None, value) => Display::fmt(value, f)?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write `text` bare, or quoted and escaped when it would break `key=value`
+/// splitting.
+fn write_display_text(f: &mut Formatter<'_>, text: &str) -> FmtResult {
+    let plain = !text.is_empty()
+        && !text
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '=' | '"' | '\\'));
+    if plain {
+        f.write_str(text)
+    } else {
+        write!(f, "{text:?}")
+    }
+}
+
+/// [`Debug`] view of [`Metadata`] that applies each field's redaction policy
+/// while keeping names and policies visible.
+pub(crate) struct RedactedMetadata<'a>(pub(crate) &'a Metadata);
+
+impl Debug for RedactedMetadata<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map()
+            .entries(
+                self.0
+                    .fields
+                    .iter()
+                    .map(|field| (field.name, RedactedField(field)))
+            )
+            .finish()
+    }
+}
+
+struct RedactedField<'a>(&'a Field);
+
+impl Debug for RedactedField<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let field = self.0;
+        let mut out = f.debug_struct("Field");
+        match field.redaction {
+            FieldRedaction::None => out.field("value", &field.value),
+            FieldRedaction::Hash => out.field("value", &hash_field_value(&field.value)),
+            FieldRedaction::Last4 => match mask_last4_field_value(&field.value) {
+                Some(masked) => out.field("value", &masked),
+                None => out.field("value", &format_args!("{REDACTED_TEXT}"))
+            },
+            FieldRedaction::Redact => out.field("value", &format_args!("{REDACTED_TEXT}"))
+        };
+        out.field("redaction", &field.redaction).finish()
+    }
 }
 
 impl IntoIterator for Metadata {
@@ -416,6 +989,47 @@ impl IntoIterator for Metadata {
     }
 }
 
+/// Insert every field in iteration order.
+///
+/// Like [`Metadata::insert`], a field replaces an existing one with the same
+/// name, together with its redaction policy.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{FieldValue, Metadata, field};
+///
+/// let mut meta = Metadata::new();
+/// meta.extend([field::u64("attempt", 1), field::u64("attempt", 2)]);
+/// assert_eq!(meta.get("attempt"), Some(&FieldValue::U64(2)));
+/// ```
+impl Extend<Field> for Metadata {
+    fn extend<I: IntoIterator<Item = Field>>(&mut self, fields: I) {
+        for field in fields {
+            self.insert(field);
+        }
+    }
+}
+
+/// Collect fields into metadata, as [`Metadata::from_fields`] does.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{Metadata, field};
+///
+/// let meta: Metadata = ["a", "b"]
+///     .into_iter()
+///     .map(|name| field::bool(name, true))
+///     .collect();
+/// assert_eq!(meta.len(), 2);
+/// ```
+impl FromIterator<Field> for Metadata {
+    fn from_iter<I: IntoIterator<Item = Field>>(fields: I) -> Self {
+        Self::from_fields(fields)
+    }
+}
+
 /// Factories for [`Field`] values.
 pub mod field {
     use alloc::borrow::Cow;
@@ -425,7 +1039,7 @@ pub mod field {
     use serde_json::Value as JsonValue;
     use uuid::Uuid;
 
-    use super::{Field, FieldValue};
+    use super::{Field, FieldRedaction, FieldValue};
 
     /// Build a string metadata field.
     #[must_use]
@@ -433,6 +1047,24 @@ pub mod field {
         Field::new(name, FieldValue::Str(value.into()))
     }
 
+    /// Build a string metadata field that is always redacted.
+    ///
+    /// Behaves like [`str`] but pins [`FieldRedaction::Redact`] regardless of
+    /// the field name, so sensitive values never reach public payloads. The
+    /// raw value stays available internally through
+    /// [`Metadata::get`](super::Metadata::get).
+    ///
+    /// ```
+    /// use masterror::{FieldRedaction, field};
+    ///
+    /// let field = field::secret("customer_note", "call me at 555-0100");
+    /// assert_eq!(field.redaction(), FieldRedaction::Redact);
+    /// ```
+    #[must_use]
+    pub fn secret(name: &'static str, value: impl Into<Cow<'static, str>>) -> Field {
+        str(name, value).with_redaction(FieldRedaction::Redact)
+    }
+
     /// Build an `i64` metadata field.
     #[must_use]
     pub fn i64(name: &'static str, value: i64) -> Field {
@@ -459,6 +1091,14 @@ pub mod field {
     }
 
     /// Build a boolean metadata field.
+    ///
+    /// ```
+    /// use masterror::{FieldValue, field};
+    ///
+    /// let (_, value, _) = field::bool("cache_hit", false).into_parts();
+    /// assert_eq!(value, FieldValue::Bool(false));
+    /// assert_eq!(value.to_string(), "false");
+    /// ```
     #[must_use]
     pub fn bool(name: &'static str, value: bool) -> Field {
         Field::new(name, FieldValue::Bool(value))
@@ -528,7 +1168,10 @@ mod tests {
     use serde_json::json;
     use uuid::Uuid;
 
-    use super::{FieldRedaction, FieldValue, Metadata, duration_to_string, field};
+    use super::{
+        FieldRedaction, FieldValue, Metadata, MetadataDiff, MetadataLimit, MetadataOverflow,
+        duration_to_string, field
+    };
 
     #[test]
     fn metadata_roundtrip() {
@@ -543,6 +1186,84 @@ mod tests {
         assert_eq!(meta.redaction("request_id"), Some(FieldRedaction::None));
     }
 
+    #[test]
+    fn metadata_remove_drops_value_and_redaction() {
+        let mut meta = Metadata::from_fields([
+            field::secret("customer_note", "call me"),
+            field::u64("attempt", 3)
+        ]);
+        assert_eq!(
+            meta.redaction("customer_note"),
+            Some(FieldRedaction::Redact)
+        );
+        assert_eq!(
+            meta.remove("customer_note"),
+            Some(FieldValue::Str(Cow::Borrowed("call me")))
+        );
+        assert_eq!(meta.redaction("customer_note"), None);
+        assert_eq!(meta.len(), 1);
+        meta.insert(field::str("customer_note", "again"));
+        assert_eq!(meta.redaction("customer_note"), Some(FieldRedaction::None));
+    }
+
+    #[test]
+    fn metadata_contains_key_tracks_inserts_and_removals() {
+        let mut meta = Metadata::new();
+        assert!(!meta.contains_key("tenant"));
+        meta.insert(field::str("tenant", "acme"));
+        assert!(meta.contains_key("tenant"));
+        assert_eq!(meta.remove("missing"), None);
+        meta.remove("tenant");
+        assert!(!meta.contains_key("tenant"));
+        assert!(meta.is_empty());
+    }
+
+    #[test]
+    fn bounded_insert_drops_new_fields_and_counts_them() {
+        let limit = MetadataLimit::new(2, MetadataOverflow::Drop);
+        let mut meta = Metadata::new();
+        meta.insert_bounded(field::u64("first", 1), limit);
+        meta.insert_bounded(field::u64("second", 2), limit);
+        assert_eq!(meta.len(), 2);
+        assert_eq!(meta.get("metadata.dropped"), None);
+        meta.insert_bounded(field::u64("third", 3), limit);
+        assert!(!meta.contains_key("third"));
+        assert_eq!(meta.get("metadata.dropped"), Some(&FieldValue::U64(1)));
+        meta.insert_bounded(field::u64("fourth", 4), limit);
+        assert_eq!(meta.get("metadata.dropped"), Some(&FieldValue::U64(2)));
+        meta.insert_bounded(field::u64("first", 10), limit);
+        assert_eq!(meta.get("first"), Some(&FieldValue::U64(10)));
+        assert_eq!(meta.get("metadata.dropped"), Some(&FieldValue::U64(2)));
+        assert_eq!(meta.len(), 3);
+    }
+
+    #[test]
+    fn bounded_insert_replaces_oldest_field() {
+        let limit = MetadataLimit::new(2, MetadataOverflow::ReplaceOldest);
+        let mut meta = Metadata::new();
+        meta.insert_bounded(field::u64("zeta", 1), limit);
+        meta.insert_bounded(field::u64("alpha", 2), limit);
+        meta.insert_bounded(field::u64("mid", 3), limit);
+        assert!(!meta.contains_key("zeta"));
+        assert!(meta.contains_key("alpha"));
+        assert!(meta.contains_key("mid"));
+        meta.insert_bounded(field::u64("alpha", 4), limit);
+        meta.insert_bounded(field::u64("next", 5), limit);
+        assert!(!meta.contains_key("mid"));
+        assert_eq!(meta.get("alpha"), Some(&FieldValue::U64(4)));
+        assert_eq!(meta.len(), 2);
+        assert_eq!(meta.get("metadata.dropped"), None);
+    }
+
+    #[test]
+    fn bounded_insert_with_zero_limit_only_counts() {
+        let limit = MetadataLimit::new(0, MetadataOverflow::ReplaceOldest);
+        let mut meta = Metadata::new();
+        meta.insert_bounded(field::str("tenant", "acme"), limit);
+        assert!(!meta.contains_key("tenant"));
+        assert_eq!(meta.get("metadata.dropped"), Some(&FieldValue::U64(1)));
+    }
+
     #[test]
     fn metadata_from_fields_is_deterministic() {
         let uuid = Uuid::nil();
@@ -584,6 +1305,29 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn metadata_stays_ordered_across_inline_spill() {
+        let mut meta = Metadata::from_fields([
+            field::u64("delta", 4),
+            field::u64("alpha", 1),
+            field::u64("charlie", 3),
+            field::u64("bravo", 2)
+        ]);
+        assert_eq!(meta.len(), 4);
+        meta.insert(field::u64("echo", 5));
+        assert_eq!(meta.len(), 5);
+        let names: Vec<_> = meta.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["alpha", "bravo", "charlie", "delta", "echo"]);
+        for (index, name) in names.iter().enumerate() {
+            assert_eq!(meta.get(name), Some(&FieldValue::U64(index as u64 + 1)));
+        }
+        assert_eq!(
+            meta.insert(field::u64("alpha", 10)),
+            Some(FieldValue::U64(1))
+        );
+        assert_eq!(meta.len(), 5);
+    }
+
     #[test]
     fn inserting_field_replaces_previous_value() {
         let mut meta = Metadata::from_fields([field::i64("count", 1)]);
@@ -592,6 +1336,21 @@ mod tests {
         assert_eq!(meta.get("count"), Some(&FieldValue::I64(2)));
     }
 
+    #[test]
+    fn secret_field_keeps_value_and_pins_redact_policy() {
+        let field = field::secret("customer_note", "call me");
+        assert_eq!(field.redaction(), FieldRedaction::Redact);
+        let meta = Metadata::from_fields([field]);
+        assert_eq!(
+            meta.get("customer_note"),
+            Some(&FieldValue::Str(Cow::Borrowed("call me")))
+        );
+        assert_eq!(
+            meta.redaction("customer_note"),
+            Some(FieldRedaction::Redact)
+        );
+    }
+
     #[test]
     fn default_redaction_applies_to_common_keys() {
         let password = field::str("password", Cow::Borrowed("secret"));
@@ -638,4 +1397,242 @@ mod tests {
         let text = duration_to_string(Duration::from_micros(1500));
         assert_eq!(text, "0.0015s");
     }
+
+    #[test]
+    fn display_lists_fields_in_insertion_order() {
+        let meta = Metadata::from_fields([
+            field::str("zone", "eu-1"),
+            field::u64("attempt", 3),
+            field::duration("elapsed", Duration::from_millis(1500)),
+            field::bool("cached", false)
+        ]);
+        assert_eq!(
+            meta.to_string(),
+            "zone=eu-1 attempt=3 elapsed=1.5s cached=false"
+        );
+        assert_eq!(Metadata::new().to_string(), "");
+    }
+
+    #[test]
+    fn bool_fields_compare_by_value() {
+        let meta = Metadata::from_fields([field::bool("cache_hit", true)]);
+        assert_eq!(meta.get("cache_hit"), Some(&FieldValue::Bool(true)));
+        assert_ne!(meta.get("cache_hit"), Some(&FieldValue::Bool(false)));
+        assert_eq!(
+            meta,
+            Metadata::from_fields([field::bool("cache_hit", true)])
+        );
+        assert_ne!(
+            meta,
+            Metadata::from_fields([field::bool("cache_hit", false)])
+        );
+        assert_eq!(meta.to_string(), "cache_hit=true");
+    }
+
+    #[test]
+    fn display_moves_overwritten_fields_to_the_end() {
+        let mut meta = Metadata::from_fields([field::u64("a", 1), field::u64("b", 2)]);
+        meta.insert(field::u64("a", 10));
+        assert_eq!(meta.to_string(), "b=2 a=10");
+    }
+
+    #[test]
+    fn display_quotes_ambiguous_text() {
+        let meta = Metadata::from_fields([
+            field::str("query", "a=1 b=2"),
+            field::str("quote", "say \"hi\""),
+            field::str("empty", ""),
+            field::str("path", "/v1/users")
+        ]);
+        assert_eq!(
+            meta.to_string(),
+            r#"query="a=1 b=2" quote="say \"hi\"" empty="" path=/v1/users"#
+        );
+    }
+
+    #[test]
+    fn display_applies_redaction_policies() {
+        let meta = Metadata::from_fields([
+            field::str("tenant", "acme").with_redaction(FieldRedaction::None),
+            field::str("secret", "hunter2"),
+            field::str("card_number", "4111111111111111"),
+            field::str("api_token", "abc"),
+            field::bool("flag", true).with_redaction(FieldRedaction::Last4)
+        ]);
+        let rendered = meta.to_string();
+        let digest = super::hash_field_value(&FieldValue::Str(Cow::Borrowed("abc")));
+        assert_eq!(
+            rendered,
+            format!(
+                "tenant=acme secret=[redacted] card_number=************1111 api_token={digest}"
+            )
+        );
+        assert!(!rendered.contains("hunter2"));
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_changed_keys() {
+        let before = Metadata::from_fields([
+            field::str("host", "db-1"),
+            field::u64("attempt", 1),
+            field::str("region", "eu")
+        ]);
+        let after = Metadata::from_fields([
+            field::str("host", "db-1"),
+            field::u64("attempt", 2),
+            field::str("trace_id", "t-9")
+        ]);
+        let diff = before.diff(&after);
+        assert_eq!(
+            diff.added,
+            [("trace_id", FieldValue::Str(Cow::Borrowed("t-9")))]
+        );
+        assert_eq!(
+            diff.removed,
+            [("region", FieldValue::Str(Cow::Borrowed("eu")))]
+        );
+        assert_eq!(
+            diff.changed,
+            [("attempt", FieldValue::U64(1), FieldValue::U64(2))]
+        );
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn diff_ignores_redaction_only_changes() {
+        let before = Metadata::from_fields([field::str("user", "alice")]);
+        let after = Metadata::from_fields([
+            field::str("user", "alice").with_redaction(FieldRedaction::Hash)
+        ]);
+        assert_eq!(before.diff(&after), MetadataDiff::default());
+        assert!(before.diff(&after).is_empty());
+    }
+
+    #[test]
+    fn diff_against_empty_lists_every_key() {
+        let meta = Metadata::from_fields([field::u64("a", 1), field::u64("b", 2)]);
+        let empty = Metadata::new();
+        let added = empty.diff(&meta);
+        assert_eq!(added.added.len(), 2);
+        assert!(added.removed.is_empty() && added.changed.is_empty());
+        let removed = meta.diff(&empty);
+        assert_eq!(removed.removed.len(), 2);
+        assert!(removed.added.is_empty() && removed.changed.is_empty());
+    }
+
+    #[test]
+    fn extend_trait_overwrites_duplicates_and_keeps_insertion_order() {
+        fn extend_all(meta: &mut Metadata, fields: Vec<super::Field>) {
+            Extend::extend(meta, fields);
+        }
+
+        let mut meta = Metadata::from_fields([field::str("user", "alice")]);
+        extend_all(
+            &mut meta,
+            vec![
+                field::u64("attempt", 1),
+                field::str("user", "bob").with_redaction(FieldRedaction::Redact),
+                field::u64("attempt", 2),
+            ]
+        );
+        assert_eq!(meta.len(), 2);
+        assert_eq!(meta.get("attempt"), Some(&FieldValue::U64(2)));
+        assert_eq!(meta.redaction("user"), Some(FieldRedaction::Redact));
+        assert_eq!(meta.to_string(), "user=[redacted] attempt=2");
+    }
+
+    #[test]
+    fn collect_matches_from_fields() {
+        let fields = || {
+            [
+                field::str("api_token", "abc"),
+                field::u64("attempt", 1),
+                field::u64("attempt", 3)
+            ]
+        };
+        let collected: Metadata = fields().into_iter().collect();
+        assert_eq!(collected, Metadata::from_fields(fields()));
+        assert_eq!(collected.get("attempt"), Some(&FieldValue::U64(3)));
+        assert_eq!(collected.redaction("api_token"), Some(FieldRedaction::Hash));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn from_header_map_copies_only_allowlisted_headers() {
+        use http::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-id", HeaderValue::from_static("req-7"));
+        headers.insert("user-agent", HeaderValue::from_static("curl/8.0"));
+        headers.append("accept", HeaderValue::from_static("text/html"));
+        headers.append("accept", HeaderValue::from_static("application/json"));
+
+        let meta = Metadata::from_header_map(&headers, &["X-Request-ID", "Accept", "x-missing"]);
+        assert_eq!(meta.len(), 2);
+        assert_eq!(
+            meta.get("x-request-id"),
+            Some(&FieldValue::Str(Cow::Borrowed("req-7")))
+        );
+        assert_eq!(
+            meta.get("accept"),
+            Some(&FieldValue::Str(Cow::Borrowed(
+                "text/html, application/json"
+            )))
+        );
+        assert_eq!(meta.redaction("accept"), Some(FieldRedaction::None));
+        assert!(meta.get("user-agent").is_none());
+        assert!(meta.get("x-missing").is_none());
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn from_header_map_redacts_sensitive_headers() {
+        use http::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", HeaderValue::from_static("Bearer abc"));
+        headers.insert("cookie", HeaderValue::from_static("sid=1"));
+        headers.insert("x-tenant", HeaderValue::from_static("acme"));
+
+        let meta = Metadata::from_header_map(&headers, &["Authorization", "Cookie", "X-Tenant"]);
+        assert_eq!(
+            meta.redaction("authorization"),
+            Some(FieldRedaction::Redact)
+        );
+        assert_eq!(meta.redaction("cookie"), Some(FieldRedaction::Redact));
+        assert_eq!(meta.redaction("x-tenant"), Some(FieldRedaction::None));
+        assert_eq!(
+            meta.get("authorization"),
+            Some(&FieldValue::Str(Cow::Borrowed("Bearer abc")))
+        );
+    }
+
+    #[cfg(any(feature = "serde_json", feature = "http"))]
+    #[test]
+    fn interned_names_fold_into_overflow_key_when_full() {
+        use std::{collections::BTreeSet, sync::RwLock};
+
+        use super::{OVERFLOW_NAME, intern_in};
+
+        let names = RwLock::new(BTreeSet::new());
+        let first = intern_in(&names, "ctx.tags.0", 2, OVERFLOW_NAME);
+        assert_eq!(first, "ctx.tags.0");
+        assert!(core::ptr::eq(
+            first,
+            intern_in(&names, "ctx.tags.0", 2, OVERFLOW_NAME)
+        ));
+        assert_eq!(
+            intern_in(&names, "ctx.tags.1", 2, OVERFLOW_NAME),
+            "ctx.tags.1"
+        );
+        assert_eq!(
+            intern_in(&names, "ctx.tags.2", 2, OVERFLOW_NAME),
+            OVERFLOW_NAME
+        );
+        assert_eq!(
+            intern_in(&names, "ctx.tags.1", 2, OVERFLOW_NAME),
+            "ctx.tags.1"
+        );
+        assert_eq!(names.read().unwrap().len(), 2);
+    }
 }
@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Process-wide cap on the number of metadata fields per error.
+//!
+//! The cap bounds memory when fields are derived from hostile or buggy input.
+//! It is disabled by default. Once configured through [`set_metadata_limit`],
+//! the `with_field` family of builders and `with_metadata` apply the chosen
+//! [`MetadataOverflow`] policy whenever a new field would exceed the limit.
+//! Replacing the value of an existing key never counts as overflow. Inserting
+//! into a [`Metadata`](crate::Metadata) directly, via `Metadata::insert`,
+//! `Metadata::extend` or `err.metadata`, is not limited.
+
+use core::sync::atomic::{AtomicU8, AtomicUsize, Ordering as AtomicOrdering};
+
+/// Sentinel stored in [`MAX_FIELDS`] while no limit is configured.
+const UNLIMITED: usize = usize::MAX;
+
+const OVERFLOW_DROP: u8 = 0;
+const OVERFLOW_REPLACE_OLDEST: u8 = 1;
+
+static MAX_FIELDS: AtomicUsize = AtomicUsize::new(UNLIMITED);
+static OVERFLOW: AtomicU8 = AtomicU8::new(OVERFLOW_DROP);
+
+/// What happens to a field inserted past the configured [`MetadataLimit`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MetadataOverflow {
+    /// Discard the new field and bump the `metadata.dropped` counter field.
+    ///
+    /// The counter is an `u64` field that does not count towards the limit.
+    #[default]
+    Drop,
+    /// Evict the field that was inserted first to make room for the new one.
+    ReplaceOldest
+}
+
+/// Maximum metadata field count together with its overflow policy.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{MetadataLimit, MetadataOverflow};
+///
+/// let limit = MetadataLimit::new(16, MetadataOverflow::ReplaceOldest);
+/// assert_eq!(limit.max_fields, 16);
+/// assert_eq!(limit.overflow, MetadataOverflow::ReplaceOldest);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MetadataLimit {
+    /// Maximum number of fields kept per error, excluding the
+    /// `metadata.dropped` counter.
+    pub max_fields: usize,
+    /// Policy applied when a new field would exceed `max_fields`.
+    pub overflow:   MetadataOverflow
+}
+
+impl MetadataLimit {
+    /// Create a limit of `max_fields` fields handled by `overflow`.
+    #[must_use]
+    pub const fn new(max_fields: usize, overflow: MetadataOverflow) -> Self {
+        Self {
+            max_fields,
+            overflow
+        }
+    }
+}
+
+/// Configure the process-wide metadata field limit.
+///
+/// Pass `None` to remove the limit again. Errors that already exceed a newly
+/// configured limit are left untouched; the policy only applies to later
+/// inserts.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{MetadataLimit, MetadataOverflow, metadata_limit, set_metadata_limit};
+///
+/// set_metadata_limit(Some(MetadataLimit::new(32, MetadataOverflow::Drop)));
+/// assert_eq!(metadata_limit().map(|limit| limit.max_fields), Some(32));
+/// set_metadata_limit(None);
+/// assert_eq!(metadata_limit(), None);
+/// ```
+pub fn set_metadata_limit(limit: Option<MetadataLimit>) {
+    match limit {
+        Some(limit) => {
+            let overflow = match limit.overflow {
+                MetadataOverflow::Drop => OVERFLOW_DROP,
+                MetadataOverflow::ReplaceOldest => OVERFLOW_REPLACE_OLDEST
+            };
+            OVERFLOW.store(overflow, AtomicOrdering::Release);
+            MAX_FIELDS.store(limit.max_fields, AtomicOrdering::Release);
+        }
+        None => MAX_FIELDS.store(UNLIMITED, AtomicOrdering::Release)
+    }
+}
+
+/// Currently configured metadata field limit, if any.
+#[must_use]
+pub fn metadata_limit() -> Option<MetadataLimit> {
+    let max_fields = MAX_FIELDS.load(AtomicOrdering::Acquire);
+    if max_fields == UNLIMITED {
+        return None;
+    }
+    let overflow = match OVERFLOW.load(AtomicOrdering::Acquire) {
+        OVERFLOW_REPLACE_OLDEST => MetadataOverflow::ReplaceOldest,
+        _ => MetadataOverflow::Drop
+    };
+    Some(MetadataLimit::new(max_fields, overflow))
+}
@@ -24,6 +24,10 @@ use super::{duration_to_string, metadata::FieldValue};
 /// [`FieldRedaction::Redact`](crate::FieldRedaction::Redact).
 pub(crate) const REDACTED_PLACEHOLDER: &str = "[REDACTED]";
 
+/// Placeholder used by the human-readable `Display` and `Debug` renderings of
+/// metadata, as opposed to [`REDACTED_PLACEHOLDER`] in serialized payloads.
+pub(crate) const REDACTED_TEXT: &str = "[redacted]";
+
 /// Fixed-capacity stack buffer used to format short values without
 /// allocating.
 struct StackBuffer<const N: usize> {
@@ -204,6 +208,16 @@ mod tests {
         assert_ne!(first, other);
     }
 
+    #[test]
+    fn hash_of_uuid_matches_canonical_hyphenated_text() {
+        let uuid = uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        let canonical = FieldValue::Str("67e55044-10b1-426f-9247-bb680e5fe0c8".into());
+        assert_eq!(
+            hash_field_value(&FieldValue::Uuid(uuid)),
+            hash_field_value(&canonical)
+        );
+    }
+
     #[test]
     fn hash_covers_uuid_and_ip() {
         let uuid = uuid::Uuid::nil();
@@ -0,0 +1,295 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Parsing of upstream `Retry-After` header values.
+//!
+//! Backs [`AppError::with_retry_after_header`](crate::AppError::with_retry_after_header).
+//! A value is either a non-negative number of seconds or an HTTP-date. Like
+//! RFC 9110 §5.6.7 requires of recipients, all three date forms are accepted:
+//! IMF-fixdate (`Sun, 06 Nov 1994 08:49:37 GMT`), the obsolete RFC 850 form
+//! (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime (`Sun Nov  6 08:49:37 1994`).
+
+/// Parse a `Retry-After` value as a delay in seconds.
+///
+/// Dates are resolved against the current system time and are only accepted
+/// with the `std` feature. Dates in the past yield a delay of zero.
+pub(crate) fn retry_after_seconds(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if let Some(secs) = delay_seconds(value) {
+        return Some(secs);
+    }
+    #[cfg(feature = "std")]
+    {
+        seconds_until(value, std::time::SystemTime::now())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        None
+    }
+}
+
+fn delay_seconds(value: &str) -> Option<u64> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    // Overlong digit strings saturate instead of being rejected.
+    Some(value.parse().unwrap_or(u64::MAX))
+}
+
+#[cfg(feature = "std")]
+fn seconds_until(value: &str, now: std::time::SystemTime) -> Option<u64> {
+    let now_secs = now
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let target =
+        std::time::UNIX_EPOCH + core::time::Duration::from_secs(http_date(value, now_secs)?);
+    Some(
+        target
+            .duration_since(now)
+            .map_or(0, |delay| delay.as_secs())
+    )
+}
+
+#[cfg(feature = "std")]
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+#[cfg(feature = "std")]
+const LONG_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday"
+];
+#[cfg(feature = "std")]
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"
+];
+
+/// Seconds since the Unix epoch of an HTTP-date in any of its three forms.
+///
+/// `now` (seconds since the epoch) resolves the century of two-digit RFC 850
+/// years: a date more than 50 years in the future is moved back 100 years.
+#[cfg(feature = "std")]
+fn http_date(value: &str, now: u64) -> Option<u64> {
+    let (year, month, day, time) = imf_fixdate(value)
+        .or_else(|| rfc850_date(value, now))
+        .or_else(|| asctime_date(value))?;
+    let mut clock = time.split(':');
+    let (hour, minute, second) = (
+        fixed_digits(clock.next()?, 2)?,
+        fixed_digits(clock.next()?, 2)?,
+        fixed_digits(clock.next()?, 2)?
+    );
+    if clock.next().is_some()
+        || year < 1970
+        || day == 0
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+    let days = days_since_epoch(year, month, day);
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second)
+}
+
+/// Year, month, day and clock of `Sun, 06 Nov 1994 08:49:37 GMT`.
+#[cfg(feature = "std")]
+fn imf_fixdate(value: &str) -> Option<(u64, u64, u64, &str)> {
+    let rest = value.strip_suffix(" GMT")?;
+    let (weekday, rest) = rest.split_once(", ")?;
+    if !WEEKDAYS.contains(&weekday) {
+        return None;
+    }
+    let mut parts = rest.split(' ');
+    let (day, month, year, time) = (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((
+        fixed_digits(year, 4)?,
+        month_number(month)?,
+        fixed_digits(day, 2)?,
+        time
+    ))
+}
+
+/// Year, month, day and clock of `Sunday, 06-Nov-94 08:49:37 GMT`.
+#[cfg(feature = "std")]
+fn rfc850_date(value: &str, now: u64) -> Option<(u64, u64, u64, &str)> {
+    const FIFTY_YEARS: u64 = 50 * 31_556_952;
+
+    let rest = value.strip_suffix(" GMT")?;
+    let (weekday, rest) = rest.split_once(", ")?;
+    if !LONG_WEEKDAYS.contains(&weekday) {
+        return None;
+    }
+    let (date, time) = rest.split_once(' ')?;
+    let mut parts = date.split('-');
+    let (day, month, year) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+    let (day, month) = (fixed_digits(day, 2)?, month_number(month)?);
+    let mut year = 1900 + fixed_digits(year, 2)?;
+    while days_since_epoch(year + 100, month, day) * 86_400 <= now.saturating_add(FIFTY_YEARS) {
+        year += 100;
+    }
+    Some((year, month, day, time))
+}
+
+/// Year, month, day and clock of `Sun Nov  6 08:49:37 1994`.
+#[cfg(feature = "std")]
+fn asctime_date(value: &str) -> Option<(u64, u64, u64, &str)> {
+    let (weekday, rest) = value.split_at_checked(3)?;
+    if !WEEKDAYS.contains(&weekday) || rest.len() != 21 || !rest.is_ascii() {
+        return None;
+    }
+    let bytes = rest.as_bytes();
+    if [0, 4, 7, 16].iter().any(|&index| bytes[index] != b' ') {
+        return None;
+    }
+    let day = match rest[5..7].strip_prefix(' ') {
+        Some(digit) => fixed_digits(digit, 1)?,
+        None => fixed_digits(&rest[5..7], 2)?
+    };
+    Some((
+        fixed_digits(&rest[17..], 4)?,
+        month_number(&rest[1..4])?,
+        day,
+        &rest[8..16]
+    ))
+}
+
+#[cfg(feature = "std")]
+fn month_number(name: &str) -> Option<u64> {
+    Some(MONTHS.iter().position(|month| *month == name)? as u64 + 1)
+}
+
+#[cfg(feature = "std")]
+fn fixed_digits(text: &str, len: usize) -> Option<u64> {
+    if text.len() != len || !text.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+#[cfg(feature = "std")]
+const fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31
+    }
+}
+
+/// Days from 1970-01-01 to the given civil date (Howard Hinnant's algorithm).
+#[cfg(feature = "std")]
+const fn days_since_epoch(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::delay_seconds;
+    #[cfg(feature = "std")]
+    use super::http_date;
+
+    /// 2026-01-01T00:00:00Z, the reference time for century resolution.
+    #[cfg(feature = "std")]
+    const NOW: u64 = 1_767_225_600;
+
+    #[test]
+    fn delay_seconds_accepts_only_digits() {
+        assert_eq!(delay_seconds("120"), Some(120));
+        assert_eq!(delay_seconds("0"), Some(0));
+        assert_eq!(delay_seconds("99999999999999999999999"), Some(u64::MAX));
+        assert_eq!(delay_seconds(""), None);
+        assert_eq!(delay_seconds("-5"), None);
+        assert_eq!(delay_seconds("1.5"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn http_date_parses_imf_fixdate() {
+        assert_eq!(http_date("Thu, 01 Jan 1970 00:00:00 GMT", NOW), Some(0));
+        assert_eq!(
+            http_date("Sun, 06 Nov 1994 08:49:37 GMT", NOW),
+            Some(784_111_777)
+        );
+        assert_eq!(
+            http_date("Tue, 29 Feb 2000 12:00:00 GMT", NOW),
+            Some(951_825_600)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn http_date_parses_obsolete_forms() {
+        assert_eq!(
+            http_date("Sunday, 06-Nov-94 08:49:37 GMT", NOW),
+            Some(784_111_777)
+        );
+        assert_eq!(
+            http_date("Sun Nov  6 08:49:37 1994", NOW),
+            Some(784_111_777)
+        );
+        assert_eq!(
+            http_date("Tue Feb 29 12:00:00 2000", NOW),
+            Some(951_825_600)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rfc850_years_more_than_fifty_years_ahead_fall_back_a_century() {
+        assert_eq!(
+            http_date("Tuesday, 29-Feb-00 12:00:00 GMT", NOW),
+            Some(951_825_600)
+        );
+        // 2075 is within 50 years of NOW; 2077 is not and resolves to 1977.
+        assert_eq!(
+            http_date("Wednesday, 01-Jan-75 00:00:00 GMT", NOW),
+            http_date("Wed, 01 Jan 2075 00:00:00 GMT", NOW)
+        );
+        assert_eq!(
+            http_date("Saturday, 01-Jan-77 00:00:00 GMT", NOW),
+            Some(220_924_800)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn http_date_rejects_malformed_dates() {
+        for value in [
+            "Sun, 06-Nov-94 08:49:37 GMT",
+            "Sunday, 06-Nov-1994 08:49:37 GMT",
+            "Sunday, 6-Nov-94 08:49:37 GMT",
+            "Sun Nov 6 08:49:37 1994",
+            "Sunday Nov  6 08:49:37 1994",
+            "Sun Nov  6 08:49:37 94",
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+            "Sun, 6 Nov 1994 08:49:37 GMT",
+            "Sun, 31 Nov 1994 08:49:37 GMT",
+            "Mon, 29 Feb 1900 00:00:00 GMT",
+            "Sun, 06 Nov 1994 24:00:00 GMT",
+            "Xyz, 06 Nov 1994 08:49:37 GMT",
+            "Wed, 31 Dec 1969 23:59:59 GMT"
+        ] {
+            assert_eq!(http_date(value, NOW), None, "{value}");
+        }
+    }
+}
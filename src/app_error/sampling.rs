@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Process-wide sampling of repeated errors.
+//!
+//! Identical errors raised in a hot path can flood logs. A sampler installed
+//! through [`set_error_sampler`] decides, per
+//! [`fingerprint`](Error::fingerprint), whether an occurrence is logged by
+//! [`Error::log_sampled`]. No sampler is installed by default, so every
+//! occurrence is logged.
+
+use alloc::boxed::Box;
+use core::{
+    fmt::{self, Debug, Formatter},
+    sync::atomic::{AtomicU64, Ordering}
+};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use super::core::Error;
+
+static SAMPLER: RwLock<Option<Arc<dyn ErrorSampler>>> = RwLock::new(None);
+
+/// Decides whether an occurrence of an error should be logged.
+///
+/// Implementations receive the error [`fingerprint`](Error::fingerprint), so
+/// errors that differ only in volatile details share one budget.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::ErrorSampler;
+///
+/// struct Never;
+///
+/// impl ErrorSampler for Never {
+///     fn should_sample(&self, _fingerprint: u64) -> bool {
+///         false
+///     }
+/// }
+///
+/// assert!(!Never.should_sample(42));
+/// ```
+pub trait ErrorSampler: Send + Sync {
+    /// Return `true` when this occurrence of `fingerprint` should be logged.
+    fn should_sample(&self, fingerprint: u64) -> bool;
+}
+
+/// Deterministic sampler that logs the first and then every `n`-th
+/// occurrence of each fingerprint.
+///
+/// Counts live in a fixed table of [`EveryNth::SLOTS`] atomic counters indexed
+/// by `fingerprint % SLOTS`, so memory stays constant however many distinct
+/// errors occur and sampling never takes a lock. Fingerprints that collide
+/// share one counter.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{ErrorSampler, EveryNth};
+///
+/// let sampler = EveryNth::new(3);
+/// let logged: Vec<bool> = (0..6).map(|_| sampler.should_sample(7)).collect();
+/// assert_eq!(logged, [true, false, false, true, false, false]);
+/// ```
+pub struct EveryNth {
+    n:      u64,
+    counts: Box<[AtomicU64]>
+}
+
+impl EveryNth {
+    /// Number of occurrence counters kept by each sampler.
+    pub const SLOTS: usize = 1024;
+
+    /// Create a sampler logging one in `n` occurrences.
+    ///
+    /// `n == 0` is treated as `1`, i.e. every occurrence is logged.
+    #[must_use]
+    pub fn new(n: u64) -> Self {
+        Self {
+            n:      n.max(1),
+            counts: (0..Self::SLOTS).map(|_| AtomicU64::new(0)).collect()
+        }
+    }
+
+    /// Sampling interval.
+    #[must_use]
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+}
+
+impl Debug for EveryNth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EveryNth")
+            .field("n", &self.n)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ErrorSampler for EveryNth {
+    fn should_sample(&self, fingerprint: u64) -> bool {
+        let slot = (fingerprint % Self::SLOTS as u64) as usize;
+        let count = self.counts[slot].fetch_add(1, Ordering::Relaxed);
+        count.is_multiple_of(self.n)
+    }
+}
+
+/// Install the process-wide error sampler.
+///
+/// Pass `None` to remove it again, after which every occurrence is logged.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use masterror::{EveryNth, error_sampler, set_error_sampler};
+///
+/// set_error_sampler(Some(Arc::new(EveryNth::new(100))));
+/// assert!(error_sampler().is_some());
+/// set_error_sampler(None);
+/// assert!(error_sampler().is_none());
+/// ```
+pub fn set_error_sampler(sampler: Option<Arc<dyn ErrorSampler>>) {
+    *SAMPLER.write().unwrap_or_else(PoisonError::into_inner) = sampler;
+}
+
+/// Currently installed error sampler, if any.
+#[must_use]
+pub fn error_sampler() -> Option<Arc<dyn ErrorSampler>> {
+    SAMPLER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+impl Error {
+    /// Ask the installed [`ErrorSampler`] whether this occurrence should be
+    /// logged.
+    ///
+    /// Returns `true` when no sampler is installed. Every call counts as one
+    /// occurrence for stateful samplers such as [`EveryNth`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// assert!(AppError::internal("boom").should_sample());
+    /// ```
+    #[must_use]
+    pub fn should_sample(&self) -> bool {
+        error_sampler().is_none_or(|sampler| sampler.should_sample(self.fingerprint()))
+    }
+
+    /// Emit telemetry like [`log`](Self::log), but only for occurrences
+    /// selected by the installed [`ErrorSampler`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppError;
+    ///
+    /// for attempt in 0..3 {
+    ///     AppError::timeout(format!("attempt {attempt} timed out")).log_sampled();
+    /// }
+    /// ```
+    pub fn log_sampled(&self) {
+        self.log_if(Self::should_sample);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::Ordering;
+
+    use super::{ErrorSampler, EveryNth};
+
+    #[test]
+    fn distinct_fingerprints_share_a_fixed_table() {
+        let sampler = EveryNth::new(2);
+        for fingerprint in 0..100_000_u64 {
+            sampler.should_sample(fingerprint.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        }
+        assert_eq!(sampler.counts.len(), EveryNth::SLOTS);
+        let total: u64 = sampler
+            .counts
+            .iter()
+            .map(|count| count.load(Ordering::Relaxed))
+            .sum();
+        assert_eq!(total, 100_000);
+    }
+
+    #[test]
+    fn colliding_fingerprints_share_a_budget() {
+        let sampler = EveryNth::new(2);
+        assert!(sampler.should_sample(5));
+        assert!(!sampler.should_sample(5 + EveryNth::SLOTS as u64));
+        assert!(sampler.should_sample(6));
+    }
+}
@@ -9,7 +9,8 @@ use std::{
     error::Error as StdError,
     fmt::{Display, Formatter, Result as FmtResult},
     io::{Error as IoError, ErrorKind as IoErrorKind},
-    sync::Arc
+    sync::Arc,
+    time::Duration
 };
 
 #[cfg(feature = "std")]
@@ -51,7 +52,7 @@ mod telemetry_support {
     };
 
     use tracing::{
-        Dispatch, Event, Subscriber,
+        Dispatch, Event, Level, Subscriber,
         field::{Field, Visit}
     };
     use tracing_subscriber::{
@@ -61,9 +62,12 @@ mod telemetry_support {
 
     #[derive(Default, Clone)]
     pub(super) struct RecordedEvent {
-        pub(super) trace_id: Option<String>,
-        pub(super) code:     Option<String>,
-        pub(super) category: Option<String>
+        pub(super) trace_id:  Option<String>,
+        pub(super) tenant_id: Option<String>,
+        pub(super) message:   Option<String>,
+        pub(super) code:      Option<String>,
+        pub(super) category:  Option<String>,
+        pub(super) level:     Option<Level>
     }
 
     pub(super) type RecordedEvents = Arc<Mutex<Vec<RecordedEvent>>>;
@@ -90,7 +94,10 @@ mod telemetry_support {
             if event.metadata().target() != "masterror::error" {
                 return;
             }
-            let mut record = RecordedEvent::default();
+            let mut record = RecordedEvent {
+                level: Some(*event.metadata().level()),
+                ..RecordedEvent::default()
+            };
             event.record(&mut EventVisitor {
                 record: &mut record
             });
@@ -107,6 +114,8 @@ mod telemetry_support {
             let normalized = normalize_debug(value);
             match field.name() {
                 "trace_id" => self.record.trace_id = Some(normalized),
+                "tenant_id" => self.record.tenant_id = Some(normalized),
+                "message" => self.record.message = Some(normalized),
                 "code" => self.record.code = Some(normalized),
                 "category" => self.record.category = Some(normalized),
                 _ => {}
@@ -127,7 +136,7 @@ mod telemetry_support {
 }
 
 use super::{AppError, FieldRedaction, FieldValue, MessageEditPolicy, field};
-use crate::{AppCode, AppErrorKind, Context, ErrorResponse, ResultExt};
+use crate::{AppCode, AppErrorKind, ConflictReason, Context, ErrorResponse, ResultExt};
 
 // --- Helpers -------------------------------------------------------------
 
@@ -232,6 +241,21 @@ fn constructors_match_kinds() {
     );
     assert_err_with_msg(AppError::queue("queue"), AppErrorKind::Queue, "queue");
     assert_err_with_msg(AppError::cache("cache"), AppErrorKind::Cache, "cache");
+    assert_err_with_msg(
+        AppError::payload_too_large("too big"),
+        AppErrorKind::PayloadTooLarge,
+        "too big"
+    );
+    assert_err_with_msg(
+        AppError::unsupported_media_type("xml"),
+        AppErrorKind::UnsupportedMediaType,
+        "xml"
+    );
+    assert_err_with_msg(AppError::locked("busy"), AppErrorKind::Locked, "busy");
+    assert_err_bare(
+        AppError::method_not_allowed(&["GET"]),
+        AppErrorKind::MethodNotAllowed
+    );
 }
 
 #[cfg(feature = "std")]
@@ -292,6 +316,32 @@ fn retry_and_www_authenticate_are_attached() {
     assert_eq!(err.www_authenticate.as_deref(), Some("Bearer"));
 }
 
+#[test]
+fn bearer_challenge_formats_present_parameters() {
+    let bare = AppError::unauthorized("login required").with_bearer_challenge(None, None, None);
+    assert_eq!(bare.www_authenticate.as_deref(), Some("Bearer"));
+    let full = AppError::unauthorized("token expired").with_bearer_challenge(
+        Some("example"),
+        Some("invalid_token"),
+        Some("The access token expired")
+    );
+    assert_eq!(
+        full.www_authenticate.as_deref(),
+        Some(
+            r#"Bearer realm="example", error="invalid_token", error_description="The access token expired""#
+        )
+    );
+    let partial = AppError::forbidden("scope missing").with_bearer_challenge(
+        None,
+        Some("insufficient_scope"),
+        Some(r#"needs "admin" \ write"#)
+    );
+    assert_eq!(
+        partial.www_authenticate.as_deref(),
+        Some(r#"Bearer error="insufficient_scope", error_description="needs \"admin\" \\ write""#)
+    );
+}
+
 #[test]
 fn context_moves_dynamic_code_without_cloning() {
     let dynamic_code =
@@ -345,6 +395,57 @@ fn dynamic_codes_flow_into_responses() {
     assert_eq!(response.code, custom);
 }
 
+#[cfg(feature = "serde_json")]
+#[test]
+fn metadata_json_keeps_only_public_fields() {
+    use serde_json::json;
+    let err = AppError::timeout("slow")
+        .with_field(field::secret("customer_note", "call me"))
+        .with_field(
+            field::str("card_number", "4111111111111111").with_redaction(FieldRedaction::Last4)
+        )
+        .with_field(field::str("api_token", "abc").with_redaction(FieldRedaction::Hash))
+        .with_field(field::duration("elapsed", Duration::from_millis(1500)))
+        .with_field(field::f64("ratio", f64::NAN))
+        .with_field(field::bool("cached", false));
+    let json = err.metadata_json();
+    assert_eq!(
+        json,
+        json!({"elapsed": "1.5s", "ratio": "NaN", "cached": false})
+    );
+    assert!(json.get("customer_note").is_none());
+    assert_eq!(AppError::internal("boom").metadata_json(), json!({}));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn debug_report_includes_chain_and_all_metadata() {
+    use serde_json::json;
+    let root = std::io::Error::other("connection reset");
+    let middle = AppError::timeout("upstream stalled").with_context(root);
+    let err = AppError::service("checkout failed")
+        .with_field(field::str("request_id", "r-1"))
+        .with_field(field::secret("card", "4111111111111111"))
+        .with_retry_after_secs(5)
+        .with_context(middle);
+    let report = err.debug_report();
+    assert_eq!(report["kind"], "Service");
+    assert_eq!(report["code"], "SERVICE");
+    assert_eq!(report["message"], "checkout failed");
+    assert_eq!(report["retry_after_seconds"], 5);
+    assert_eq!(
+        report["sources"],
+        json!(["upstream stalled", "connection reset"])
+    );
+    assert_eq!(
+        report["metadata"],
+        json!({
+            "request_id": {"value": "r-1", "redaction": "none"},
+            "card": {"value": "4111111111111111", "redaction": "redact"}
+        })
+    );
+}
+
 #[cfg(feature = "serde_json")]
 #[test]
 fn with_details_json_attaches_payload() {
@@ -354,6 +455,40 @@ fn with_details_json_attaches_payload() {
     assert_eq!(err.details, Some(payload));
 }
 
+#[cfg(feature = "serde_json")]
+#[test]
+fn details_object_builds_payload_incrementally() {
+    use serde_json::json;
+    let mut err = AppError::validation("invalid");
+    err.details_object().insert("field".into(), json!("email"));
+    err.details_object().insert("attempts".into(), json!(3));
+    assert_eq!(err.details, Some(json!({"field": "email", "attempts": 3})));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn details_object_preserves_existing_object_keys() {
+    use serde_json::json;
+    let mut err = AppError::validation("invalid").with_details_json(json!({"field": "email"}));
+    err.details_object().insert("reason".into(), json!("taken"));
+    assert_eq!(
+        err.details,
+        Some(json!({"field": "email", "reason": "taken"}))
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn details_object_wraps_non_object_details() {
+    use serde_json::json;
+    let mut err = AppError::validation("invalid").with_details_json(json!(["a", "b"]));
+    err.details_object().insert("field".into(), json!("email"));
+    assert_eq!(
+        err.details,
+        Some(json!({"value": ["a", "b"], "field": "email"}))
+    );
+}
+
 #[cfg(feature = "serde_json")]
 #[test]
 fn with_details_serialization_failure_is_bad_request() {
@@ -373,6 +508,38 @@ fn with_details_serialization_failure_is_bad_request() {
     assert!(matches!(err.kind, AppErrorKind::BadRequest));
 }
 
+#[cfg(feature = "serde_json")]
+#[test]
+fn with_details_or_skip_attaches_serializable_payload() {
+    use serde_json::json;
+    let err = AppError::conflict("duplicate").with_details_or_skip(json!({"field": "email"}));
+    assert_eq!(err.details, Some(json!({"field": "email"})));
+    assert!(err.metadata().get("details.error").is_none());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn with_details_or_skip_keeps_error_when_serialization_fails() {
+    use serde::{Serialize, Serializer};
+    struct Failing;
+    impl Serialize for Failing {
+        fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer
+        {
+            Err(serde::ser::Error::custom("nope"))
+        }
+    }
+    let err = AppError::conflict("duplicate").with_details_or_skip(Failing);
+    assert_eq!(err.kind, AppErrorKind::Conflict);
+    assert_eq!(err.message.as_deref(), Some("duplicate"));
+    assert!(err.details.is_none());
+    assert_eq!(
+        err.metadata().get("details.error"),
+        Some(&FieldValue::Str("nope".into()))
+    );
+}
+
 #[cfg(not(feature = "serde_json"))]
 #[test]
 fn with_details_text_attaches_payload() {
@@ -380,6 +547,113 @@ fn with_details_text_attaches_payload() {
     assert_eq!(err.details.as_deref(), Some("retry later"));
 }
 
+#[test]
+fn aggregate_picks_most_severe_kind() {
+    let err = AppError::aggregate(vec![
+        AppError::validation("bad email"),
+        AppError::service("downstream"),
+        AppError::cache("evicted"),
+    ]);
+    assert_err_with_msg(err, AppErrorKind::Service, "3 errors occurred");
+
+    let err = AppError::aggregate(vec![
+        AppError::conflict("duplicate"),
+        AppError::not_found("missing"),
+    ]);
+    assert_eq!(err.kind, AppErrorKind::Conflict);
+    assert_eq!(
+        err.metadata().get("errors.count"),
+        Some(&FieldValue::U64(2))
+    );
+}
+
+#[test]
+fn aggregate_ranks_by_status_override() {
+    let err = AppError::aggregate(vec![
+        AppError::conflict("duplicate"),
+        AppError::bad_request("upstream rejected").with_status(503),
+    ]);
+    assert_eq!(err.kind, AppErrorKind::BadRequest);
+    assert_eq!(err.status(), 503);
+    assert!(err.is_server_error());
+
+    let err = AppError::aggregate(vec![
+        AppError::internal("masked").with_status(404),
+        AppError::validation("bad email"),
+    ]);
+    assert_eq!(err.kind, AppErrorKind::Internal);
+    assert_eq!(err.status(), 404);
+}
+
+#[test]
+fn aggregate_single_error_uses_singular_message() {
+    let err = AppError::aggregate(vec![AppError::timeout("slow")]);
+    assert_err_with_msg(err, AppErrorKind::Timeout, "1 error occurred");
+}
+
+#[test]
+fn aggregate_empty_input_is_bare_internal() {
+    let err = AppError::aggregate(Vec::new());
+    assert_eq!(err.kind, AppErrorKind::Internal);
+    assert!(err.message.is_none());
+    assert_eq!(
+        err.metadata().get("errors.count"),
+        Some(&FieldValue::U64(0))
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn aggregate_collects_sub_error_details() {
+    use serde_json::json;
+    let err = AppError::aggregate(vec![
+        AppError::not_found("missing user"),
+        AppError::internal("db password leaked").redactable(),
+    ]);
+    assert_eq!(
+        err.details,
+        Some(json!({
+            "errors": [
+                {"status": 404, "code": "NOT_FOUND", "message": "missing user"},
+                {"status": 500, "code": "INTERNAL", "message": "Internal server error"}
+            ]
+        }))
+    );
+}
+
+#[test]
+fn locale_does_not_translate_internal_messages() {
+    let err = AppError::bare(AppErrorKind::NotFound).with_locale("ru");
+    assert_eq!(err.render_message(), AppErrorKind::NotFound.label());
+    let debug = format!("{err:?}");
+    assert!(!debug.contains("Не найдено"), "{debug}");
+
+    let err = err.context_str("ctx");
+    assert_eq!(err.message.as_deref(), Some("ctx: Not found"));
+}
+
+#[test]
+fn status_class_follows_status_override() {
+    let err = AppError::not_found("gone").with_status(500);
+    assert!(err.is_server_error());
+    assert!(!err.is_client_error());
+    let err = AppError::internal("upstream rejected input").with_status(422);
+    assert!(err.is_client_error());
+    assert!(!err.is_server_error());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn aggregate_details_report_status_override() {
+    let err = AppError::aggregate(vec![
+        AppError::not_found("missing user").with_status(410),
+        AppError::internal("boom"),
+    ]);
+    let details = err.details.as_ref().expect("aggregate details");
+    assert_eq!(details["errors"][0]["status"], 410);
+    assert_eq!(details["errors"][1]["status"], 500);
+}
+
 #[test]
 fn context_with_preserves_default_redaction() {
     let err = super::Context::new(AppErrorKind::Service)
@@ -466,6 +740,360 @@ fn app_error_redact_field_updates_metadata() {
     );
 }
 
+#[test]
+fn app_error_redact_all_fields_applies_policy_to_every_field() {
+    let err = AppError::internal("boom")
+        .with_field(field::str("user_id", "42"))
+        .with_field(field::u64("attempt", 3))
+        .with_field(field::str("password", "hunter2"))
+        .redact_all_fields(FieldRedaction::Hash);
+    for name in ["user_id", "attempt", "password"] {
+        assert_eq!(err.metadata().redaction(name), Some(FieldRedaction::Hash));
+    }
+    assert_eq!(
+        err.metadata().get("user_id"),
+        Some(&FieldValue::Str(Cow::Borrowed("42")))
+    );
+    assert_eq!(err.metadata().get("attempt"), Some(&FieldValue::U64(3)));
+}
+
+#[test]
+fn with_kind_reclassifies_and_keeps_custom_code() {
+    let err = AppError::service("pool exhausted")
+        .with_code(AppCode::new("DB_POOL_EXHAUSTED"))
+        .with_field(field::u64("pool_size", 8))
+        .with_context(IoError::other("no connections"));
+    assert_eq!(err.kind.http_status(), 500);
+    let err = err.with_kind(AppErrorKind::DependencyUnavailable);
+    assert_eq!(err.kind, AppErrorKind::DependencyUnavailable);
+    assert_eq!(err.kind.http_status(), 503);
+    assert_eq!(err.code.as_str(), "DB_POOL_EXHAUSTED");
+    assert_eq!(err.message.as_deref(), Some("pool exhausted"));
+    assert!(err.metadata().contains_key("pool_size"));
+    assert!(err.is::<IoError>());
+    let response = ErrorResponse::from(&err);
+    assert_eq!(response.status, 503);
+    assert_eq!(response.code.as_str(), "DB_POOL_EXHAUSTED");
+}
+
+#[test]
+fn with_category_from_status_maps_known_status() {
+    let err = AppError::service("user lookup failed")
+        .with_field(field::str("upstream", "accounts"))
+        .with_category_from_status(404);
+    assert_eq!(err.kind, AppErrorKind::NotFound);
+    assert_eq!(err.message.as_deref(), Some("user lookup failed"));
+    assert!(err.metadata().contains_key("upstream"));
+    assert_eq!(
+        err.metadata().get("upstream.status"),
+        Some(&FieldValue::U64(404))
+    );
+}
+
+#[test]
+fn with_category_from_status_falls_back_to_internal() {
+    let err = AppError::bad_request("teapot").with_category_from_status(418);
+    assert_eq!(err.kind, AppErrorKind::Internal);
+    assert_eq!(err.message.as_deref(), Some("teapot"));
+    assert_eq!(
+        err.metadata().get("upstream.status"),
+        Some(&FieldValue::U64(418))
+    );
+}
+
+#[test]
+fn with_source_context_prefixes_message_and_stores_source() {
+    let err = AppError::dependency_unavailable("cache down")
+        .with_field(field::str("node", "redis-1"))
+        .with_source_context("warming sessions", IoError::other("connection refused"));
+    assert_eq!(err.kind, AppErrorKind::DependencyUnavailable);
+    assert_eq!(err.message.as_deref(), Some("warming sessions: cache down"));
+    assert!(err.metadata().contains_key("node"));
+    let source = err.downcast_ref::<IoError>().expect("io source");
+    assert_eq!(source.to_string(), "connection refused");
+}
+
+#[test]
+fn into_parts_round_trips_every_component() {
+    let original = AppError::unauthorized("token expired")
+        .with_code(AppCode::new("TOKEN_EXPIRED"))
+        .with_field(field::str("user", "alice"))
+        .with_retry_after_secs(30)
+        .with_www_authenticate("Bearer realm=\"api\"")
+        .with_help("Sign in again.")
+        .redactable()
+        .with_source(IoError::other("clock skew"));
+    #[cfg(feature = "serde_json")]
+    let original = original.with_details_json(serde_json::json!({"realm": "api"}));
+    #[cfg(not(feature = "serde_json"))]
+    let original = original.with_details_text("realm=api");
+    let metadata = original.metadata().clone();
+    let details = original.details.clone();
+    let rebuilt = AppError::from_parts(original.into_parts());
+    assert_eq!(rebuilt.kind, AppErrorKind::Unauthorized);
+    assert_eq!(rebuilt.code.as_str(), "TOKEN_EXPIRED");
+    assert_eq!(rebuilt.message.as_deref(), Some("token expired"));
+    assert_eq!(rebuilt.metadata(), &metadata);
+    assert_eq!(rebuilt.retry.map(|advice| advice.after_seconds), Some(30));
+    assert_eq!(
+        rebuilt.www_authenticate.as_deref(),
+        Some("Bearer realm=\"api\"")
+    );
+    assert_eq!(rebuilt.edit_policy, MessageEditPolicy::Redact);
+    assert_eq!(rebuilt.help.as_deref(), Some("Sign in again."));
+    assert_eq!(rebuilt.details, details);
+    assert!(rebuilt.is::<IoError>());
+    assert_eq!(
+        rebuilt.source_ref().map(ToString::to_string).as_deref(),
+        Some("clock skew")
+    );
+}
+
+#[test]
+fn conflict_reason_stores_each_reason_as_metadata() {
+    for (reason, name) in [
+        (ConflictReason::VersionMismatch, "version_mismatch"),
+        (ConflictReason::Duplicate, "duplicate"),
+        (ConflictReason::StateConflict, "state_conflict")
+    ] {
+        let err = AppError::conflict_reason("conflict", reason);
+        assert_eq!(err.kind, AppErrorKind::Conflict);
+        assert_eq!(err.code, AppCode::Conflict);
+        assert_eq!(
+            err.metadata().get("conflict.reason"),
+            Some(&FieldValue::Str(Cow::Borrowed(name)))
+        );
+        assert_eq!(err.conflict_cause(), Some(reason));
+        assert_eq!(reason.to_string(), name);
+    }
+}
+
+#[test]
+fn conflict_cause_ignores_other_kinds_and_unknown_reasons() {
+    assert_eq!(AppError::conflict("busy").conflict_cause(), None);
+    let unknown =
+        AppError::conflict("busy").with_field(field::str("conflict.reason", "lock_held"));
+    assert_eq!(unknown.conflict_cause(), None);
+    let reclassified = AppError::conflict_reason("stale", ConflictReason::VersionMismatch)
+        .with_kind(AppErrorKind::BadRequest);
+    assert_eq!(reclassified.conflict_cause(), None);
+}
+
+#[test]
+fn expect_kind_returns_error_on_match() {
+    let err = AppError::conflict("duplicate order")
+        .expect_kind(AppErrorKind::Conflict)
+        .with_field(field::u64("order", 7));
+    assert_eq!(err.kind, AppErrorKind::Conflict);
+    assert!(err.metadata().contains_key("order"));
+}
+
+#[test]
+fn expect_kind_panics_with_kind_code_and_message() {
+    let payload = std::panic::catch_unwind(|| {
+        AppError::timeout("upstream stalled").expect_kind(AppErrorKind::NotFound)
+    })
+    .expect_err("kind mismatch must panic");
+    let message = payload
+        .downcast_ref::<String>()
+        .expect("formatted panic message");
+    assert!(message.contains("expected error kind NotFound"));
+    assert!(message.contains("found Timeout"));
+    assert!(message.contains("code: TIMEOUT"));
+    assert!(message.contains("\"upstream stalled\""));
+}
+
+#[test]
+fn with_elapsed_stores_duration_field() {
+    let err = AppError::timeout("slow").with_elapsed(Duration::from_millis(250));
+    assert_eq!(
+        err.metadata().get("elapsed"),
+        Some(&FieldValue::Duration(Duration::from_millis(250)))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_elapsed_measures_since_start() {
+    let start = std::time::Instant::now();
+    std::thread::sleep(Duration::from_millis(5));
+    let err = AppError::from_elapsed(AppErrorKind::Timeout, start);
+    assert_eq!(err.kind, AppErrorKind::Timeout);
+    assert!(err.message.is_none());
+    match err.metadata().get("elapsed") {
+        Some(FieldValue::Duration(elapsed)) => assert!(*elapsed >= Duration::from_millis(5)),
+        other => panic!("unexpected elapsed field: {other:?}")
+    }
+}
+
+#[test]
+fn with_request_id_stores_uuid_field() {
+    let id = uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+    let err = AppError::internal("boom").with_request_id(id);
+    let value = err.metadata().get("request_id").expect("request id");
+    assert_eq!(value, &FieldValue::Uuid(id));
+    assert_eq!(value.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(
+        err.metadata().redaction("request_id"),
+        Some(FieldRedaction::None)
+    );
+}
+
+#[test]
+fn app_error_remove_field_drops_value_and_redaction() {
+    let mut err = AppError::internal("boom")
+        .with_field(field::secret("api_key", "sk-123"))
+        .with_field(field::str("user_id", "42"));
+    assert_eq!(
+        err.remove_field("api_key"),
+        Some(FieldValue::Str(Cow::Borrowed("sk-123")))
+    );
+    assert!(!err.metadata().contains_key("api_key"));
+    assert_eq!(err.metadata().redaction("api_key"), None);
+    assert_eq!(err.remove_field("api_key"), None);
+    assert!(err.metadata().contains_key("user_id"));
+}
+
+#[test]
+fn app_error_ensure_field_passes_when_present() {
+    let err = AppError::internal("boom")
+        .with_field(field::str("user_id", "42"))
+        .ensure_field("user_id");
+    assert_eq!(
+        err.metadata().get("user_id"),
+        Some(&FieldValue::Str(Cow::Borrowed("42")))
+    );
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "missing required field `request_id`")]
+fn app_error_ensure_field_names_missing_key_in_debug() {
+    let _ = AppError::internal("boom")
+        .with_field(field::str("user_id", "42"))
+        .ensure_field("request_id");
+}
+
+#[test]
+fn rename_fields_moves_value_and_redaction() {
+    let err = AppError::internal("boom")
+        .with_field(field::str("req_id", "r-1"))
+        .with_field(field::str("card", "4242"))
+        .redact_field("card", FieldRedaction::Last4)
+        .rename_fields(&[("req_id", "request_id"), ("card", "card_number")]);
+    assert!(!err.metadata().contains_key("req_id"));
+    assert_eq!(
+        err.metadata().get("request_id"),
+        Some(&FieldValue::Str(Cow::Borrowed("r-1")))
+    );
+    assert_eq!(
+        err.metadata().redaction("card_number"),
+        Some(FieldRedaction::Last4)
+    );
+    let names: Vec<_> = err.metadata().iter().map(|(name, _)| name).collect();
+    assert_eq!(names, ["card_number", "request_id"]);
+}
+
+#[test]
+fn rename_fields_ignores_absent_source() {
+    let err = AppError::internal("boom")
+        .with_field(field::u64("attempt", 1))
+        .rename_fields(&[("req_id", "request_id")]);
+    assert!(!err.metadata().contains_key("request_id"));
+    assert_eq!(err.metadata().get("attempt"), Some(&FieldValue::U64(1)));
+}
+
+#[test]
+fn rename_fields_skips_existing_target() {
+    let err = AppError::internal("boom")
+        .with_field(field::str("req_id", "old"))
+        .with_field(field::str("request_id", "new"))
+        .rename_fields(&[("req_id", "request_id")]);
+    assert_eq!(
+        err.metadata().get("request_id"),
+        Some(&FieldValue::Str(Cow::Borrowed("new")))
+    );
+    assert_eq!(
+        err.metadata().get("req_id"),
+        Some(&FieldValue::Str(Cow::Borrowed("old")))
+    );
+}
+
+#[test]
+fn app_error_scrub_clears_fields_matching_key_pattern() {
+    let mut err = AppError::internal("boom")
+        .with_field(field::str("user_email", "alice@example.com"))
+        .with_field(field::str("billing_email", "billing@example.com"))
+        .with_field(field::str("user_id", "42"));
+    let mut visited = Vec::new();
+    err.scrub(|name, value, redaction| {
+        visited.push(name.to_owned());
+        if name.contains("email") {
+            *value = FieldValue::Str(Cow::Borrowed(""));
+            *redaction = FieldRedaction::Redact;
+        }
+    });
+    assert_eq!(visited, ["billing_email", "user_email", "user_id"]);
+    for name in ["user_email", "billing_email"] {
+        assert_eq!(err.metadata().redaction(name), Some(FieldRedaction::Redact));
+        assert_eq!(
+            err.metadata().get(name),
+            Some(&FieldValue::Str(Cow::Borrowed("")))
+        );
+    }
+    assert_eq!(
+        err.metadata().redaction("user_id"),
+        Some(FieldRedaction::None)
+    );
+    assert_eq!(
+        err.metadata().get("user_id"),
+        Some(&FieldValue::Str(Cow::Borrowed("42")))
+    );
+}
+
+#[test]
+fn fingerprint_ignores_volatile_metadata() {
+    let first = AppError::service("upstream failed").with_field(field::str("request_id", "r-1"));
+    let second = AppError::service("upstream failed")
+        .with_field(field::str("request_id", "r-2"))
+        .with_field(field::u64("attempt", 3));
+    assert_eq!(first.fingerprint(), second.fingerprint());
+    assert_eq!(first.fingerprint_hex(), second.fingerprint_hex());
+}
+
+#[test]
+fn fingerprint_collapses_numbers_in_message() {
+    let first = AppError::not_found("order 1234 not found");
+    let second = AppError::not_found("order 98 not found");
+    assert_eq!(first.fingerprint(), second.fingerprint());
+    assert_ne!(
+        first.fingerprint(),
+        AppError::not_found("invoice 1234 not found").fingerprint()
+    );
+}
+
+#[test]
+fn fingerprint_distinguishes_kind_and_code() {
+    let base = AppError::service("upstream failed");
+    let other_kind = AppError::timeout("upstream failed");
+    let other_code = AppError::service("upstream failed").with_code(AppCode::new("UPSTREAM"));
+    assert_ne!(base.fingerprint(), other_kind.fingerprint());
+    assert_ne!(base.fingerprint(), other_code.fingerprint());
+    assert_ne!(other_kind.fingerprint(), other_code.fingerprint());
+}
+
+#[test]
+fn fingerprint_hex_is_zero_padded() {
+    let err = AppError::bare(AppErrorKind::Internal);
+    let hex = err.fingerprint_hex();
+    assert_eq!(hex.len(), 16);
+    assert!(
+        hex.bytes()
+            .all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase())
+    );
+    assert_eq!(u64::from_str_radix(&hex, 16), Ok(err.fingerprint()));
+}
+
 #[derive(Debug)]
 struct DummyError;
 
@@ -548,12 +1176,36 @@ fn redactable_policy_is_exposed() {
     assert!(matches!(err.edit_policy, MessageEditPolicy::Redact));
 }
 
-/// Smoke test to ensure `log()` is callable; tracing output isn't asserted.
 #[test]
-fn log_uses_kind_and_code() {
-    let err = AppError::internal("boom");
-    err.log();
-}
+fn redactable_if_follows_condition() {
+    let redacted = AppError::internal("boom").redactable_if(true);
+    assert_eq!(redacted.edit_policy, MessageEditPolicy::Redact);
+    assert_eq!(
+        ErrorResponse::from(&redacted).message,
+        "Internal server error"
+    );
+
+    let plain = AppError::internal("boom").redactable_if(false);
+    assert_eq!(plain.edit_policy, MessageEditPolicy::Preserve);
+    assert_eq!(ErrorResponse::from(&plain).message, "boom");
+
+    let kept = AppError::internal("boom").redactable().redactable_if(false);
+    assert_eq!(kept.edit_policy, MessageEditPolicy::Redact);
+}
+
+#[test]
+fn plain_message_overrides_prior_redactable() {
+    let err = AppError::internal("boom").redactable().plain_message();
+    assert_eq!(err.edit_policy, MessageEditPolicy::Preserve);
+    assert_eq!(ErrorResponse::from(err).message, "boom");
+}
+
+/// Smoke test to ensure `log()` is callable; tracing output isn't asserted.
+#[test]
+fn log_uses_kind_and_code() {
+    let err = AppError::internal("boom");
+    err.log();
+}
 
 #[cfg(feature = "tracing")]
 #[test]
@@ -583,6 +1235,230 @@ fn telemetry_emits_single_tracing_event_with_trace_id() {
     });
 }
 
+#[cfg(feature = "tracing")]
+#[test]
+fn log_if_emits_only_when_predicate_holds() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use telemetry_support::new_recording_dispatch;
+    use tracing::{callsite::rebuild_interest_cache, dispatcher};
+    let server = AppError::internal("boom");
+    let client = AppError::not_found("missing");
+    let (dispatch, events) = new_recording_dispatch();
+    dispatcher::with_default(&dispatch, || {
+        rebuild_interest_cache();
+        client.log_if(AppError::is_server_error);
+        assert!(events.lock().expect("events lock").is_empty());
+        server.log_if(AppError::is_server_error);
+        let events = events.lock().expect("events lock");
+        assert_eq!(events.len(), 1, "expected exactly one tracing event");
+        assert_eq!(events[0].code.as_deref(), Some(AppCode::Internal.as_str()));
+    });
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn log_sampled_emits_every_nth_occurrence() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use std::sync::Arc;
+
+    use telemetry_support::new_recording_dispatch;
+    use tracing::{callsite::rebuild_interest_cache, dispatcher};
+
+    use crate::{EveryNth, set_error_sampler};
+    let errors: Vec<AppError> = (0..5)
+        .map(|shard| AppError::timeout(format!("shard {shard} timed out")))
+        .collect();
+    let (dispatch, events) = new_recording_dispatch();
+    set_error_sampler(Some(Arc::new(EveryNth::new(2))));
+    dispatcher::with_default(&dispatch, || {
+        rebuild_interest_cache();
+        for err in &errors {
+            err.log_sampled();
+        }
+    });
+    set_error_sampler(None);
+    let events = events.lock().expect("events lock");
+    assert_eq!(events.len(), 3, "expected occurrences 1, 3 and 5 to log");
+}
+
+#[test]
+fn with_tenant_sets_canonical_field() {
+    let err = AppError::forbidden("plan limit reached").with_tenant("acme");
+    assert_eq!(err.tenant_id(), Some("acme"));
+    assert_eq!(
+        err.metadata().get("tenant_id"),
+        Some(&FieldValue::Str(Cow::Borrowed("acme")))
+    );
+    assert_eq!(
+        err.metadata().redaction("tenant_id"),
+        Some(FieldRedaction::None)
+    );
+    assert_eq!(AppError::internal("boom").tenant_id(), None);
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn log_includes_tenant_id() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use telemetry_support::new_recording_dispatch;
+    use tracing::{callsite::rebuild_interest_cache, dispatcher};
+    let err = AppError::forbidden("plan limit reached").with_tenant("acme");
+    let (dispatch, events) = new_recording_dispatch();
+    dispatcher::with_default(&dispatch, || {
+        rebuild_interest_cache();
+        err.log();
+    });
+    let events = events.lock().expect("events lock");
+    assert_eq!(events.len(), 1, "expected exactly one tracing event");
+    assert!(
+        events[0]
+            .tenant_id
+            .as_deref()
+            .is_some_and(|value| value.contains("acme"))
+    );
+}
+
+#[test]
+fn severity_defaults_to_kind_and_can_be_overridden() {
+    use crate::Severity;
+
+    assert_eq!(AppError::internal("boom").severity(), Severity::Critical);
+    assert_eq!(
+        AppError::dependency_unavailable("db").severity(),
+        Severity::Critical
+    );
+    assert_eq!(AppError::timeout("slow").severity(), Severity::Error);
+    assert_eq!(AppError::not_found("missing").severity(), Severity::Warning);
+    assert_eq!(AppError::validation("bad").severity(), Severity::Warning);
+    let err = AppError::validation("bad").with_severity(Severity::Info);
+    assert_eq!(err.severity(), Severity::Info);
+    assert_eq!(err.kind, AppErrorKind::Validation);
+    let restored = AppError::from_parts(err.into_parts());
+    assert_eq!(restored.severity(), Severity::Info);
+}
+
+#[test]
+fn status_override_drives_responses_and_ignores_invalid_codes() {
+    let err = AppError::conflict("stale").with_status(412);
+    assert_eq!(err.status(), 412);
+    assert_eq!(err.kind.http_status(), 409);
+    assert_eq!(ErrorResponse::from(&err).status, 412);
+    assert_eq!(crate::ProblemJson::from_ref(&err).status, 412);
+    let restored = AppError::from_parts(err.into_parts());
+    assert_eq!(restored.status(), 412);
+
+    assert_eq!(AppError::conflict("stale").with_status(99).status(), 409);
+    assert_eq!(AppError::conflict("stale").with_status(600).status(), 409);
+    assert_eq!(
+        ErrorResponse::from(AppError::conflict("stale").with_status(418)).status,
+        418
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn log_level_follows_severity() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use telemetry_support::new_recording_dispatch;
+    use tracing::{Level, callsite::rebuild_interest_cache, dispatcher};
+
+    use crate::Severity;
+    let errors = [
+        AppError::internal("boom"),
+        AppError::not_found("missing"),
+        AppError::not_found("probe").with_severity(Severity::Debug),
+        AppError::validation("odd input").with_severity(Severity::Critical)
+    ];
+    let (dispatch, events) = new_recording_dispatch();
+    dispatcher::with_default(&dispatch, || {
+        rebuild_interest_cache();
+        for err in &errors {
+            err.log();
+        }
+    });
+    let levels: Vec<_> = events
+        .lock()
+        .expect("events lock")
+        .iter()
+        .map(|event| event.level)
+        .collect();
+    assert_eq!(
+        levels,
+        [
+            Some(Level::ERROR),
+            Some(Level::WARN),
+            Some(Level::DEBUG),
+            Some(Level::ERROR)
+        ]
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn log_keeps_internal_message_when_public_message_is_set() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use telemetry_support::new_recording_dispatch;
+    use tracing::{callsite::rebuild_interest_cache, dispatcher};
+    let err = AppError::internal("pool exhausted on shard 3").with_public_message("Service busy.");
+    let (dispatch, events) = new_recording_dispatch();
+    dispatcher::with_default(&dispatch, || {
+        rebuild_interest_cache();
+        err.log();
+    });
+    let events = events.lock().expect("events lock");
+    assert_eq!(events.len(), 1, "expected exactly one tracing event");
+    let message = events[0].message.as_deref().expect("message field");
+    assert!(message.contains("pool exhausted on shard 3"), "{message}");
+}
+
+#[test]
+fn display_shows_internal_message_when_public_message_is_set() {
+    let err = AppError::internal("pool exhausted on shard 3").with_public_message("Service busy.");
+    let local = err.display_as(DisplayMode::Local).to_string();
+    assert!(local.contains("pool exhausted on shard 3"));
+    assert!(!local.contains("Service busy."));
+    let staging = err.display_as(DisplayMode::Staging).to_string();
+    assert!(staging.contains("pool exhausted on shard 3"));
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn log_err_logs_once_and_passes_error_through() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use telemetry_support::new_recording_dispatch;
+    use tracing::{callsite::rebuild_interest_cache, dispatcher};
+
+    use crate::ResultExt;
+    let (dispatch, events) = new_recording_dispatch();
+    dispatcher::with_default(&dispatch, || {
+        rebuild_interest_cache();
+        let res: super::AppResult<u8> = Err(AppError::dependency_unavailable("queue offline"));
+        let err = res.log_err().unwrap_err();
+        assert_eq!(err.kind, AppErrorKind::DependencyUnavailable);
+        assert_eq!(err.message.as_deref(), Some("queue offline"));
+    });
+    let events = events.lock().expect("events lock");
+    assert_eq!(events.len(), 1, "expected exactly one tracing event");
+    assert_eq!(events[0].code.as_deref(), Some("DEPENDENCY_UNAVAILABLE"));
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn log_err_leaves_ok_untouched() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use telemetry_support::new_recording_dispatch;
+    use tracing::{callsite::rebuild_interest_cache, dispatcher};
+
+    use crate::ResultExt;
+    let (dispatch, events) = new_recording_dispatch();
+    dispatcher::with_default(&dispatch, || {
+        rebuild_interest_cache();
+        let res: super::AppResult<u8> = Ok(7);
+        assert_eq!(res.log_err().ok(), Some(7));
+    });
+    assert!(events.lock().expect("events lock").is_empty());
+}
+
 #[cfg(feature = "tracing")]
 #[test]
 fn telemetry_flushes_after_subscriber_install() {
@@ -608,50 +1484,148 @@ fn telemetry_flushes_after_subscriber_install() {
     });
 }
 
-#[cfg(feature = "metrics")]
+#[cfg(feature = "tracing")]
 #[test]
-fn metrics_counter_is_incremented_once() {
+fn with_current_span_attaches_span_identity() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use tracing::{Dispatch, callsite::rebuild_interest_cache, dispatcher, info_span};
+    use tracing_subscriber::Registry;
+    dispatcher::with_default(&Dispatch::new(Registry::default()), || {
+        rebuild_interest_cache();
+        let span = info_span!("checkout", order = 42);
+        let err = span.in_scope(|| AppError::service("gateway declined").with_current_span());
+        assert_eq!(
+            err.metadata().get("span"),
+            Some(&FieldValue::Str(Cow::Borrowed("checkout")))
+        );
+        assert_eq!(
+            err.metadata().get("span.target"),
+            Some(&FieldValue::Str(Cow::Borrowed(module_path!())))
+        );
+        assert_eq!(
+            err.metadata().get("span.id"),
+            span.id().map(|id| FieldValue::U64(id.into_u64())).as_ref()
+        );
+        let outside = AppError::service("no span").with_current_span();
+        assert!(outside.metadata().is_empty());
+    });
+    let disabled = AppError::internal("no subscriber").with_current_span();
+    assert!(disabled.metadata().is_empty());
+}
+
+#[cfg(feature = "span-fields")]
+#[test]
+fn with_span_copies_recorded_fields() {
+    let _guard = TELEMETRY_GUARD.lock().expect("telemetry guard");
+    use tracing::{
+        Dispatch, callsite::rebuild_interest_cache, dispatcher, field::Empty, info_span
+    };
+    use tracing_subscriber::{Registry, layer::SubscriberExt};
+
+    use crate::span_fields::SpanFieldsLayer;
+    let subscriber = Registry::default().with(SpanFieldsLayer::new());
+    dispatcher::with_default(&Dispatch::new(subscriber), || {
+        rebuild_interest_cache();
+        let span = info_span!(
+            "checkout",
+            order = 42_u64,
+            tenant = "acme",
+            ok = Empty,
+            id = "order-42",
+            target = "payments"
+        );
+        span.record("ok", false);
+        span.record("order", 43_u64);
+        let err = AppError::service("gateway declined").with_span(&span);
+        assert_eq!(
+            err.metadata().get("span.field.order"),
+            Some(&FieldValue::U64(43))
+        );
+        assert_eq!(
+            err.metadata().get("span.field.tenant"),
+            Some(&FieldValue::Str(Cow::Borrowed("acme")))
+        );
+        assert_eq!(
+            err.metadata().get("span.field.ok"),
+            Some(&FieldValue::Bool(false))
+        );
+        assert_eq!(
+            err.metadata().get("span"),
+            Some(&FieldValue::Str(Cow::Borrowed("checkout")))
+        );
+        assert_eq!(
+            err.metadata().get("span.id"),
+            span.id().map(|id| FieldValue::U64(id.into_u64())).as_ref()
+        );
+        assert_eq!(
+            err.metadata().get("span.target"),
+            Some(&FieldValue::Str(Cow::Borrowed(module_path!())))
+        );
+        assert_eq!(
+            err.metadata().get("span.field.id"),
+            Some(&FieldValue::Str(Cow::Borrowed("order-42")))
+        );
+        assert_eq!(
+            err.metadata().get("span.field.target"),
+            Some(&FieldValue::Str(Cow::Borrowed("payments")))
+        );
+    });
+}
+
+#[cfg(feature = "metrics")]
+mod metrics_support {
     use std::{
         collections::HashMap,
-        sync::{Arc, Mutex}
+        sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError}
     };
 
     use metrics::{
         Counter, CounterFn, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit
     };
+
     #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-    struct CounterKey {
+    pub(super) struct CounterKey {
         name:   String,
         labels: Vec<(String, String)>
     }
+
     impl CounterKey {
-        fn new(name: String, labels: Vec<(String, String)>) -> Self {
+        pub(super) fn new(name: &str, labels: &[(&str, &str)]) -> Self {
             Self {
-                name,
-                labels
+                name:   name.to_owned(),
+                labels: labels
+                    .iter()
+                    .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+                    .collect()
             }
         }
     }
-    type CounterMap = HashMap<CounterKey, u64>;
+
+    pub(super) type CounterMap = HashMap<CounterKey, u64>;
     type SharedCounterMap = Arc<Mutex<CounterMap>>;
+
     #[derive(Clone)]
     struct MetricsCounterHandle {
         key:    CounterKey,
         counts: SharedCounterMap
     }
+
     impl CounterFn for MetricsCounterHandle {
         fn increment(&self, value: u64) {
             let mut map = self.counts.lock().expect("counter map");
             *map.entry(self.key.clone()).or_default() += value;
         }
+
         fn absolute(&self, value: u64) {
             let mut map = self.counts.lock().expect("counter map");
             map.insert(self.key.clone(), value);
         }
     }
+
     struct CountingRecorder {
         counts: SharedCounterMap
     }
+
     impl Recorder for CountingRecorder {
         fn describe_counter(
             &self,
@@ -660,7 +1634,9 @@ fn metrics_counter_is_incremented_once() {
             _description: SharedString
         ) {
         }
+
         fn describe_gauge(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+
         fn describe_histogram(
             &self,
             _key: KeyName,
@@ -668,48 +1644,140 @@ fn metrics_counter_is_incremented_once() {
             _description: SharedString
         ) {
         }
+
         fn register_counter(&self, key: &Key, _metadata: &Metadata<'_>) -> Counter {
-            let labels = key
-                .labels()
-                .map(|label| (label.key().to_owned(), label.value().to_owned()))
-                .collect::<Vec<_>>();
-            let counter_key = CounterKey::new(key.name().to_owned(), labels);
+            let counter_key = CounterKey {
+                name:   key.name().to_owned(),
+                labels: key
+                    .labels()
+                    .map(|label| (label.key().to_owned(), label.value().to_owned()))
+                    .collect()
+            };
             Counter::from_arc(Arc::new(MetricsCounterHandle {
                 key:    counter_key,
                 counts: self.counts.clone()
             }))
         }
+
         fn register_gauge(&self, _key: &Key, _metadata: &Metadata<'_>) -> Gauge {
             Gauge::noop()
         }
+
         fn register_histogram(&self, _key: &Key, _metadata: &Metadata<'_>) -> Histogram {
             Histogram::noop()
         }
     }
-    use std::sync::OnceLock;
-    static RECORDER_COUNTS: OnceLock<SharedCounterMap> = OnceLock::new();
-    let counts = RECORDER_COUNTS
-        .get_or_init(|| {
+
+    /// Run `test` against the process-wide counting recorder with cleared
+    /// counts, one test at a time.
+    pub(super) fn with_counts(test: impl FnOnce(&dyn Fn() -> CounterMap)) {
+        static GUARD: Mutex<()> = Mutex::new(());
+        static COUNTS: OnceLock<SharedCounterMap> = OnceLock::new();
+        let _guard: MutexGuard<'_, ()> = GUARD.lock().unwrap_or_else(PoisonError::into_inner);
+        let counts = COUNTS.get_or_init(|| {
             let counts = Arc::new(Mutex::new(HashMap::new()));
             metrics::set_global_recorder(CountingRecorder {
                 counts: counts.clone()
             })
             .expect("install recorder");
             counts
-        })
-        .clone();
-    counts.lock().expect("counter map").clear();
-    let err = AppError::forbidden("denied");
-    err.log();
-    let key = CounterKey::new(
-        "error_total".to_owned(),
-        vec![
-            ("code".to_owned(), AppCode::Forbidden.as_str().to_owned()),
-            ("category".to_owned(), "Forbidden".to_owned()),
-        ]
+        });
+        counts.lock().expect("counter map").clear();
+        test(&|| counts.lock().expect("counter map").clone());
+    }
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn metrics_counter_is_incremented_once() {
+    use metrics_support::{CounterKey, with_counts};
+
+    with_counts(|counts| {
+        let err = AppError::forbidden("denied");
+        err.log();
+        let key = CounterKey::new(
+            "error_total",
+            &[
+                ("code", AppCode::Forbidden.as_str()),
+                ("category", "Forbidden")
+            ]
+        );
+        assert_eq!(counts().get(&key).copied(), Some(1));
+    });
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn metrics_counter_carries_operation_label() {
+    use metrics_support::{CounterKey, with_counts};
+
+    with_counts(|counts| {
+        let err = AppError::not_found("no such order").with_operation("get_order");
+        err.log();
+        let key = CounterKey::new(
+            "error_total",
+            &[
+                ("code", AppCode::NotFound.as_str()),
+                ("category", "NotFound"),
+                ("operation", "get_order")
+            ]
+        );
+        assert_eq!(counts().get(&key).copied(), Some(1));
+    });
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn metrics_counter_carries_custom_labels() {
+    use metrics_support::{CounterKey, with_counts};
+
+    with_counts(|counts| {
+        let err = AppError::service("upstream down")
+            .with_metric_label("region", "eu-west")
+            .with_metric_label("tier", "gold")
+            .with_metric_label("region", "us-east");
+        err.log();
+        let key = CounterKey::new(
+            "error_total",
+            &[
+                ("code", AppCode::Service.as_str()),
+                ("category", "Service"),
+                ("region", "us-east"),
+                ("tier", "gold")
+            ]
+        );
+        assert_eq!(counts().get(&key).copied(), Some(1));
+    });
+}
+
+#[test]
+fn with_metric_label_caps_count_and_skips_reserved_keys() {
+    let err = AppError::internal("boom")
+        .with_metric_label("code", "spoofed")
+        .with_metric_label("operation", "spoofed")
+        .with_metric_label("a", "1")
+        .with_metric_label("b", "2")
+        .with_metric_label("c", "3")
+        .with_metric_label("d", "4")
+        .with_metric_label("e", "5")
+        .with_metric_label("a", "updated");
+    assert_eq!(
+        err.metric_labels().collect::<Vec<_>>(),
+        [("a", "updated"), ("b", "2"), ("c", "3"), ("d", "4")]
+    );
+    assert!(err.metadata().is_empty());
+}
+
+#[test]
+fn with_operation_records_operation_field() {
+    let err = AppError::timeout("slow").with_operation("charge_card");
+    assert_eq!(err.operation(), Some("charge_card"));
+    assert_eq!(
+        err.metadata().get("operation"),
+        Some(&FieldValue::Str("charge_card".into()))
     );
-    let counts = counts.lock().expect("counter map");
-    assert_eq!(counts.get(&key).copied(), Some(1));
+    let numeric = AppError::timeout("slow").with_field(field::u64("operation", 7));
+    assert_eq!(numeric.operation(), None);
 }
 
 #[test]
@@ -767,6 +1835,84 @@ fn error_chain_multiple_sources() {
     assert_eq!(chain.len(), 2);
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn as_dyn_error_exposes_attached_source() {
+    let err = AppError::internal("db down").with_context(IoError::other("disk offline"));
+    let dyn_err = err.as_dyn_error();
+    let source = dyn_err.source().expect("source");
+    assert!(core::ptr::addr_eq(
+        source,
+        err.source_ref().expect("attached source")
+    ));
+    assert!(source.is::<IoError>());
+    assert!(core::ptr::addr_eq(dyn_err, &err));
+    assert!(
+        AppError::bad_request("plain")
+            .as_dyn_error()
+            .source()
+            .is_none()
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn cause_count_and_chain_limited_on_three_level_chain() {
+    let root = IoError::new(IoErrorKind::NotFound, "file not found");
+    let middle = AppError::service("config unreadable").with_context(root);
+    let inner = AppError::internal("startup failed").with_context(middle);
+    let outer = AppError::dependency_unavailable("boot aborted").with_context(inner);
+    assert_eq!(outer.cause_count(), 3);
+    assert_eq!(outer.chain().count(), 4);
+
+    let limited: Vec<_> = outer.chain_limited(1).collect();
+    assert_eq!(limited.len(), 2);
+    assert!(core::ptr::addr_eq(limited[0], &outer));
+    assert!(limited[1].is::<AppError>());
+    assert_eq!(outer.chain_limited(10).count(), 4);
+}
+
+#[test]
+fn chain_visits_additional_sources_in_order() {
+    let primary = AppError::timeout("replica a").with_context(IoError::other("a reset"));
+    let err = AppError::service("replication failed")
+        .with_additional_source(primary)
+        .with_additional_source(IoError::other("replica b"))
+        .with_additional_source(
+            AppError::network("replica c")
+                .with_additional_source(IoError::other("c refused"))
+                .with_additional_source(IoError::other("c dns"))
+        );
+
+    let label = |error: &(dyn core::error::Error + 'static)| match error.downcast_ref::<AppError>()
+    {
+        Some(app) => app.message.as_deref().unwrap_or_default().to_owned(),
+        None => error.to_string()
+    };
+    let chain: Vec<String> = err.chain().skip(1).map(label).collect();
+    assert_eq!(
+        chain,
+        [
+            "replica a",
+            "a reset",
+            "replica b",
+            "replica c",
+            "c refused",
+            "c dns"
+        ]
+    );
+    assert_eq!(err.cause_count(), 6);
+    assert_eq!(err.root_cause().to_string(), "a reset");
+    assert_eq!(
+        core::error::Error::source(&err).map(label).as_deref(),
+        Some("replica a")
+    );
+
+    let restored = AppError::from_parts(err.into_parts());
+    assert_eq!(restored.additional_sources.len(), 2);
+    assert_eq!(restored.cause_count(), 6);
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn root_cause_returns_deepest_error() {
@@ -776,6 +1922,180 @@ fn root_cause_returns_deepest_error() {
     assert_eq!(root.to_string(), "disk offline");
 }
 
+#[test]
+fn debug_applies_redaction_policies_to_metadata() {
+    let err = AppError::internal("boom")
+        .with_field(field::str("user", "alice"))
+        .with_field(field::str("note", "internal").with_redaction(FieldRedaction::Redact))
+        .with_field(field::u64("attempt", 3));
+    let rendered = format!("{err:?}");
+    assert!(rendered.starts_with("AppError {"), "{rendered}");
+    assert!(rendered.contains(r#""note": Field { value: [redacted], redaction: Redact }"#));
+    assert!(!rendered.contains("internal\""));
+    assert!(rendered.contains(r#""user": Field { value: Str("alice"), redaction: None }"#));
+    assert!(rendered.contains(r#""attempt": Field { value: U64(3), redaction: None }"#));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn debug_lists_source_chain_messages() {
+    let err = AppError::internal("db down").with_context(
+        AppError::timeout("pool exhausted").with_source(IoError::other("disk offline"))
+    );
+    let rendered = format!("{err:#?}");
+    assert!(rendered.contains("sources: ["), "{rendered}");
+    let pool = rendered
+        .find(r#""pool exhausted""#)
+        .expect("nested message");
+    let disk = rendered.find(r#""disk offline""#).expect("root message");
+    assert!(pool < disk);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn inner_debug_matches_redacted_error_debug() {
+    let err = AppError::internal("db down")
+        .with_field(field::str("password", "hunter2"))
+        .with_context(IoError::other("disk offline"));
+    let inner = format!("{:?}", *err);
+    assert!(inner.starts_with("ErrorInner {"), "{inner}");
+    assert!(!inner.contains("hunter2"), "{inner}");
+    assert!(inner.contains("[redacted]"), "{inner}");
+    assert_eq!(
+        inner.replacen("ErrorInner", "AppError", 1),
+        format!("{err:?}")
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn map_source_wraps_the_source() {
+    #[derive(Debug)]
+    struct Sanitized(Arc<dyn StdError + Send + Sync>);
+
+    impl Display for Sanitized {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str("upstream failure")
+        }
+    }
+
+    impl StdError for Sanitized {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(self.0.as_ref())
+        }
+    }
+
+    let err = AppError::internal("db down")
+        .with_source(IoError::other("password=hunter2"))
+        .map_source(|source| Some(Arc::new(Sanitized(source))));
+    let source = err.source_ref().expect("source kept");
+    assert_eq!(source.to_string(), "upstream failure");
+    assert!(source.is::<Sanitized>());
+    assert!(err.root_cause().is::<IoError>());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn map_source_returning_none_detaches_the_source() {
+    let err = AppError::internal("db down")
+        .with_source(IoError::other("disk offline"))
+        .map_source(|_| None);
+    assert!(err.source_ref().is_none());
+    let mut called = false;
+    let bare = AppError::internal("boom").map_source(|source| {
+        called = true;
+        Some(source)
+    });
+    assert!(!called);
+    assert!(bare.source_ref().is_none());
+}
+
+#[test]
+fn audit_unredacted_sensitive_flags_fields_without_redaction() {
+    let err = AppError::unauthorized("login failed")
+        .with_field(field::str("db.secret", "s3cr3t").with_redaction(FieldRedaction::None))
+        .with_field(field::str("Upstream_TOKEN", "abc").with_redaction(FieldRedaction::None))
+        .with_field(field::str("user.name", "alice"));
+    assert_eq!(
+        err.audit_unredacted_sensitive(&["password", "token", "secret"]),
+        ["Upstream_TOKEN", "db.secret"]
+    );
+    assert!(err.audit_unredacted_sensitive(&[""]).is_empty());
+}
+
+#[test]
+fn audit_unredacted_sensitive_skips_redacted_fields() {
+    let err = AppError::unauthorized("login failed")
+        .with_field(field::str("db.secret", "s3cr3t").with_redaction(FieldRedaction::Redact))
+        .with_field(field::str("api_token", "abc"))
+        .with_field(field::str("user.password", "hunter2"));
+    assert!(
+        err.audit_unredacted_sensitive(&["password", "token", "secret"])
+            .is_empty()
+    );
+}
+
+#[test]
+fn with_retry_after_header_parses_delay_seconds() {
+    let err = AppError::service("throttled").with_retry_after_header(" 120 ");
+    assert_eq!(err.retry.map(|r| r.after_seconds), Some(120));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn with_retry_after_header_converts_http_date_to_delay() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // Fri, 01 Jan 2100 00:00:00 GMT
+    const TARGET: u64 = 4_102_444_800;
+    let before = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let err =
+        AppError::service("throttled").with_retry_after_header("Fri, 01 Jan 2100 00:00:00 GMT");
+    let after = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let delay = err.retry.map(|r| r.after_seconds).unwrap();
+    assert!((TARGET - after - 1..=TARGET - before).contains(&delay));
+
+    let past =
+        AppError::service("throttled").with_retry_after_header("Sun, 06 Nov 1994 08:49:37 GMT");
+    assert_eq!(past.retry.map(|r| r.after_seconds), Some(0));
+}
+
+#[test]
+fn with_retry_after_header_ignores_invalid_values() {
+    for value in ["", "soon", "-1", "1.5", "Sun, 06 Nov 1994 08:49:37 UTC"] {
+        let err = AppError::service("throttled").with_retry_after_header(value);
+        assert!(err.retry.is_none(), "{value:?}");
+    }
+    let err = AppError::service("throttled")
+        .with_retry_after_secs(5)
+        .with_retry_after_header("later");
+    assert_eq!(err.retry.map(|r| r.after_seconds), Some(5));
+}
+
+#[test]
+fn with_source_str_appends_string_error_to_chain() {
+    let err = AppError::internal("sync failed").with_source_str("EIO from driver");
+    assert_eq!(err.chain().count(), 2);
+    let last = err.chain().last().expect("chain is never empty");
+    assert_eq!(last.to_string(), "EIO from driver");
+    let string_error = last
+        .downcast_ref::<crate::StringError>()
+        .expect("string source downcasts to StringError");
+    assert_eq!(string_error.message(), "EIO from driver");
+    assert!(err.root_cause().is::<crate::StringError>());
+    assert_eq!(
+        err.downcast_ref::<crate::StringError>()
+            .map(crate::StringError::message),
+        Some("EIO from driver")
+    );
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn root_cause_returns_self_when_no_source() {
@@ -798,6 +2118,41 @@ fn is_checks_source_type() {
     assert!(!anyhow_app_err.is::<IoError>());
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn root_cause_is_finds_deepest_io_error() {
+    #[derive(Debug)]
+    struct Transport {
+        inner: IoError
+    }
+    impl Display for Transport {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("transport failed")
+        }
+    }
+    impl StdError for Transport {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            Some(&self.inner)
+        }
+    }
+    let err = AppError::timeout("upstream stalled").with_context(Transport {
+        inner: IoError::new(IoErrorKind::TimedOut, "read timed out")
+    });
+    assert!(!err.is::<IoError>());
+    assert!(err.root_cause_is::<IoError>());
+    assert!(err.root_cause_is::<Transport>());
+    assert!(!err.root_cause_is::<AnyhowSource>());
+    assert!(!err.root_cause_is::<AppError>());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn root_cause_is_false_without_source() {
+    let err = AppError::not_found("user not found");
+    assert!(!err.root_cause_is::<IoError>());
+    assert!(!err.root_cause_is::<AppError>());
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn is_returns_false_when_no_source() {
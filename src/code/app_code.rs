@@ -22,6 +22,38 @@ use utoipa::{
 
 use crate::kind::AppErrorKind;
 
+/// Every built-in [`AppCode`] constant, in declaration order.
+const BUILTIN_CODES: [AppCode; 28] = [
+    AppCode::NotFound,
+    AppCode::Validation,
+    AppCode::Conflict,
+    AppCode::UserAlreadyExists,
+    AppCode::Unauthorized,
+    AppCode::Forbidden,
+    AppCode::NotImplemented,
+    AppCode::BadRequest,
+    AppCode::MethodNotAllowed,
+    AppCode::PayloadTooLarge,
+    AppCode::UnsupportedMediaType,
+    AppCode::Locked,
+    AppCode::RateLimited,
+    AppCode::TelegramAuth,
+    AppCode::InvalidJwt,
+    AppCode::Internal,
+    AppCode::Database,
+    AppCode::Service,
+    AppCode::Config,
+    AppCode::Turnkey,
+    AppCode::Timeout,
+    AppCode::Network,
+    AppCode::DependencyUnavailable,
+    AppCode::Serialization,
+    AppCode::Deserialization,
+    AppCode::ExternalApi,
+    AppCode::Queue,
+    AppCode::Cache
+];
+
 /// Error returned when parsing [`AppCode`] from a string fails.
 ///
 /// The parser only accepts SCREAMING_SNAKE_CASE values accepted by
@@ -75,6 +107,14 @@ impl AppCode {
     pub const NotImplemented: Self = Self::from_static("NOT_IMPLEMENTED");
     /// Machine code emitted when a request is malformed.
     pub const BadRequest: Self = Self::from_static("BAD_REQUEST");
+    /// Machine code emitted when the HTTP method is not allowed.
+    pub const MethodNotAllowed: Self = Self::from_static("METHOD_NOT_ALLOWED");
+    /// Machine code emitted when a request body is too large.
+    pub const PayloadTooLarge: Self = Self::from_static("PAYLOAD_TOO_LARGE");
+    /// Machine code emitted when a request body media type is not accepted.
+    pub const UnsupportedMediaType: Self = Self::from_static("UNSUPPORTED_MEDIA_TYPE");
+    /// Machine code emitted when the target resource is locked.
+    pub const Locked: Self = Self::from_static("LOCKED");
     /// Machine code emitted when a caller is throttled.
     pub const RateLimited: Self = Self::from_static("RATE_LIMITED");
     /// Machine code emitted when Telegram authentication fails.
@@ -142,6 +182,51 @@ impl AppCode {
         Self::from_static(code)
     }
 
+    /// Canonical machine code for an [`AppErrorKind`].
+    ///
+    /// Same mapping as the `From<AppErrorKind>` conversion, usable in `const`
+    /// contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// use masterror::{AppCode, AppErrorKind};
+    ///
+    /// const CODE: AppCode = AppCode::from_kind(AppErrorKind::Timeout);
+    /// assert_eq!(CODE, AppCode::Timeout);
+    /// ```
+    #[must_use]
+    pub const fn from_kind(kind: AppErrorKind) -> Self {
+        match kind {
+            AppErrorKind::NotFound => Self::NotFound,
+            AppErrorKind::Validation => Self::Validation,
+            AppErrorKind::Conflict => Self::Conflict,
+            AppErrorKind::Unauthorized => Self::Unauthorized,
+            AppErrorKind::Forbidden => Self::Forbidden,
+            AppErrorKind::NotImplemented => Self::NotImplemented,
+            AppErrorKind::BadRequest => Self::BadRequest,
+            AppErrorKind::MethodNotAllowed => Self::MethodNotAllowed,
+            AppErrorKind::PayloadTooLarge => Self::PayloadTooLarge,
+            AppErrorKind::UnsupportedMediaType => Self::UnsupportedMediaType,
+            AppErrorKind::Locked => Self::Locked,
+            AppErrorKind::RateLimited => Self::RateLimited,
+            AppErrorKind::TelegramAuth => Self::TelegramAuth,
+            AppErrorKind::InvalidJwt => Self::InvalidJwt,
+            AppErrorKind::Internal => Self::Internal,
+            AppErrorKind::Database => Self::Database,
+            AppErrorKind::Service => Self::Service,
+            AppErrorKind::Config => Self::Config,
+            AppErrorKind::Turnkey => Self::Turnkey,
+            AppErrorKind::Timeout => Self::Timeout,
+            AppErrorKind::Network => Self::Network,
+            AppErrorKind::DependencyUnavailable => Self::DependencyUnavailable,
+            AppErrorKind::Serialization => Self::Serialization,
+            AppErrorKind::Deserialization => Self::Deserialization,
+            AppErrorKind::ExternalApi => Self::ExternalApi,
+            AppErrorKind::Queue => Self::Queue,
+            AppErrorKind::Cache => Self::Cache
+        }
+    }
+
     /// Construct an [`AppCode`] from a dynamically provided string.
     ///
     /// The input must be SCREAMING_SNAKE_CASE. This constructor allocates to
@@ -167,6 +252,63 @@ impl AppCode {
         Ok(Self::from_owned(code))
     }
 
+    /// Parse a code leniently, accepting lowercase and kebab-case spellings.
+    ///
+    /// ASCII letters are uppercased and hyphens become underscores before the
+    /// input is matched against built-in codes. Anything else falls back to
+    /// the same validation as [`AppCode::try_new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseAppCodeError`] when the normalized string is not a
+    /// valid SCREAMING_SNAKE_CASE code, e.g. because it contains spaces.
+    ///
+    /// # Examples
+    /// ```
+    /// use masterror::AppCode;
+    ///
+    /// assert_eq!(AppCode::parse_lenient("not-found")?, AppCode::NotFound);
+    /// assert_eq!(
+    ///     AppCode::parse_lenient("rate_limited")?,
+    ///     AppCode::RateLimited
+    /// );
+    /// assert_eq!(
+    ///     AppCode::parse_lenient("billing-declined")?.as_str(),
+    ///     "BILLING_DECLINED"
+    /// );
+    /// assert!(AppCode::parse_lenient("not found").is_err());
+    /// # Ok::<(), masterror::ParseAppCodeError>(())
+    /// ```
+    pub fn parse_lenient(code: &str) -> Result<Self, ParseAppCodeError> {
+        let normalized: String = code
+            .chars()
+            .map(|ch| match ch {
+                '-' => '_',
+                other => other.to_ascii_uppercase()
+            })
+            .collect();
+        if let Some(code) = match_static(&normalized) {
+            return Ok(code);
+        }
+        Self::try_new(normalized)
+    }
+
+    /// Iterate over every built-in code constant.
+    ///
+    /// Caller-defined codes created via [`AppCode::new`] or
+    /// [`AppCode::try_new`] are not included. Useful for generating client
+    /// enums or documentation from the stable code set.
+    ///
+    /// # Examples
+    /// ```
+    /// use masterror::AppCode;
+    ///
+    /// assert!(AppCode::iter_builtin().any(|code| code == AppCode::NotFound));
+    /// ```
+    pub fn iter_builtin() -> impl ExactSizeIterator<Item = AppCode> {
+        BUILTIN_CODES.into_iter()
+    }
+
     /// Get the canonical string form of this code (SCREAMING_SNAKE_CASE).
     ///
     /// This matches the JSON serialization.
@@ -231,31 +373,7 @@ impl From<AppErrorKind> for AppCode {
     ///
     /// The mapping is 1:1 today and intentionally conservative.
     fn from(kind: AppErrorKind) -> Self {
-        match kind {
-            AppErrorKind::NotFound => Self::NotFound,
-            AppErrorKind::Validation => Self::Validation,
-            AppErrorKind::Conflict => Self::Conflict,
-            AppErrorKind::Unauthorized => Self::Unauthorized,
-            AppErrorKind::Forbidden => Self::Forbidden,
-            AppErrorKind::NotImplemented => Self::NotImplemented,
-            AppErrorKind::BadRequest => Self::BadRequest,
-            AppErrorKind::RateLimited => Self::RateLimited,
-            AppErrorKind::TelegramAuth => Self::TelegramAuth,
-            AppErrorKind::InvalidJwt => Self::InvalidJwt,
-            AppErrorKind::Internal => Self::Internal,
-            AppErrorKind::Database => Self::Database,
-            AppErrorKind::Service => Self::Service,
-            AppErrorKind::Config => Self::Config,
-            AppErrorKind::Turnkey => Self::Turnkey,
-            AppErrorKind::Timeout => Self::Timeout,
-            AppErrorKind::Network => Self::Network,
-            AppErrorKind::DependencyUnavailable => Self::DependencyUnavailable,
-            AppErrorKind::Serialization => Self::Serialization,
-            AppErrorKind::Deserialization => Self::Deserialization,
-            AppErrorKind::ExternalApi => Self::ExternalApi,
-            AppErrorKind::Queue => Self::Queue,
-            AppErrorKind::Cache => Self::Cache
-        }
+        Self::from_kind(kind)
     }
 }
 
@@ -336,6 +454,10 @@ fn match_static(value: &str) -> Option<AppCode> {
         "FORBIDDEN" => Some(AppCode::Forbidden),
         "NOT_IMPLEMENTED" => Some(AppCode::NotImplemented),
         "BAD_REQUEST" => Some(AppCode::BadRequest),
+        "METHOD_NOT_ALLOWED" => Some(AppCode::MethodNotAllowed),
+        "PAYLOAD_TOO_LARGE" => Some(AppCode::PayloadTooLarge),
+        "UNSUPPORTED_MEDIA_TYPE" => Some(AppCode::UnsupportedMediaType),
+        "LOCKED" => Some(AppCode::Locked),
         "RATE_LIMITED" => Some(AppCode::RateLimited),
         "TELEGRAM_AUTH" => Some(AppCode::TelegramAuth),
         "INVALID_JWT" => Some(AppCode::InvalidJwt),
@@ -402,6 +524,31 @@ mod tests {
         assert_eq!(AppCode::from(AppErrorKind::Validation), AppCode::Validation);
         assert_eq!(AppCode::from(AppErrorKind::Internal), AppCode::Internal);
         assert_eq!(AppCode::from(AppErrorKind::Timeout), AppCode::Timeout);
+        assert_eq!(
+            AppCode::from(AppErrorKind::PayloadTooLarge),
+            AppCode::PayloadTooLarge
+        );
+        assert_eq!(
+            AppCode::from(AppErrorKind::UnsupportedMediaType),
+            AppCode::UnsupportedMediaType
+        );
+        assert_eq!(AppCode::from(AppErrorKind::Locked), AppCode::Locked);
+        assert_eq!(
+            AppCode::from(AppErrorKind::MethodNotAllowed),
+            AppCode::MethodNotAllowed
+        );
+    }
+
+    #[test]
+    fn iter_builtin_yields_every_static_code() {
+        let codes: Vec<AppCode> = AppCode::iter_builtin().collect();
+        assert_eq!(codes.len(), 28);
+        assert_eq!(codes.len(), crate::CODE_MAPPINGS.len());
+        assert!(codes.contains(&AppCode::NotFound));
+        assert!(codes.contains(&AppCode::Internal));
+        for code in &codes {
+            assert_eq!(super::match_static(code.as_str()).as_ref(), Some(code));
+        }
     }
 
     #[test]
@@ -430,7 +577,11 @@ mod tests {
             AppCode::Validation,
             AppCode::Unauthorized,
             AppCode::Internal,
-            AppCode::Timeout
+            AppCode::Timeout,
+            AppCode::PayloadTooLarge,
+            AppCode::UnsupportedMediaType,
+            AppCode::Locked,
+            AppCode::MethodNotAllowed
         ] {
             let parsed = AppCode::from_str(code.as_str()).expect("parse");
             assert_eq!(parsed, code);
@@ -443,6 +594,35 @@ mod tests {
         assert_eq!(parsed.as_str(), "THIRD_PARTY_FAILURE");
     }
 
+    #[test]
+    fn parse_lenient_normalizes_case_and_hyphens() {
+        assert_eq!(
+            AppCode::parse_lenient("not-found").expect("kebab"),
+            AppCode::NotFound
+        );
+        assert_eq!(
+            AppCode::parse_lenient("not_found").expect("snake"),
+            AppCode::NotFound
+        );
+        assert_eq!(
+            AppCode::parse_lenient("NOT_FOUND").expect("canonical"),
+            AppCode::NotFound
+        );
+        assert_eq!(
+            AppCode::parse_lenient("Third-Party-Failure")
+                .expect("custom")
+                .as_str(),
+            "THIRD_PARTY_FAILURE"
+        );
+    }
+
+    #[test]
+    fn parse_lenient_rejects_invalid_input() {
+        for input in ["not found", "", "-leading", "double--hyphen", "caf\u{e9}"] {
+            assert_eq!(AppCode::parse_lenient(input), Err(ParseAppCodeError));
+        }
+    }
+
     #[test]
     fn from_str_rejects_unknown_code_shape() {
         let err = AppCode::from_str("NOT-A-REAL-CODE").unwrap_err();
@@ -16,6 +16,11 @@
 //!   message.
 //! - [`String`] → `AppErrorKind::BadRequest`   Lightweight validation helper
 //!   when you don’t pull in `validator`.
+//! - [`core::num::ParseIntError`] / [`core::num::ParseFloatError`] →
+//!   `AppErrorKind::BadRequest` and [`core::str::Utf8Error`] →
+//!   `AppErrorKind::Deserialization` (require the default `std` feature)
+//!   Malformed query parameters, env vars and byte payloads; the original error
+//!   is kept as the source.
 //!
 //! ## Feature-gated mappings
 //!
@@ -88,7 +93,11 @@
 use alloc::{boxed::Box, string::String};
 use core::error::Error as CoreError;
 #[cfg(feature = "std")]
-use std::io::Error as IoError;
+use std::{
+    io::Error as IoError,
+    num::{ParseFloatError, ParseIntError},
+    str::Utf8Error
+};
 
 use crate::{AppError, AppErrorKind};
 
@@ -145,7 +154,7 @@ mod init_data;
 mod tonic;
 
 #[cfg(feature = "tonic")]
-pub use self::tonic::StatusConversionError;
+pub use self::tonic::{ERROR_INFO_TYPE_URL, ErrorInfo, StatusConversionError};
 
 /// Map `std::io::Error` to an internal application error.
 ///
@@ -170,6 +179,66 @@ impl From<IoError> for AppError {
     }
 }
 
+/// Map `std::num::ParseIntError` to a client error (`BadRequest`).
+///
+/// Integer parsing usually fails on caller-supplied input such as query
+/// parameters or environment variables. The parser message becomes the public
+/// message and the original error is attached as the source.
+///
+/// ```rust
+/// use masterror::{AppError, AppErrorKind};
+///
+/// let err: AppError = "abc".parse::<u32>().unwrap_err().into();
+/// assert!(matches!(err.kind, AppErrorKind::BadRequest));
+/// assert!(err.source_ref().is_some());
+/// ```
+#[cfg(feature = "std")]
+impl From<ParseIntError> for AppError {
+    fn from(err: ParseIntError) -> Self {
+        AppError::bad_request(err.to_string()).with_source(err)
+    }
+}
+
+/// Map `std::num::ParseFloatError` to a client error (`BadRequest`).
+///
+/// Mirrors the [`ParseIntError`] mapping: the parser message becomes the
+/// public message and the original error is attached as the source.
+///
+/// ```rust
+/// use masterror::{AppError, AppErrorKind};
+///
+/// let err: AppError = "1.2.3".parse::<f64>().unwrap_err().into();
+/// assert!(matches!(err.kind, AppErrorKind::BadRequest));
+/// assert!(err.source_ref().is_some());
+/// ```
+#[cfg(feature = "std")]
+impl From<ParseFloatError> for AppError {
+    fn from(err: ParseFloatError) -> Self {
+        AppError::bad_request(err.to_string()).with_source(err)
+    }
+}
+
+/// Map `std::str::Utf8Error` to a `Deserialization` error.
+///
+/// Invalid UTF-8 means an incoming payload could not be decoded, which matches
+/// the classification used for malformed JSON. The original error, including
+/// [`Utf8Error::valid_up_to`], stays available via the source chain.
+///
+/// ```rust
+/// use masterror::{AppError, AppErrorKind};
+///
+/// let bytes = vec![0x66, 0x6f, 0xff];
+/// let err: AppError = std::str::from_utf8(&bytes).unwrap_err().into();
+/// assert!(matches!(err.kind, AppErrorKind::Deserialization));
+/// assert!(err.source_ref().is_some());
+/// ```
+#[cfg(feature = "std")]
+impl From<Utf8Error> for AppError {
+    fn from(err: Utf8Error) -> Self {
+        AppError::deserialization(err.to_string()).with_source(err)
+    }
+}
+
 /// Map a plain `String` to a client error (`BadRequest`).
 ///
 /// Handy for quick validation paths without the `validator` feature.
@@ -232,6 +301,39 @@ mod tests {
         assert_eq!(app.message.as_deref(), Some("disk said nope"));
     }
 
+    // --- std parse errors -> AppError ----------------------------------------
+
+    #[test]
+    fn parse_int_error_maps_to_bad_request_and_preserves_source() {
+        use std::num::ParseIntError;
+        let app: AppError = "12a".parse::<i64>().unwrap_err().into();
+        assert!(matches!(app.kind, AppErrorKind::BadRequest));
+        assert_eq!(
+            app.message.as_deref(),
+            Some("invalid digit found in string")
+        );
+        assert!(app.downcast_ref::<ParseIntError>().is_some());
+    }
+
+    #[test]
+    fn parse_float_error_maps_to_bad_request_and_preserves_source() {
+        use std::num::ParseFloatError;
+        let app: AppError = "nan?".parse::<f32>().unwrap_err().into();
+        assert!(matches!(app.kind, AppErrorKind::BadRequest));
+        assert_eq!(app.message.as_deref(), Some("invalid float literal"));
+        assert!(app.downcast_ref::<ParseFloatError>().is_some());
+    }
+
+    #[test]
+    fn utf8_error_maps_to_deserialization_and_preserves_source() {
+        use std::str::Utf8Error;
+        let bytes = vec![b'o', b'k', 0xc3, 0x28];
+        let app: AppError = std::str::from_utf8(&bytes).unwrap_err().into();
+        assert!(matches!(app.kind, AppErrorKind::Deserialization));
+        let source = app.downcast_ref::<Utf8Error>().expect("utf8 source");
+        assert_eq!(source.valid_up_to(), 2);
+    }
+
     // --- String -> AppError --------------------------------------------------
 
     #[test]
@@ -82,10 +82,9 @@ use crate::{AppError, ProblemJson};
 #[cfg(feature = "actix")]
 impl ResponseError for AppError {
     /// Map to Actix `StatusCode` using the stable `AppErrorKind` → HTTP
-    /// mapping.
+    /// mapping, or the override set by [`AppError::with_status`].
     fn status_code(&self) -> ActixStatus {
-        ActixStatus::from_u16(self.kind.http_status())
-            .unwrap_or(ActixStatus::INTERNAL_SERVER_ERROR)
+        ActixStatus::from_u16(self.status()).unwrap_or(ActixStatus::INTERNAL_SERVER_ERROR)
     }
 
     /// Produce JSON body with [`ProblemJson`]. Does not leak sources.
@@ -52,13 +52,14 @@ use crate::{AppError, response::ProblemJson};
 
 impl AppError {
     /// Map this error to an HTTP status derived from its
-    /// [`crate::AppErrorKind`].
+    /// [`crate::AppErrorKind`], or the override set by
+    /// [`AppError::with_status`].
     ///
     /// This is the transport-specific view over the framework-agnostic
-    /// `AppErrorKind::http_status()` mapping.
+    /// [`AppError::status`] mapping.
     #[inline]
     pub fn http_status(&self) -> StatusCode {
-        self.kind.status_code()
+        StatusCode::from_u16(self.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
     }
 }
 
@@ -12,6 +12,8 @@
 //! conversion sets no public message, so clients see the kind's fallback
 //! title; a `timeout.source` metadata field records the timeout origin and
 //! the original error is retained in the source chain.
+//! [`Error::timeout_with_deadline`] additionally records the time budget in a
+//! `timeout.budget` field.
 //!
 //! ## Rationale
 //!
@@ -38,6 +40,9 @@
 //! }
 //! ```
 
+#[cfg(feature = "tokio")]
+use core::time::Duration;
+
 #[cfg(feature = "tokio")]
 use tokio::time::error::Elapsed;
 
@@ -77,6 +82,43 @@ impl From<Elapsed> for Error {
     }
 }
 
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl Error {
+    /// Build a `Timeout` error from [`Elapsed`] that records the exceeded
+    /// time budget.
+    ///
+    /// Behaves like the `From<Elapsed>`
+    /// conversion and attaches `budget` as a `timeout.budget` duration field,
+    /// so the error describes which deadline was missed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind, FieldValue};
+    /// use tokio::time::{Duration, sleep, timeout};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let budget = Duration::from_millis(1);
+    ///     let elapsed = timeout(budget, sleep(Duration::from_millis(100)))
+    ///         .await
+    ///         .unwrap_err();
+    ///
+    ///     let err = AppError::timeout_with_deadline(elapsed, budget);
+    ///     assert_eq!(err.kind, AppErrorKind::Timeout);
+    ///     assert_eq!(
+    ///         err.metadata().get("timeout.budget"),
+    ///         Some(&FieldValue::Duration(budget))
+    ///     );
+    /// }
+    /// ```
+    #[must_use]
+    pub fn timeout_with_deadline(elapsed: Elapsed, budget: Duration) -> Self {
+        Self::from(elapsed).with_field(field::duration("timeout.budget", budget))
+    }
+}
+
 #[cfg(all(test, feature = "tokio"))]
 mod tests {
     use std::error::Error as StdError;
@@ -217,6 +259,29 @@ mod tests {
         assert!(matches!(app_err.kind, AppErrorKind::Timeout));
     }
 
+    #[tokio::test]
+    async fn timeout_with_deadline_records_budget() {
+        let budget = Duration::from_millis(2);
+        let err = timeout(budget, sleep(Duration::from_millis(50)))
+            .await
+            .expect_err("expect timeout");
+        let app_err = Error::timeout_with_deadline(err, budget);
+        assert_eq!(app_err.kind, AppErrorKind::Timeout);
+        assert_eq!(
+            app_err.metadata().get("timeout.budget"),
+            Some(&FieldValue::Duration(budget))
+        );
+        assert_eq!(
+            app_err.metadata().get("timeout.source"),
+            Some(&FieldValue::Str("tokio::time::timeout".into()))
+        );
+        assert!(
+            app_err
+                .source()
+                .is_some_and(|source| source.is::<Elapsed>())
+        );
+    }
+
     #[tokio::test]
     async fn elapsed_metadata_is_not_empty() {
         let fut = sleep(Duration::from_millis(20));
@@ -7,11 +7,16 @@
 //! Enabled with the `tonic` feature flag.
 //!
 //! ## Behavior
-//! - Maps [`AppCode`] to the corresponding gRPC [`tonic::Code`].
-//! - Emits retry/authentication hints via metadata when available.
+//! - Maps [`AppCode`] to the corresponding gRPC [`tonic::Code`];
+//!   [`Error::into_tonic_status_with`] lets callers supply their own
+//!   [`AppErrorKind`] mapping instead.
+//! - Emits retry/authentication hints via metadata when available
+//!   (`retry-after`, `retry-delay`, `www-authenticate`).
 //! - Propagates public metadata only when the error is not marked as
 //!   redactable.
 //! - Redacts the message automatically when the error is private.
+//! - [`Error::to_status_with_details`] additionally encodes a
+//!   `google.rpc.Status` carrying an [`ErrorInfo`] into the status details.
 //!
 //! ## Example
 //!
@@ -23,9 +28,10 @@
 //! ```
 
 use core::convert::Infallible;
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap};
 
 use itoa::Buffer as IntegerBuffer;
+use prost::{Message, bytes::Bytes};
 use ryu::Buffer as FloatBuffer;
 use tonic::{
     Code, Status,
@@ -59,17 +65,158 @@ use crate::{
 /// ```
 pub type StatusConversionError = Infallible;
 
+/// Type URL under which [`ErrorInfo`] is packed into `google.protobuf.Any`.
+pub const ERROR_INFO_TYPE_URL: &str = "type.googleapis.com/google.rpc.ErrorInfo";
+
+/// Wire-compatible `google.rpc.ErrorInfo` message.
+///
+/// Produced by [`Error::to_status_with_details`] with `reason` set to the
+/// [`AppCode`](crate::AppCode), `domain` set to the caller-supplied service
+/// namespace and `metadata` holding the public fields of the error.
+///
+/// # Examples
+/// ```rust,ignore
+/// use masterror::ErrorInfo;
+/// use prost::Message;
+///
+/// let info = ErrorInfo {
+///     reason:   "NOT_FOUND".into(),
+///     domain:   "users.example.com".into(),
+///     metadata: Default::default()
+/// };
+/// let decoded = ErrorInfo::decode(info.encode_to_vec().as_slice())?;
+/// assert_eq!(decoded, info);
+/// # Ok::<(), prost::DecodeError>(())
+/// ```
+#[derive(Clone, PartialEq, Message)]
+pub struct ErrorInfo {
+    /// Stable machine-readable reason, taken from the error code.
+    #[prost(string, tag = "1")]
+    pub reason:   String,
+    /// Logical grouping the reason belongs to, e.g. the service name.
+    #[prost(string, tag = "2")]
+    pub domain:   String,
+    /// Public metadata fields rendered as strings.
+    #[prost(map = "string, string", tag = "3")]
+    pub metadata: HashMap<String, String>
+}
+
+/// Wire-compatible `google.protobuf.Any`.
+#[derive(Clone, PartialEq, Message)]
+struct ProtoAny {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value:    Vec<u8>
+}
+
+/// Wire-compatible `google.rpc.Status`, the payload tonic places into
+/// `grpc-status-details-bin`.
+#[derive(Clone, PartialEq, Message)]
+struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    code:    i32,
+    #[prost(string, tag = "2")]
+    message: String,
+    #[prost(message, repeated, tag = "3")]
+    details: Vec<ProtoAny>
+}
+
 impl From<Error> for Status {
     fn from(error: Error) -> Self {
         status_from_error(&error)
     }
 }
 
+impl Error {
+    /// Convert into a [`tonic::Status`] whose details carry a
+    /// `google.rpc.Status` with a single [`ErrorInfo`] entry.
+    ///
+    /// Code, message and metadata match the plain [`From`] conversion. The
+    /// [`ErrorInfo`] uses the error code as `reason`, `domain` as given and
+    /// the public metadata fields; redactable errors and non-public fields
+    /// contribute no metadata.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use masterror::{AppError, field};
+    ///
+    /// let status = AppError::not_found("missing")
+    ///     .with_field(field::str("user_id", "42"))
+    ///     .to_status_with_details("users.example.com");
+    /// assert!(!status.details().is_empty());
+    /// ```
+    #[must_use]
+    pub fn to_status_with_details(&self, domain: impl Into<String>) -> Status {
+        let code = default_grpc_code(self);
+        let (message, meta) = status_parts(self);
+        let info = ErrorInfo {
+            reason:   self.code.as_str().to_owned(),
+            domain:   domain.into(),
+            metadata: if matches!(self.edit_policy, MessageEditPolicy::Redact) {
+                HashMap::new()
+            } else {
+                error_info_metadata(self.metadata())
+            }
+        };
+        let details = RpcStatus {
+            code:    code as i32,
+            message: message.clone(),
+            details: vec![ProtoAny {
+                type_url: ERROR_INFO_TYPE_URL.to_owned(),
+                value:    info.encode_to_vec()
+            }]
+        };
+        Status::with_details_and_metadata(
+            code,
+            message,
+            Bytes::from(details.encode_to_vec()),
+            meta
+        )
+    }
+
+    /// Convert into a [`tonic::Status`] whose code is chosen by `map`.
+    ///
+    /// For services whose gRPC conventions differ from the built-in
+    /// [`AppCode`](crate::AppCode) mapping. Message sanitization and metadata
+    /// match the plain [`From`] conversion.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use masterror::{AppError, AppErrorKind};
+    /// use tonic::Code;
+    ///
+    /// let status = AppError::conflict("version mismatch").into_tonic_status_with(|kind| match kind {
+    ///     AppErrorKind::Conflict => Code::Aborted,
+    ///     _ => Code::Unknown
+    /// });
+    /// assert_eq!(status.code(), Code::Aborted);
+    /// ```
+    #[must_use]
+    pub fn into_tonic_status_with(self, map: impl Fn(AppErrorKind) -> Code) -> Status {
+        let code = map(self.kind);
+        let (message, meta) = status_parts(&self);
+        Status::with_metadata(code, message, meta)
+    }
+}
+
 fn status_from_error(error: &Error) -> Status {
+    let code = default_grpc_code(error);
+    let (message, meta) = status_parts(error);
+    Status::with_metadata(code, message, meta)
+}
+
+fn default_grpc_code(error: &Error) -> Code {
+    Code::from_i32(mapping_for_code(&error.code).grpc().value)
+}
+
+fn status_parts(error: &Error) -> (String, MetadataMap) {
     error.emit_telemetry();
     let mapping = mapping_for_code(&error.code);
-    let grpc_code = Code::from_i32(mapping.grpc().value);
-    let detail = sanitize_detail(error.message.as_ref(), error.kind, error.edit_policy);
+    let detail = match error.public_message.as_deref() {
+        Some(public) => public.to_owned(),
+        None => sanitize_detail(error.message.as_ref(), error.kind, error.edit_policy)
+    };
     let mut meta = MetadataMap::new();
     insert_ascii(&mut meta, "app-code", error.code.as_str());
     let mut http_status_buffer = IntegerBuffer::new();
@@ -87,7 +234,29 @@ fn status_from_error(error: &Error) -> Status {
     if !matches!(error.edit_policy, MessageEditPolicy::Redact) {
         attach_metadata(&mut meta, error.metadata());
     }
-    Status::with_metadata(grpc_code, detail, meta)
+    (detail, meta)
+}
+
+fn error_info_metadata(metadata: &Metadata) -> HashMap<String, String> {
+    let mut formatter = MetadataValueFormatter::new();
+    let mut entries = HashMap::new();
+    for (name, value, redaction) in metadata.iter_with_redaction() {
+        if !matches!(redaction, FieldRedaction::None) {
+            continue;
+        }
+        let rendered = match value {
+            FieldValue::Str(text) => Some(text.as_ref().to_owned()),
+            #[cfg(feature = "serde_json")]
+            FieldValue::Json(json) => Some(json.to_string()),
+            other => {
+                metadata_value_to_ascii(other, &mut formatter).map(|text| text.as_ref().to_owned())
+            }
+        };
+        if let Some(rendered) = rendered {
+            entries.insert(name.to_owned(), rendered);
+        }
+    }
+    entries
 }
 
 fn sanitize_detail(
@@ -101,10 +270,17 @@ fn sanitize_detail(
     message.map_or_else(|| kind.to_string(), |msg| msg.as_ref().to_owned())
 }
 
+/// Emit retry hints as `retry-after` (whole seconds) and `retry-delay`
+/// (protobuf `Duration` text form, e.g. `30s`) so gRPC clients can back off
+/// without parsing the status message.
 fn insert_retry(meta: &mut MetadataMap, retry: RetryAdvice) {
     let mut retry_after_buffer = IntegerBuffer::new();
     let retry_after = retry_after_buffer.format(retry.after_seconds);
+    let mut retry_delay = String::with_capacity(retry_after.len() + 1);
+    retry_delay.push_str(retry_after);
+    retry_delay.push('s');
     insert_ascii(meta, "retry-after", retry_after);
+    insert_ascii(meta, "retry-delay", retry_delay);
 }
 
 fn attach_metadata(meta: &mut MetadataMap, metadata: &Metadata) {
@@ -228,6 +404,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn custom_mapper_overrides_code_and_keeps_sanitization() {
+        let map = |kind| match kind {
+            AppErrorKind::Conflict => Code::Aborted,
+            _ => Code::Unknown
+        };
+        let status = AppError::conflict("version mismatch")
+            .with_field(field::str("request_id", "abc"))
+            .into_tonic_status_with(map);
+        assert_eq!(status.code(), Code::Aborted);
+        assert_eq!(status.message(), "version mismatch");
+        assert_eq!(
+            status
+                .metadata()
+                .get("request_id")
+                .and_then(|v| v.to_str().ok()),
+            Some("abc")
+        );
+        assert_eq!(
+            status
+                .metadata()
+                .get("app-code")
+                .and_then(|v| v.to_str().ok()),
+            Some("CONFLICT")
+        );
+
+        let redacted = AppError::internal("secret")
+            .redactable()
+            .with_field(field::str("request_id", "abc"))
+            .into_tonic_status_with(map);
+        assert_eq!(redacted.code(), Code::Unknown);
+        assert_eq!(redacted.message(), AppErrorKind::Internal.to_string());
+        assert!(redacted.metadata().get("request_id").is_none());
+    }
+
     #[test]
     fn redacted_errors_hide_metadata() {
         let err = AppError::internal("secret")
@@ -238,6 +449,14 @@ mod tests {
         assert!(status.metadata().get("request_id").is_none());
     }
 
+    #[test]
+    fn public_message_replaces_status_message() {
+        let err = AppError::internal("pool exhausted on shard 3")
+            .with_public_message("Service busy, retry later.");
+        let status = Status::from(err);
+        assert_eq!(status.message(), "Service busy, retry later.");
+    }
+
     #[test]
     fn public_metadata_is_propagated() {
         let err = AppError::service("downstream")
@@ -301,4 +520,73 @@ mod tests {
             Some("7")
         );
     }
+
+    #[test]
+    fn rate_limited_status_carries_retry_delay() {
+        let status = Status::from(AppError::rate_limited("slow down").with_retry_after_secs(30));
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        let metadata = status.metadata();
+        assert_eq!(
+            metadata
+                .get("retry-delay")
+                .and_then(|value| value.to_str().ok()),
+            Some("30s")
+        );
+        assert_eq!(
+            metadata
+                .get("retry-after")
+                .and_then(|value| value.to_str().ok()),
+            Some("30")
+        );
+    }
+
+    fn decode_error_info(status: &Status) -> ErrorInfo {
+        let rpc = RpcStatus::decode(status.details()).expect("google.rpc.Status");
+        assert_eq!(rpc.code, status.code() as i32);
+        assert_eq!(rpc.message, status.message());
+        assert_eq!(rpc.details.len(), 1);
+        assert_eq!(rpc.details[0].type_url, ERROR_INFO_TYPE_URL);
+        ErrorInfo::decode(rpc.details[0].value.as_slice()).expect("ErrorInfo")
+    }
+
+    #[test]
+    fn status_details_carry_error_info() {
+        let status = AppError::not_found("missing user")
+            .with_field(field::str("user_id", "42"))
+            .with_field(field::str("note", "résumé"))
+            .with_field(field::u64("attempt", 3))
+            .with_field(field::str("password", "hunter2"))
+            .to_status_with_details("users.example.com");
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.message(), "missing user");
+        let info = decode_error_info(&status);
+        assert_eq!(info.reason, "NOT_FOUND");
+        assert_eq!(info.domain, "users.example.com");
+        let expected: HashMap<String, String> =
+            [("user_id", "42"), ("note", "résumé"), ("attempt", "3")]
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect();
+        assert_eq!(info.metadata, expected);
+    }
+
+    #[test]
+    fn redacted_status_details_omit_metadata() {
+        let status = AppError::internal("secret")
+            .redactable()
+            .with_field(field::str("request_id", "abc"))
+            .to_status_with_details("billing");
+        assert_eq!(status.message(), AppErrorKind::Internal.to_string());
+        let info = decode_error_info(&status);
+        assert_eq!(info.reason, "INTERNAL");
+        assert_eq!(info.domain, "billing");
+        assert!(info.metadata.is_empty());
+    }
+
+    #[test]
+    fn status_without_retry_advice_omits_retry_metadata() {
+        let status = Status::from(AppError::rate_limited("slow down"));
+        assert!(status.metadata().get("retry-delay").is_none());
+        assert!(status.metadata().get("retry-after").is_none());
+    }
 }
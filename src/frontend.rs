@@ -8,7 +8,8 @@
 //! This module is available when the `frontend` feature is enabled. It provides
 //! helpers to serialize [`crate::AppError`] and [`crate::ErrorResponse`] into
 //! [`wasm_bindgen::JsValue`] and optionally emit structured logs via
-//! `console.error` when running inside a browser.
+//! `console.error` when running inside a browser. Errors can also be
+//! broadcast as DOM events with [`crate::AppError::dispatch_event`].
 //!
 //! # Examples
 //!
@@ -39,9 +40,11 @@
 
 mod browser_console_error;
 mod browser_console_ext;
+mod error_event;
 
 pub use browser_console_error::BrowserConsoleError;
 pub use browser_console_ext::BrowserConsoleExt;
+pub use error_event::ERROR_EVENT_NAME;
 
 #[cfg(test)]
 mod tests;
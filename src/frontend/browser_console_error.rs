@@ -18,6 +18,7 @@
 //!   callable
 //! - [`BrowserConsoleError::ConsoleInvocation`] - console.error invocation
 //!   failed
+//! - [`BrowserConsoleError::EventDispatch`] - DOM error event dispatch failed
 //! - [`BrowserConsoleError::UnsupportedTarget`] - Not a WASM target
 //!
 //! # Examples
@@ -138,6 +139,24 @@ pub enum BrowserConsoleError {
         message: String
     },
 
+    /// Building or dispatching the DOM error event failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use masterror::frontend::BrowserConsoleError;
+    ///
+    /// let err = BrowserConsoleError::EventDispatch {
+    ///     message: "listener threw".to_owned()
+    /// };
+    /// assert_eq!(err.context(), Some("listener threw"));
+    /// ```
+    #[error("failed to dispatch browser error event: {message}")]
+    EventDispatch {
+        /// Textual representation of the JavaScript exception.
+        message: String
+    },
+
     /// Logging is not supported on the current compilation target.
     ///
     /// # Examples
@@ -188,6 +207,9 @@ impl BrowserConsoleError {
             }
             | Self::ConsoleInvocation {
                 message
+            }
+            | Self::EventDispatch {
+                message
             } => Some(message.as_str()),
             Self::ConsoleMethodNotCallable | Self::UnsupportedTarget => None
         }
@@ -0,0 +1,142 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! DOM error events for application-wide error handling.
+//!
+//! [`AppError::dispatch_event`] emits a [`CustomEvent`](web_sys::CustomEvent)
+//! named [`ERROR_EVENT_NAME`] whose `detail` carries the public `code`,
+//! `status` and `message`, so SPA shells can react to failures globally (e.g.
+//! by showing a toast).
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use masterror::AppError;
+//!
+//! let window = web_sys::window().expect("window");
+//! AppError::service("payment provider is down").dispatch_event(&window)?;
+//! ```
+
+#[cfg(target_arch = "wasm32")]
+use js_sys::{Object, Reflect};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsValue;
+use web_sys::EventTarget;
+#[cfg(target_arch = "wasm32")]
+use web_sys::{CustomEvent, CustomEventInit};
+
+use super::BrowserConsoleError;
+#[cfg(target_arch = "wasm32")]
+use crate::ErrorResponse;
+use crate::{AppError, AppResult};
+
+/// Name of the event emitted by [`AppError::dispatch_event`].
+pub const ERROR_EVENT_NAME: &str = "masterror:error";
+
+impl AppError {
+    /// Dispatch a [`ERROR_EVENT_NAME`] `CustomEvent` on `target`.
+    ///
+    /// The event `detail` is an object with the public `code`, HTTP `status`
+    /// and `message`; redactable errors expose the generic message, like
+    /// [`ErrorResponse`](crate::ErrorResponse). On non-WASM targets this
+    /// returns [`BrowserConsoleError::UnsupportedTarget`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrowserConsoleError::EventDispatch`] when the `detail` object
+    /// or the `CustomEvent` cannot be built, or when `dispatchEvent` rejects
+    /// the event. Exceptions thrown by listeners are reported to the global
+    /// error handler by the browser and are not returned here.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use masterror::AppError;
+    ///
+    /// let document = web_sys::window().and_then(|w| w.document()).expect("document");
+    /// AppError::unauthorized("session expired").dispatch_event(&document)?;
+    /// ```
+    pub fn dispatch_event(&self, target: &EventTarget) -> AppResult<(), BrowserConsoleError> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let response = ErrorResponse::from(self);
+            let detail = Object::new();
+            for (key, value) in [
+                ("code", JsValue::from_str(response.code.as_str())),
+                ("status", JsValue::from(response.status)),
+                ("message", JsValue::from_str(&response.message))
+            ] {
+                Reflect::set(&detail, &JsValue::from_str(key), &value)
+                    .map_err(|err| dispatch_error(&err))?;
+            }
+            let init = CustomEventInit::new();
+            init.set_detail(&detail);
+            let event = CustomEvent::new_with_event_init_dict(ERROR_EVENT_NAME, &init)
+                .map_err(|err| dispatch_error(&err))?;
+            target
+                .dispatch_event(&event)
+                .map_err(|err| dispatch_error(&err))?;
+            Ok(())
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = target;
+            Err(BrowserConsoleError::UnsupportedTarget)
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn dispatch_error(err: &JsValue) -> BrowserConsoleError {
+    BrowserConsoleError::EventDispatch {
+        message: err.as_string().unwrap_or_else(|| format!("{err:?}"))
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use js_sys::Reflect;
+    use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+    use wasm_bindgen_test::wasm_bindgen_test;
+    use web_sys::{CustomEvent, DocumentFragment};
+
+    use super::ERROR_EVENT_NAME;
+    use crate::AppError;
+
+    fn detail_field(detail: &JsValue, key: &str) -> JsValue {
+        Reflect::get(detail, &JsValue::from_str(key)).expect("detail field")
+    }
+
+    #[wasm_bindgen_test]
+    fn dispatch_event_fires_with_error_detail() {
+        let fragment = DocumentFragment::new().expect("fragment");
+        let received: Rc<RefCell<Vec<JsValue>>> = Rc::default();
+        let sink = received.clone();
+        let listener = Closure::<dyn FnMut(CustomEvent)>::new(move |event: CustomEvent| {
+            sink.borrow_mut().push(event.detail());
+        });
+        fragment
+            .add_event_listener_with_callback(ERROR_EVENT_NAME, listener.as_ref().unchecked_ref())
+            .expect("listener");
+
+        AppError::not_found("user not found")
+            .dispatch_event(&fragment)
+            .expect("dispatch");
+
+        let received = received.borrow();
+        assert_eq!(received.len(), 1);
+        let detail = &received[0];
+        assert_eq!(
+            detail_field(detail, "code").as_string().as_deref(),
+            Some("NOT_FOUND")
+        );
+        assert_eq!(detail_field(detail, "status").as_f64(), Some(404.0));
+        assert_eq!(
+            detail_field(detail, "message").as_string().as_deref(),
+            Some("user not found")
+        );
+    }
+}
@@ -30,6 +30,15 @@ fn context_returns_message_for_console_unavailable() {
     assert_eq!(err.context(), Some("missing console"));
 }
 
+#[test]
+fn context_returns_message_for_event_dispatch() {
+    let err = BrowserConsoleError::EventDispatch {
+        message: "listener threw".to_owned()
+    };
+    assert_eq!(err.context(), Some("listener threw"));
+    assert!(err.to_string().contains("dispatch browser error event"));
+}
+
 #[test]
 fn context_returns_message_for_console_error_unavailable() {
     let err = BrowserConsoleError::ConsoleErrorUnavailable {
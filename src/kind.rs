@@ -41,7 +41,8 @@
 
 use core::{
     error::Error as CoreError,
-    fmt::{self, Display, Formatter}
+    fmt::{self, Display, Formatter},
+    str::FromStr
 };
 
 #[cfg(feature = "axum")]
@@ -98,6 +99,26 @@ pub enum AppErrorKind {
     /// Maps to **400 Bad Request**.
     BadRequest,
 
+    /// HTTP method is not supported by the target resource.
+    ///
+    /// Maps to **405 Method Not Allowed**.
+    MethodNotAllowed,
+
+    /// Request body exceeds the size accepted by the server.
+    ///
+    /// Maps to **413 Content Too Large**.
+    PayloadTooLarge,
+
+    /// Request body uses a media type the endpoint does not accept.
+    ///
+    /// Maps to **415 Unsupported Media Type**.
+    UnsupportedMediaType,
+
+    /// Target resource is locked by another operation.
+    ///
+    /// Maps to **423 Locked**.
+    Locked,
+
     // ── Domain-specific categories (map conservatively) ───────────────────────
     /// Telegram authentication flow failed (signature, timestamp, or payload).
     ///
@@ -149,7 +170,9 @@ pub enum AppErrorKind {
 
     /// Client exceeded rate limits or quota.
     ///
-    /// Maps to **429 Too Many Requests**.
+    /// Maps to **429 Too Many Requests**. HTTP responses carry a default
+    /// `Retry-After` from [`retry_after_hint`](Self::retry_after_hint) unless
+    /// the error has explicit retry advice.
     RateLimited,
 
     /// External dependency is unavailable or degraded.
@@ -186,6 +209,60 @@ pub enum AppErrorKind {
     Cache
 }
 
+/// Generates [`AppErrorKind::ALL`] from a single variant list.
+///
+/// The list is also matched exhaustively, so adding a variant without listing
+/// it here fails to compile.
+macro_rules! all_kinds {
+    ($($variant:ident),+ $(,)?) => {
+        impl AppErrorKind {
+            /// Every kind, in declaration order.
+            ///
+            /// ```rust
+            /// use masterror::AppErrorKind;
+            ///
+            /// assert!(AppErrorKind::ALL.contains(&AppErrorKind::NotFound));
+            /// assert!(AppErrorKind::ALL.iter().all(|kind| kind.http_status() >= 400));
+            /// ```
+            pub const ALL: &'static [AppErrorKind] = &[$(Self::$variant),+];
+        }
+
+        const _: fn(AppErrorKind) = |kind| match kind {
+            $(AppErrorKind::$variant => {})+
+        };
+    };
+}
+
+all_kinds!(
+    NotFound,
+    Validation,
+    Conflict,
+    Unauthorized,
+    Forbidden,
+    NotImplemented,
+    Internal,
+    BadRequest,
+    MethodNotAllowed,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    Locked,
+    TelegramAuth,
+    InvalidJwt,
+    Database,
+    Service,
+    Config,
+    Turnkey,
+    Timeout,
+    Network,
+    RateLimited,
+    DependencyUnavailable,
+    Serialization,
+    Deserialization,
+    ExternalApi,
+    Queue,
+    Cache
+);
+
 #[cfg(not(feature = "colored"))]
 impl Display for AppErrorKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -222,6 +299,10 @@ impl AppErrorKind {
             Self::NotImplemented => "Not implemented",
             Self::Internal => "Internal server error",
             Self::BadRequest => "Bad request",
+            Self::MethodNotAllowed => "Method not allowed",
+            Self::PayloadTooLarge => "Payload too large",
+            Self::UnsupportedMediaType => "Unsupported media type",
+            Self::Locked => "Resource locked",
             Self::TelegramAuth => "Telegram authentication error",
             Self::InvalidJwt => "Invalid JWT",
             Self::Database => "Database error",
@@ -240,6 +321,71 @@ impl AppErrorKind {
         }
     }
 
+    /// RFC 7807 `title` emitted for this kind in problem+json payloads.
+    ///
+    /// [`ProblemJson`](crate::ProblemJson) uses this value for its `title`
+    /// field, so services assembling problem documents by hand stay
+    /// consistent with the built-in conversions. Titles are stable.
+    ///
+    /// ```rust
+    /// use masterror::AppErrorKind;
+    ///
+    /// assert_eq!(AppErrorKind::NotFound.problem_title(), "Not found");
+    /// assert_eq!(
+    ///     AppErrorKind::RateLimited.problem_title(),
+    ///     "Rate limit exceeded"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn problem_title(&self) -> &'static str {
+        self.label()
+    }
+
+    /// Canonical PascalCase name of the kind, matching the variant identifier.
+    ///
+    /// Unlike [`Display`], which renders the human-readable
+    /// [`label`](Self::label), this name is stable and round-trips through
+    /// [`FromStr`].
+    ///
+    /// ```rust
+    /// use masterror::AppErrorKind;
+    ///
+    /// assert_eq!(AppErrorKind::RateLimited.name(), "RateLimited");
+    /// assert_eq!("RateLimited".parse(), Ok(AppErrorKind::RateLimited));
+    /// ```
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::NotFound => "NotFound",
+            Self::Validation => "Validation",
+            Self::Conflict => "Conflict",
+            Self::Unauthorized => "Unauthorized",
+            Self::Forbidden => "Forbidden",
+            Self::NotImplemented => "NotImplemented",
+            Self::Internal => "Internal",
+            Self::BadRequest => "BadRequest",
+            Self::MethodNotAllowed => "MethodNotAllowed",
+            Self::PayloadTooLarge => "PayloadTooLarge",
+            Self::UnsupportedMediaType => "UnsupportedMediaType",
+            Self::Locked => "Locked",
+            Self::TelegramAuth => "TelegramAuth",
+            Self::InvalidJwt => "InvalidJwt",
+            Self::Database => "Database",
+            Self::Service => "Service",
+            Self::Config => "Config",
+            Self::Turnkey => "Turnkey",
+            Self::Timeout => "Timeout",
+            Self::Network => "Network",
+            Self::RateLimited => "RateLimited",
+            Self::DependencyUnavailable => "DependencyUnavailable",
+            Self::Serialization => "Serialization",
+            Self::Deserialization => "Deserialization",
+            Self::ExternalApi => "ExternalApi",
+            Self::Queue => "Queue",
+            Self::Cache => "Cache"
+        }
+    }
+
     /// Framework-agnostic mapping to an HTTP status code (`u16`).
     ///
     /// This mapping is intentionally conservative and stable. It should **not**
@@ -256,6 +402,10 @@ impl AppErrorKind {
             AppErrorKind::Forbidden => 403,
             AppErrorKind::NotImplemented => 501,
             AppErrorKind::BadRequest => 400,
+            AppErrorKind::MethodNotAllowed => 405,
+            AppErrorKind::PayloadTooLarge => 413,
+            AppErrorKind::UnsupportedMediaType => 415,
+            AppErrorKind::Locked => 423,
             AppErrorKind::RateLimited => 429,
             AppErrorKind::Timeout => 504,
             AppErrorKind::Network | AppErrorKind::DependencyUnavailable => 503,
@@ -272,6 +422,53 @@ impl AppErrorKind {
         }
     }
 
+    /// Whether the failure is transient, so repeating the same operation may
+    /// succeed.
+    ///
+    /// `Timeout`, `Network`, `RateLimited` and `DependencyUnavailable` are
+    /// retryable. `Locked` is retryable too: locks are normally held only for
+    /// the duration of a competing operation, so the same request usually
+    /// succeeds once it is released. Every other kind describes a request or
+    /// server state that a retry does not change.
+    ///
+    /// ```rust
+    /// use masterror::AppErrorKind;
+    ///
+    /// assert!(AppErrorKind::Timeout.is_retryable());
+    /// assert!(!AppErrorKind::Validation.is_retryable());
+    /// ```
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Timeout
+                | Self::Network
+                | Self::RateLimited
+                | Self::DependencyUnavailable
+                | Self::Locked
+        )
+    }
+
+    /// Default `Retry-After` delay, in seconds, for errors of this kind.
+    ///
+    /// Only `RateLimited` has a default (60 seconds): a 429 without a delay
+    /// invites clients to retry immediately. HTTP responses fall back to it
+    /// when the error carries no [`RetryAdvice`](crate::RetryAdvice).
+    ///
+    /// ```rust
+    /// use masterror::AppErrorKind;
+    ///
+    /// assert_eq!(AppErrorKind::RateLimited.retry_after_hint(), Some(60));
+    /// assert_eq!(AppErrorKind::Timeout.retry_after_hint(), None);
+    /// ```
+    #[must_use]
+    pub const fn retry_after_hint(&self) -> Option<u64> {
+        match self {
+            Self::RateLimited => Some(60),
+            _ => None
+        }
+    }
+
     /// Mapping to [`axum::http::StatusCode`] (available with the `axum`
     /// feature).
     #[cfg(feature = "axum")]
@@ -293,9 +490,151 @@ impl AppErrorKind {
     }
 }
 
+/// Error returned when an HTTP status has no canonical [`AppErrorKind`].
+///
+/// Produced by the [`TryFrom<u16>`] conversion for statuses outside the
+/// taxonomy (e.g. `200` or `418`). The rejected status is kept for
+/// diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownHttpStatusError {
+    status: u16
+}
+
+impl UnknownHttpStatusError {
+    /// HTTP status that could not be mapped.
+    #[must_use]
+    pub const fn status(&self) -> u16 {
+        self.status
+    }
+}
+
+impl Display for UnknownHttpStatusError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "no error kind maps to HTTP status {}", self.status)
+    }
+}
+
+impl CoreError for UnknownHttpStatusError {}
+
+/// Resolve the canonical [`AppErrorKind`] for an HTTP status code.
+///
+/// Statuses shared by several kinds resolve to the most generic one (`401` →
+/// `Unauthorized`, `500` → `Internal`, `503` → `DependencyUnavailable`), so
+/// `kind.http_status()` always survives the round-trip.
+///
+/// # Errors
+///
+/// Returns [`UnknownHttpStatusError`] when no kind maps to `status`.
+///
+/// # Examples
+/// ```rust
+/// use masterror::AppErrorKind;
+///
+/// assert_eq!(
+///     AppErrorKind::try_from(413),
+///     Ok(AppErrorKind::PayloadTooLarge)
+/// );
+/// assert!(AppErrorKind::try_from(200).is_err());
+/// ```
+impl TryFrom<u16> for AppErrorKind {
+    type Error = UnknownHttpStatusError;
+
+    fn try_from(status: u16) -> Result<Self, Self::Error> {
+        match status {
+            400 => Ok(Self::BadRequest),
+            401 => Ok(Self::Unauthorized),
+            403 => Ok(Self::Forbidden),
+            404 => Ok(Self::NotFound),
+            405 => Ok(Self::MethodNotAllowed),
+            409 => Ok(Self::Conflict),
+            413 => Ok(Self::PayloadTooLarge),
+            415 => Ok(Self::UnsupportedMediaType),
+            422 => Ok(Self::Validation),
+            423 => Ok(Self::Locked),
+            429 => Ok(Self::RateLimited),
+            500 => Ok(Self::Internal),
+            501 => Ok(Self::NotImplemented),
+            503 => Ok(Self::DependencyUnavailable),
+            504 => Ok(Self::Timeout),
+            _ => Err(UnknownHttpStatusError {
+                status
+            })
+        }
+    }
+}
+
+/// Error returned when parsing [`AppErrorKind`] from a string fails.
+///
+/// The parser accepts the PascalCase variant name (`"NotFound"`) or its
+/// snake_case form (`"not_found"`). Any other value results in this error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseAppErrorKindError;
+
+impl Display for ParseAppErrorKindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown error kind; expected a PascalCase or snake_case kind name")
+    }
+}
+
+impl CoreError for ParseAppErrorKindError {}
+
+/// Parse an [`AppErrorKind`] from its PascalCase or snake_case name.
+///
+/// # Errors
+///
+/// Returns [`ParseAppErrorKindError`] when the name matches no kind.
+///
+/// # Examples
+/// ```rust
+/// use masterror::{AppErrorKind, ParseAppErrorKindError};
+///
+/// assert_eq!("not_found".parse(), Ok(AppErrorKind::NotFound));
+/// assert_eq!("NotFound".parse(), Ok(AppErrorKind::NotFound));
+/// assert_eq!(
+///     "teapot".parse::<AppErrorKind>(),
+///     Err(ParseAppErrorKindError)
+/// );
+/// ```
+impl FromStr for AppErrorKind {
+    type Err = ParseAppErrorKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NotFound" | "not_found" => Ok(Self::NotFound),
+            "Validation" | "validation" => Ok(Self::Validation),
+            "Conflict" | "conflict" => Ok(Self::Conflict),
+            "Unauthorized" | "unauthorized" => Ok(Self::Unauthorized),
+            "Forbidden" | "forbidden" => Ok(Self::Forbidden),
+            "NotImplemented" | "not_implemented" => Ok(Self::NotImplemented),
+            "Internal" | "internal" => Ok(Self::Internal),
+            "BadRequest" | "bad_request" => Ok(Self::BadRequest),
+            "MethodNotAllowed" | "method_not_allowed" => Ok(Self::MethodNotAllowed),
+            "PayloadTooLarge" | "payload_too_large" => Ok(Self::PayloadTooLarge),
+            "UnsupportedMediaType" | "unsupported_media_type" => Ok(Self::UnsupportedMediaType),
+            "Locked" | "locked" => Ok(Self::Locked),
+            "TelegramAuth" | "telegram_auth" => Ok(Self::TelegramAuth),
+            "InvalidJwt" | "invalid_jwt" => Ok(Self::InvalidJwt),
+            "Database" | "database" => Ok(Self::Database),
+            "Service" | "service" => Ok(Self::Service),
+            "Config" | "config" => Ok(Self::Config),
+            "Turnkey" | "turnkey" => Ok(Self::Turnkey),
+            "Timeout" | "timeout" => Ok(Self::Timeout),
+            "Network" | "network" => Ok(Self::Network),
+            "RateLimited" | "rate_limited" => Ok(Self::RateLimited),
+            "DependencyUnavailable" | "dependency_unavailable" => Ok(Self::DependencyUnavailable),
+            "Serialization" | "serialization" => Ok(Self::Serialization),
+            "Deserialization" | "deserialization" => Ok(Self::Deserialization),
+            "ExternalApi" | "external_api" => Ok(Self::ExternalApi),
+            "Queue" | "queue" => Ok(Self::Queue),
+            "Cache" | "cache" => Ok(Self::Cache),
+            _ => Err(ParseAppErrorKindError)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::AppErrorKind::*;
+    use super::{AppErrorKind, AppErrorKind::*, ParseAppErrorKindError, UnknownHttpStatusError};
 
     #[test]
     fn http_status_is_stable() {
@@ -305,6 +644,10 @@ mod tests {
         assert_eq!(Forbidden.http_status(), 403);
         assert_eq!(Conflict.http_status(), 409);
         assert_eq!(BadRequest.http_status(), 400);
+        assert_eq!(MethodNotAllowed.http_status(), 405);
+        assert_eq!(PayloadTooLarge.http_status(), 413);
+        assert_eq!(UnsupportedMediaType.http_status(), 415);
+        assert_eq!(Locked.http_status(), 423);
         assert_eq!(RateLimited.http_status(), 429);
         assert_eq!(Timeout.http_status(), 504);
         assert_eq!(DependencyUnavailable.http_status(), 503);
@@ -342,6 +685,59 @@ mod tests {
         assert!(!TelegramAuth.is_critical());
         assert!(!InvalidJwt.is_critical());
         assert!(!RateLimited.is_critical());
+        assert!(!PayloadTooLarge.is_critical());
+        assert!(!UnsupportedMediaType.is_critical());
+        assert!(!Locked.is_critical());
+        assert!(!MethodNotAllowed.is_critical());
+    }
+
+    #[test]
+    fn try_from_status_resolves_new_client_kinds() {
+        assert_eq!(AppErrorKind::try_from(413), Ok(PayloadTooLarge));
+        assert_eq!(AppErrorKind::try_from(415), Ok(UnsupportedMediaType));
+        assert_eq!(PayloadTooLarge.label(), "Payload too large");
+        assert_eq!(UnsupportedMediaType.label(), "Unsupported media type");
+    }
+
+    #[test]
+    fn method_not_allowed_round_trips_405() {
+        assert_eq!(AppErrorKind::try_from(405), Ok(MethodNotAllowed));
+        assert_eq!(
+            AppErrorKind::try_from(MethodNotAllowed.http_status()),
+            Ok(MethodNotAllowed)
+        );
+        assert_eq!("method_not_allowed".parse(), Ok(MethodNotAllowed));
+        assert_eq!(MethodNotAllowed.label(), "Method not allowed");
+        assert!(!MethodNotAllowed.is_retryable());
+    }
+
+    #[test]
+    fn locked_maps_to_423_and_is_retryable() {
+        assert_eq!(AppErrorKind::try_from(423), Ok(Locked));
+        assert_eq!("locked".parse(), Ok(Locked));
+        assert_eq!(Locked.label(), "Resource locked");
+        // Locks are released once the competing operation finishes.
+        assert!(Locked.is_retryable());
+    }
+
+    #[test]
+    fn try_from_status_round_trips_every_kind() {
+        for &kind in AppErrorKind::ALL {
+            let status = kind.http_status();
+            let resolved = AppErrorKind::try_from(status).expect("mapped status");
+            assert_eq!(resolved.http_status(), status, "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn try_from_status_rejects_unmapped_codes() {
+        let err = AppErrorKind::try_from(418).unwrap_err();
+        assert_eq!(err.status(), 418);
+        assert_eq!(err.to_string(), "no error kind maps to HTTP status 418");
+        assert!(matches!(
+            AppErrorKind::try_from(200),
+            Err(UnknownHttpStatusError { .. })
+        ));
     }
 
     #[test]
@@ -359,4 +755,64 @@ mod tests {
         let output = BadRequest.to_string();
         assert!(output.contains("Bad request"));
     }
+
+    #[test]
+    fn problem_title_is_stable() {
+        assert_eq!(NotFound.problem_title(), "Not found");
+        assert_eq!(RateLimited.problem_title(), "Rate limit exceeded");
+        assert_eq!(Internal.problem_title(), "Internal server error");
+        assert_eq!(Validation.problem_title(), "Validation error");
+    }
+
+    #[test]
+    fn problem_title_is_defined_for_every_kind() {
+        for &kind in AppErrorKind::ALL {
+            assert!(!kind.problem_title().is_empty(), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn name_matches_debug_and_round_trips() {
+        for &kind in AppErrorKind::ALL {
+            assert_eq!(kind.name(), format!("{kind:?}"));
+            assert_eq!(kind.name().parse::<AppErrorKind>(), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn from_str_accepts_snake_case() {
+        assert_eq!("not_found".parse(), Ok(NotFound));
+        assert_eq!("rate_limited".parse(), Ok(RateLimited));
+        assert_eq!("invalid_jwt".parse(), Ok(InvalidJwt));
+        assert_eq!("unsupported_media_type".parse(), Ok(UnsupportedMediaType));
+        assert_eq!("internal".parse(), Ok(Internal));
+    }
+
+    #[test]
+    fn from_str_rejects_unknown_names() {
+        for input in ["", "teapot", "NOT_FOUND", "notfound", "Not found"] {
+            assert_eq!(
+                input.parse::<AppErrorKind>(),
+                Err(ParseAppErrorKindError),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            ParseAppErrorKindError.to_string(),
+            "unknown error kind; expected a PascalCase or snake_case kind name"
+        );
+    }
+
+    #[test]
+    fn only_transient_kinds_are_retryable() {
+        let retryable: Vec<_> = AppErrorKind::ALL
+            .iter()
+            .copied()
+            .filter(AppErrorKind::is_retryable)
+            .collect();
+        assert_eq!(
+            retryable,
+            [Locked, Timeout, Network, RateLimited, DependencyUnavailable]
+        );
+    }
 }
@@ -57,8 +57,12 @@
 //!   with RFC7807 body
 //! - `actix` — implements `Responder` for [`ProblemJson`] and Actix
 //!   `ResponseError` for [`AppError`]
+//! - `tower` — `ErrorLayer` middleware rendering service errors as RFC7807
+//!   responses, exposed in the `tower` module (works with `axum` routers)
+//! - `http` — `Metadata::from_header_map` copying allowlisted request headers
+//!   into metadata, redacting credentials
 //! - `tonic` — converts [`struct@Error`] into `tonic::Status` with sanitized
-//!   metadata
+//!   metadata and optional `google.rpc.ErrorInfo` details
 //! - `openapi` — derives an OpenAPI schema for [`ErrorResponse`] (via `utoipa`)
 //! - `sqlx` — `From<sqlx_core::Error>` mapping (pulls only `sqlx-core`)
 //! - `sqlx-migrate` — `From<sqlx::migrate::MigrateError>` mapping (pulls full
@@ -71,8 +75,9 @@
 //! - `teloxide` — `From<teloxide_core::RequestError>` mapping
 //! - `init-data` — `From<init_data_rs::InitDataError>` mapping for Telegram
 //!   Mini Apps init-data validation
-//! - `frontend` — convert errors into `wasm_bindgen::JsValue` and emit
-//!   `console.error` logs in WASM/browser contexts
+//! - `frontend` — convert errors into `wasm_bindgen::JsValue`, emit
+//!   `console.error` logs and dispatch `masterror:error` DOM events in
+//!   WASM/browser contexts
 //! - `serde_json` — support for structured JSON details in [`ErrorResponse`]
 //!   and [`ProblemJson`] plus a `From<serde_json::Error>` mapping. Note:
 //!   `axum`/`actix` enable the `serde_json` *dependency* for their own bodies,
@@ -82,6 +87,8 @@
 //! - `colored` — ANSI styling for the `Local` `Display` layout of
 //!   [`struct@Error`] (see [`DisplayMode`])
 //! - `tracing` — emit structured `tracing` events when errors are constructed
+//! - `span-fields` — `span_fields::SpanFieldsLayer` so `AppError::with_span`
+//!   copies recorded span values (implies `tracing`)
 //! - `metrics` — increment an `error_total{code,category}` counter per error
 //! - `backtrace` — capture backtraces (controlled by `RUST_BACKTRACE`)
 //! - `turnkey` — domain taxonomy and conversions for Turnkey errors, exposed in
@@ -182,7 +189,9 @@
 //!   boundary, `fields("name" = hash, "card" = last4)` override metadata
 //!   policies (`hash`, `last4`, `redact`, `none`).
 //! - `telemetry(...)` — list of expressions producing
-//!   `Option<masterror::Field>` to be inserted into [`Metadata`].
+//!   `Option<masterror::Field>` to be inserted into [`Metadata`]. An entry
+//!   `serialize(self.field, prefix = "ctx")` (requires `serde_json`) flattens a
+//!   `Serialize` field into dotted metadata keys instead.
 //! - `map.grpc` / `map.problem` — optional gRPC status (as `i32`) and
 //!   problem+json type for generated mapping tables. Access them via
 //!   `TYPE::HTTP_MAPPING`, `TYPE::GRPC_MAPPING`/`MAPPINGS` and
@@ -368,6 +377,7 @@ mod code;
 mod convert;
 pub mod error;
 mod kind;
+mod locale;
 mod macros;
 #[cfg(masterror_has_error_generic_member_access)]
 #[doc(hidden)]
@@ -377,9 +387,134 @@ pub mod provide;
 #[doc(hidden)]
 pub mod __private {
     pub use alloc::format;
+    use core::time::Duration;
+
+    /// Retry delay accepted by `#[masterror(retry_after = ...)]`.
+    pub trait RetryAfterSecs {
+        fn into_retry_after_secs(self) -> u64;
+    }
+
+    impl RetryAfterSecs for u32 {
+        fn into_retry_after_secs(self) -> u64 {
+            u64::from(self)
+        }
+    }
+
+    impl RetryAfterSecs for u64 {
+        fn into_retry_after_secs(self) -> u64 {
+            self
+        }
+    }
+
+    impl RetryAfterSecs for Duration {
+        fn into_retry_after_secs(self) -> u64 {
+            self.as_secs()
+        }
+    }
+
+    /// Normalize a derive-provided retry delay to whole seconds.
+    pub fn retry_after_secs(value: impl RetryAfterSecs) -> u64 {
+        value.into_retry_after_secs()
+    }
+
+    /// Flatten a value named by `telemetry(serialize(...))` into metadata.
+    #[cfg(feature = "serde_json")]
+    pub fn flatten_telemetry<T>(metadata: &mut crate::Metadata, prefix: Option<&str>, value: &T)
+    where
+        T: serde::Serialize + ?Sized
+    {
+        crate::app_error::flatten_into(metadata, prefix, value);
+    }
+
+    /// Value unwrapped by `{field?}` template placeholders.
+    pub trait OptionalValue {
+        type Value: ?Sized;
+
+        fn optional_value(&self) -> Option<&Self::Value>;
+    }
+
+    impl<T> OptionalValue for Option<T> {
+        type Value = T;
+
+        fn optional_value(&self) -> Option<&T> {
+            self.as_ref()
+        }
+    }
+
+    impl<T> OptionalValue for &T
+    where
+        T: OptionalValue + ?Sized
+    {
+        type Value = T::Value;
+
+        fn optional_value(&self) -> Option<&T::Value> {
+            (**self).optional_value()
+        }
+    }
+
+    /// Formats the inner value of an `Option`, or `fallback` for `None`.
+    pub struct OptionalArg<'a, T: ?Sized> {
+        value:    &'a T,
+        fallback: &'static str
+    }
+
+    impl<'a, T> OptionalArg<'a, T>
+    where
+        T: OptionalValue + ?Sized
+    {
+        pub fn new(value: &'a T, fallback: &'static str) -> Self {
+            Self {
+                value,
+                fallback
+            }
+        }
+    }
+
+    macro_rules! forward_optional_fmt {
+        ($($trait:ident),*) => {$(
+            impl<T> core::fmt::$trait for OptionalArg<'_, T>
+            where
+                T: OptionalValue + ?Sized,
+                T::Value: core::fmt::$trait
+            {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    match self.value.optional_value() {
+                        Some(value) => core::fmt::$trait::fmt(value, f),
+                        None => f.pad(self.fallback)
+                    }
+                }
+            }
+        )*};
+    }
+
+    forward_optional_fmt!(
+        Display, Debug, LowerHex, UpperHex, Pointer, Binary, Octal, LowerExp, UpperExp
+    );
+
+    /// Record the source message requested by
+    /// `#[masterror(source_as_detail)]`.
+    ///
+    /// Stored under `details.cause` with `serde_json`; otherwise used as the
+    /// text details unless details are already set.
+    pub fn source_as_detail<T>(error: &mut crate::AppError, source: &T)
+    where
+        T: core::fmt::Display + ?Sized
+    {
+        use alloc::string::ToString;
+        #[cfg(feature = "serde_json")]
+        error.details_object().insert(
+            "cause".into(),
+            serde_json::Value::String(source.to_string())
+        );
+        #[cfg(not(feature = "serde_json"))]
+        if error.details.is_none() {
+            error.details = Some(source.to_string());
+        }
+    }
 }
 mod response;
 mod result_ext;
+mod severity;
 
 #[cfg(feature = "frontend")]
 #[cfg_attr(docsrs, doc(cfg(feature = "frontend")))]
@@ -389,10 +524,22 @@ pub mod frontend;
 #[cfg_attr(docsrs, doc(cfg(feature = "turnkey")))]
 pub mod turnkey;
 
+#[cfg(feature = "tower")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tower")))]
+pub mod tower;
+
 #[cfg(feature = "colored")]
 #[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
 pub mod colored;
 
+#[cfg(feature = "span-fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "span-fields")))]
+pub mod span_fields;
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+mod retry;
+
 /// Minimal prelude re-exporting core types for handler signatures.
 pub mod prelude;
 
@@ -400,11 +547,14 @@ pub mod prelude;
 pub mod mapping;
 
 pub use app_error::{
-    AppError, AppResult, Context, DisplayMode, Error, ErrorChain, Field, FieldRedaction,
-    FieldValue, MessageEditPolicy, Metadata, field
+    AppError, AppErrorParts, AppResult, ConflictReason, Context, DisplayMode, Error, ErrorChain,
+    ErrorDisplay, Field, FieldRedaction, FieldValue, MessageEditPolicy, Metadata, MetadataDiff,
+    MetadataLimit, MetadataOverflow, StringError, field, metadata_limit, set_metadata_limit
 };
+#[cfg(feature = "std")]
+pub use app_error::{ErrorSampler, EveryNth, error_sampler, set_error_sampler};
 pub use code::{AppCode, ParseAppCodeError};
-pub use kind::AppErrorKind;
+pub use kind::{AppErrorKind, ParseAppErrorKindError, UnknownHttpStatusError};
 /// Re-export derive macros so users only depend on this crate.
 ///
 /// The `From<T> for AppError` conversion generated by `#[app_error(...)]`
@@ -440,14 +590,18 @@ pub use kind::AppErrorKind;
 /// ```
 pub use masterror_derive::{Error, Masterror};
 pub use response::{
-    ErrorResponse, ProblemJson, RetryAdvice,
+    ErrorResponse, ErrorResponseBuilder, ProblemJson, RetryAdvice,
     problem_json::{
         CODE_MAPPINGS, CodeMapping, GrpcCode, ProblemMetadata, ProblemMetadataValue,
         mapping_for_code
     }
 };
 pub use result_ext::ResultExt;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub use retry::retry;
+pub use severity::Severity;
 
 #[cfg(feature = "tonic")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tonic")))]
-pub use crate::convert::StatusConversionError;
+pub use crate::convert::{ERROR_INFO_TYPE_URL, ErrorInfo, StatusConversionError};
@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Localized kind labels for client-facing payloads.
+//!
+//! Errors carrying a locale set with
+//! [`AppError::with_locale`](crate::AppError::with_locale) render the
+//! [`ProblemJson`](crate::ProblemJson) `title` and the generic message of bare
+//! or redacted errors in that language. English, Russian (`ru`) and Korean
+//! (`ko`) are built in; other languages fall back to English.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use masterror::{AppError, AppErrorKind, ProblemJson};
+//!
+//! let problem =
+//!     ProblemJson::from_app_error(AppError::bare(AppErrorKind::NotFound).with_locale("ru"));
+//! assert_eq!(problem.title, "Не найдено");
+//! ```
+
+use crate::AppErrorKind;
+
+impl AppErrorKind {
+    /// [`label`](Self::label) translated to `lang`.
+    ///
+    /// `lang` is a BCP 47 tag; only the primary subtag is matched, ignoring
+    /// case, so `ru`, `ru-RU` and `RU` are equivalent. Unsupported languages
+    /// return the English label.
+    ///
+    /// ```rust
+    /// use masterror::AppErrorKind;
+    ///
+    /// assert_eq!(
+    ///     AppErrorKind::NotFound.localized_label("ru-RU"),
+    ///     "Не найдено"
+    /// );
+    /// assert_eq!(AppErrorKind::NotFound.localized_label("ko"), "찾을 수 없음");
+    /// assert_eq!(AppErrorKind::NotFound.localized_label("de"), "Not found");
+    /// ```
+    #[must_use]
+    pub fn localized_label(&self, lang: &str) -> &'static str {
+        let primary = lang.split(['-', '_']).next().unwrap_or_default();
+        if primary.eq_ignore_ascii_case("ru") {
+            self.label_ru()
+        } else if primary.eq_ignore_ascii_case("ko") {
+            self.label_ko()
+        } else {
+            self.label()
+        }
+    }
+
+    const fn label_ru(&self) -> &'static str {
+        match self {
+            Self::NotFound => "Не найдено",
+            Self::Validation => "Ошибка валидации",
+            Self::Conflict => "Конфликт",
+            Self::Unauthorized => "Не авторизован",
+            Self::Forbidden => "Доступ запрещён",
+            Self::NotImplemented => "Не реализовано",
+            Self::Internal => "Внутренняя ошибка сервера",
+            Self::BadRequest => "Некорректный запрос",
+            Self::MethodNotAllowed => "Метод не разрешён",
+            Self::PayloadTooLarge => "Слишком большой запрос",
+            Self::UnsupportedMediaType => "Неподдерживаемый тип данных",
+            Self::Locked => "Ресурс заблокирован",
+            Self::TelegramAuth => "Ошибка аутентификации Telegram",
+            Self::InvalidJwt => "Недействительный JWT",
+            Self::Database => "Ошибка базы данных",
+            Self::Service => "Ошибка сервиса",
+            Self::Config => "Ошибка конфигурации",
+            Self::Turnkey => "Ошибка Turnkey",
+            Self::Timeout => "Превышено время ожидания",
+            Self::Network => "Сетевая ошибка",
+            Self::RateLimited => "Превышен лимит запросов",
+            Self::DependencyUnavailable => "Внешняя зависимость недоступна",
+            Self::Serialization => "Ошибка сериализации",
+            Self::Deserialization => "Ошибка десериализации",
+            Self::ExternalApi => "Ошибка внешнего API",
+            Self::Queue => "Ошибка обработки очереди",
+            Self::Cache => "Ошибка кэша"
+        }
+    }
+
+    const fn label_ko(&self) -> &'static str {
+        match self {
+            Self::NotFound => "찾을 수 없음",
+            Self::Validation => "유효성 검사 오류",
+            Self::Conflict => "충돌",
+            Self::Unauthorized => "인증되지 않음",
+            Self::Forbidden => "접근 금지",
+            Self::NotImplemented => "구현되지 않음",
+            Self::Internal => "내부 서버 오류",
+            Self::BadRequest => "잘못된 요청",
+            Self::MethodNotAllowed => "허용되지 않은 메서드",
+            Self::PayloadTooLarge => "페이로드가 너무 큼",
+            Self::UnsupportedMediaType => "지원되지 않는 미디어 타입",
+            Self::Locked => "리소스 잠김",
+            Self::TelegramAuth => "Telegram 인증 오류",
+            Self::InvalidJwt => "유효하지 않은 JWT",
+            Self::Database => "데이터베이스 오류",
+            Self::Service => "서비스 오류",
+            Self::Config => "설정 오류",
+            Self::Turnkey => "Turnkey 오류",
+            Self::Timeout => "작업 시간 초과",
+            Self::Network => "네트워크 오류",
+            Self::RateLimited => "요청 한도 초과",
+            Self::DependencyUnavailable => "외부 의존성을 사용할 수 없음",
+            Self::Serialization => "직렬화 오류",
+            Self::Deserialization => "역직렬화 오류",
+            Self::ExternalApi => "외부 API 오류",
+            Self::Queue => "큐 처리 오류",
+            Self::Cache => "캐시 오류"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AppErrorKind;
+
+    #[test]
+    fn primary_subtag_selects_language() {
+        let kind = AppErrorKind::Forbidden;
+        assert_eq!(kind.localized_label("ru"), "Доступ запрещён");
+        assert_eq!(kind.localized_label("RU_ru"), "Доступ запрещён");
+        assert_eq!(kind.localized_label("ko-KR"), "접근 금지");
+        assert_eq!(kind.localized_label("en-GB"), "Forbidden");
+        assert_eq!(kind.localized_label(""), "Forbidden");
+        assert_eq!(kind.localized_label("rus"), "Forbidden");
+    }
+
+    #[test]
+    fn every_kind_is_translated() {
+        for &kind in AppErrorKind::ALL {
+            for lang in ["ru", "ko"] {
+                assert_ne!(
+                    kind.localized_label(lang),
+                    kind.label(),
+                    "{kind:?} lacks a {lang} label"
+                );
+            }
+        }
+    }
+}
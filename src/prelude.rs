@@ -30,6 +30,9 @@
 //! - Optional framework integrations (e.g. `IntoResponse` for Axum, Actix
 //!   `Responder`) remain gated behind feature flags and do not require explicit
 //!   imports from this prelude.
+//! - Handler-oriented bundles live in `prelude::axum` and `prelude::actix`
+//!   (behind the matching feature flags) and add
+//!   [`ProblemJson`](crate::ProblemJson) plus the framework response traits.
 //! - This keeps the public surface small, predictable, and easy to reason
 //!   about.
 
@@ -53,3 +56,43 @@ pub use crate::ErrorResponse;
 ///   strings
 #[cfg(feature = "turnkey")]
 pub use crate::turnkey::{TurnkeyError, TurnkeyErrorKind, classify_turnkey_error};
+
+/// Handler glue for Axum (enabled with the `axum` feature).
+///
+/// One import brings the core error types, [`ProblemJson`](crate::ProblemJson)
+/// and Axum's response traits into scope:
+///
+/// ```rust,ignore
+/// use masterror::prelude::axum::*;
+///
+/// async fn health() -> AppResult<&'static str> {
+///     Ok("ok")
+/// }
+/// ```
+#[cfg(feature = "axum")]
+#[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+pub mod axum {
+    pub use ::axum::response::{IntoResponse, Response};
+
+    pub use crate::{AppCode, AppError, AppErrorKind, AppResult, ErrorResponse, ProblemJson};
+}
+
+/// Handler glue for Actix Web (enabled with the `actix` feature).
+///
+/// One import brings the core error types, [`ProblemJson`](crate::ProblemJson)
+/// and Actix's response traits into scope:
+///
+/// ```rust,ignore
+/// use masterror::prelude::actix::*;
+///
+/// async fn health() -> AppResult<&'static str> {
+///     Ok("ok")
+/// }
+/// ```
+#[cfg(feature = "actix")]
+#[cfg_attr(docsrs, doc(cfg(feature = "actix")))]
+pub mod actix {
+    pub use ::actix_web::{HttpResponse, Responder, ResponseError};
+
+    pub use crate::{AppCode, AppError, AppErrorKind, AppResult, ErrorResponse, ProblemJson};
+}
@@ -60,6 +60,7 @@
 //! stable machine-readable code. A temporary [`ErrorResponse::new_legacy`] is
 //! provided as a deprecated shim.
 
+mod builder;
 mod core;
 mod details;
 pub mod internal;
@@ -76,6 +77,7 @@ pub(crate) mod actix_impl;
 
 pub use core::{ErrorResponse, RetryAdvice};
 
+pub use builder::ErrorResponseBuilder;
 pub use problem_json::ProblemJson;
 
 #[cfg(test)]
@@ -7,8 +7,12 @@
 //!
 //! Behavior:
 //! - Serializes the response as RFC7807 `application/problem+json`.
-//! - Adds `Retry-After` when retry advice is present.
+//! - Adds `Retry-After` when retry advice is present (rate-limited errors get a
+//!   default from
+//!   [`AppErrorKind::retry_after_hint`](crate::AppErrorKind::retry_after_hint)).
 //! - Adds `WWW-Authenticate` when an authentication challenge is provided.
+//! - Adds `Allow` when the error lists the allowed methods (see
+//!   [`AppError::method_not_allowed`](crate::AppError::method_not_allowed)).
 //! - Redaction itself happens earlier, in
 //!   [`ProblemJson::from_app_error`](crate::ProblemJson::from_app_error), which
 //!   applies the error's message and metadata redaction policies before the
@@ -17,7 +21,7 @@
 use actix_web::{
     HttpRequest, HttpResponse, Responder,
     body::BoxBody,
-    http::header::{CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE}
+    http::header::{ALLOW, CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE}
 };
 use itoa::Buffer as IntegerBuffer;
 
@@ -42,6 +46,7 @@ pub(crate) fn respond_with_problem_json(mut problem: ProblemJson) -> HttpRespons
         .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR);
     let retry_after = problem.retry_after;
     let www_authenticate = problem.www_authenticate.take();
+    let allow = problem.allow.take();
     let mut response = HttpResponse::build(status).json(problem);
     response
         .headers_mut()
@@ -58,6 +63,11 @@ pub(crate) fn respond_with_problem_json(mut problem: ProblemJson) -> HttpRespons
     {
         response.headers_mut().insert(WWW_AUTHENTICATE, hv);
     }
+    if let Some(methods) = allow
+        && let Ok(hv) = methods.parse()
+    {
+        response.headers_mut().insert(ALLOW, hv);
+    }
     response
 }
 
@@ -81,7 +91,7 @@ impl Responder for ErrorResponse {
 mod tests {
     use actix_web::{
         Responder,
-        http::header::{CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE},
+        http::header::{ALLOW, CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE},
         test
     };
 
@@ -113,6 +123,29 @@ mod tests {
         assert_eq!(retry, Some("60"));
     }
 
+    #[actix_web::test]
+    async fn rate_limited_response_defaults_retry_after_header() {
+        let problem = ProblemJson::from_app_error(AppError::rate_limited("too many requests"));
+        let response = respond_with_problem_json(problem);
+        assert_eq!(response.status(), 429);
+        let retry = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok());
+        assert_eq!(retry, Some("60"));
+    }
+
+    #[actix_web::test]
+    async fn explicit_retry_advice_overrides_default_header() {
+        let error = AppError::rate_limited_after("too many requests", 5);
+        let response = respond_with_problem_json(ProblemJson::from_ref(&error));
+        let retry = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok());
+        assert_eq!(retry, Some("5"));
+    }
+
     #[actix_web::test]
     async fn respond_with_problem_json_includes_www_authenticate_header() {
         let error =
@@ -147,6 +180,24 @@ mod tests {
         assert_eq!(auth, Some("Bearer"));
     }
 
+    #[actix_web::test]
+    async fn respond_with_problem_json_includes_allow_header() {
+        let problem = ProblemJson::from_app_error(AppError::method_not_allowed(&["GET", "HEAD"]));
+        let response = respond_with_problem_json(problem);
+        assert_eq!(response.status(), 405);
+        let allow = response.headers().get(ALLOW).and_then(|v| v.to_str().ok());
+        assert_eq!(allow, Some("GET, HEAD"));
+    }
+
+    #[actix_web::test]
+    async fn redacted_method_not_allowed_keeps_allow_header() {
+        let error = AppError::method_not_allowed(&["GET", "HEAD"]).redactable();
+        let response = respond_with_problem_json(ProblemJson::from_ref(&error));
+        assert_eq!(response.status(), 405);
+        let allow = response.headers().get(ALLOW).and_then(|v| v.to_str().ok());
+        assert_eq!(allow, Some("GET, HEAD"));
+    }
+
     #[actix_web::test]
     async fn problem_json_responder_returns_valid_response() {
         let req = test::TestRequest::default().to_http_request();
@@ -8,8 +8,12 @@
 //! Behavior:
 //! - Serializes the response as `application/problem+json` with the given
 //!   status.
-//! - Adds `Retry-After` if retry advice is present.
+//! - Adds `Retry-After` if retry advice is present (rate-limited errors get a
+//!   default from
+//!   [`AppErrorKind::retry_after_hint`](crate::AppErrorKind::retry_after_hint)).
 //! - Adds `WWW-Authenticate` if an authentication challenge is present.
+//! - Adds `Allow` if the error lists the allowed methods (see
+//!   [`AppError::method_not_allowed`](crate::AppError::method_not_allowed)).
 //! - Redaction itself happens earlier, in
 //!   [`ProblemJson::from_app_error`](crate::ProblemJson::from_app_error), which
 //!   applies the error's message and metadata redaction policies before the
@@ -19,7 +23,7 @@ use axum::{
     Json,
     http::{
         HeaderValue,
-        header::{CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE}
+        header::{ALLOW, CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE}
     },
     response::{IntoResponse, Response}
 };
@@ -33,6 +37,7 @@ impl IntoResponse for ProblemJson {
         let status = body.status_code();
         let retry_after = body.retry_after;
         let www_authenticate = body.www_authenticate.take();
+        let allow = body.allow.take();
         let mut response = (status, Json(body)).into_response();
         response.headers_mut().insert(
             CONTENT_TYPE,
@@ -50,6 +55,11 @@ impl IntoResponse for ProblemJson {
         {
             response.headers_mut().insert(WWW_AUTHENTICATE, hv);
         }
+        if let Some(methods) = allow
+            && let Ok(hv) = HeaderValue::from_str(&methods)
+        {
+            response.headers_mut().insert(ALLOW, hv);
+        }
         response
     }
 }
@@ -65,7 +75,7 @@ mod tests {
     use axum::{
         http::{
             StatusCode,
-            header::{CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE}
+            header::{ALLOW, CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE}
         },
         response::IntoResponse
     };
@@ -97,6 +107,29 @@ mod tests {
         assert_eq!(retry, Some("120"));
     }
 
+    #[tokio::test]
+    async fn rate_limited_response_defaults_retry_after_header() {
+        let response = AppError::rate_limited("too many requests").into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let retry = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok());
+        assert_eq!(retry, Some("60"));
+        let response = AppError::timeout("slow").into_response();
+        assert!(response.headers().get(RETRY_AFTER).is_none());
+    }
+
+    #[tokio::test]
+    async fn explicit_retry_advice_overrides_default_header() {
+        let response = AppError::rate_limited_after("too many requests", 5).into_response();
+        let retry = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok());
+        assert_eq!(retry, Some("5"));
+    }
+
     #[tokio::test]
     async fn problem_json_into_response_includes_www_authenticate_header() {
         let error = AppError::unauthorized("invalid credentials")
@@ -131,6 +164,31 @@ mod tests {
         assert_eq!(auth, Some("Bearer"));
     }
 
+    #[tokio::test]
+    async fn problem_json_into_response_includes_allow_header() {
+        let problem = ProblemJson::from_app_error(AppError::method_not_allowed(&["GET", "POST"]));
+        let response = problem.into_response();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        let allow = response.headers().get(ALLOW).and_then(|v| v.to_str().ok());
+        assert_eq!(allow, Some("GET, POST"));
+    }
+
+    #[tokio::test]
+    async fn redacted_method_not_allowed_keeps_allow_header() {
+        let error = AppError::method_not_allowed(&["GET", "POST"]).redactable();
+        let response = ProblemJson::from_app_error(error).into_response();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        let allow = response.headers().get(ALLOW).and_then(|v| v.to_str().ok());
+        assert_eq!(allow, Some("GET, POST"));
+    }
+
+    #[tokio::test]
+    async fn problem_json_into_response_omits_allow_header_by_default() {
+        let problem = ProblemJson::from_app_error(AppError::bad_request("bad"));
+        let response = problem.into_response();
+        assert!(response.headers().get(ALLOW).is_none());
+    }
+
     #[tokio::test]
     async fn error_response_into_response_converts_correctly() {
         let error_response =
@@ -0,0 +1,263 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+#[cfg(feature = "serde_json")]
+use alloc::string::ToString;
+use core::time::Duration;
+
+use http::StatusCode;
+#[cfg(feature = "serde_json")]
+use serde::Serialize;
+#[cfg(feature = "serde_json")]
+use serde_json::{Value as JsonValue, to_value};
+
+use super::core::{ErrorResponse, RetryAdvice};
+#[cfg(feature = "serde_json")]
+use crate::AppError;
+use crate::{AppCode, AppResult};
+
+/// Step-by-step constructor for [`ErrorResponse`].
+///
+/// Created by [`ErrorResponse::builder`]. Every setter is infallible; the
+/// status and any typed details are validated once in
+/// [`build`](Self::build).
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{AppCode, ErrorResponse};
+///
+/// let resp = ErrorResponse::builder(429, AppCode::RateLimited)
+///     .message("slow down")
+///     .retry_after_secs(30)
+///     .build()
+///     .expect("status");
+/// assert_eq!(resp.status, 429);
+/// assert_eq!(resp.retry.expect("retry").after_seconds, 30);
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "call `build` to produce the ErrorResponse"]
+pub struct ErrorResponseBuilder {
+    status:           u16,
+    code:             AppCode,
+    message:          Option<String>,
+    #[cfg(feature = "serde_json")]
+    details:          Option<JsonValue>,
+    #[cfg(not(feature = "serde_json"))]
+    details:          Option<String>,
+    #[cfg(feature = "serde_json")]
+    details_error:    Option<String>,
+    retry:            Option<RetryAdvice>,
+    www_authenticate: Option<String>
+}
+
+impl ErrorResponse {
+    /// Start building an [`ErrorResponse`] for `status` and `code`.
+    ///
+    /// See [`ErrorResponseBuilder`] for the available setters.
+    pub fn builder(status: u16, code: AppCode) -> ErrorResponseBuilder {
+        ErrorResponseBuilder {
+            status,
+            code,
+            message: None,
+            details: None,
+            #[cfg(feature = "serde_json")]
+            details_error: None,
+            retry: None,
+            www_authenticate: None
+        }
+    }
+}
+
+impl ErrorResponseBuilder {
+    /// Set the public message.
+    ///
+    /// When omitted, [`build`](Self::build) uses the canonical reason phrase
+    /// of the status (e.g. `Not Found`), or an empty string if it has none.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Attach structured JSON details.
+    #[cfg(feature = "serde_json")]
+    pub fn details_json(mut self, details: JsonValue) -> Self {
+        self.details = Some(details);
+        self.details_error = None;
+        self
+    }
+
+    /// Serialize and attach structured details from any [`Serialize`] value.
+    ///
+    /// Serialization failures are reported by [`build`](Self::build).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppCode, ErrorResponse};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Conflict {
+    ///     resource: &'static str
+    /// }
+    ///
+    /// let resp = ErrorResponse::builder(409, AppCode::Conflict)
+    ///     .details(Conflict {
+    ///         resource: "user"
+    ///     })
+    ///     .build()
+    ///     .expect("response");
+    /// assert_eq!(resp.details.expect("details")["resource"], "user");
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn details<T>(mut self, payload: T) -> Self
+    where
+        T: Serialize
+    {
+        match to_value(payload) {
+            Ok(details) => {
+                self.details = Some(details);
+                self.details_error = None;
+            }
+            Err(err) => {
+                self.details = None;
+                self.details_error = Some(err.to_string());
+            }
+        }
+        self
+    }
+
+    /// Attach plain-text details (available when `serde_json` is disabled).
+    #[cfg(not(feature = "serde_json"))]
+    pub fn details_text(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Attach retry advice (number of seconds).
+    pub fn retry_after_secs(mut self, secs: u64) -> Self {
+        self.retry = Some(RetryAdvice {
+            after_seconds: secs
+        });
+        self
+    }
+
+    /// Attach retry advice as a [`Duration`].
+    pub fn retry_after_duration(self, dur: Duration) -> Self {
+        self.retry_after_secs(dur.as_secs())
+    }
+
+    /// Attach an authentication challenge string.
+    pub fn www_authenticate(mut self, value: impl Into<String>) -> Self {
+        self.www_authenticate = Some(value.into());
+        self
+    }
+
+    /// Validate the status and assemble the [`ErrorResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppError`](crate::AppError) if `status` is not a valid HTTP
+    /// status code or if serializing typed details failed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppCode, AppErrorKind, ErrorResponse};
+    ///
+    /// let err = ErrorResponse::builder(1000, AppCode::Internal)
+    ///     .message("bad status")
+    ///     .build()
+    ///     .unwrap_err();
+    /// assert_eq!(err.kind, AppErrorKind::BadRequest);
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn build(self) -> AppResult<ErrorResponse> {
+        let message = match self.message {
+            Some(message) => message,
+            None => StatusCode::from_u16(self.status)
+                .ok()
+                .and_then(|status| status.canonical_reason())
+                .map(String::from)
+                .unwrap_or_default()
+        };
+        let mut resp = ErrorResponse::new(self.status, self.code, message)?;
+        #[cfg(feature = "serde_json")]
+        if let Some(err) = self.details_error {
+            return Err(AppError::bad_request(err));
+        }
+        resp.details = self.details;
+        resp.retry = self.retry;
+        resp.www_authenticate = self.www_authenticate;
+        Ok(resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppCode, AppErrorKind, ErrorResponse};
+
+    #[test]
+    fn builder_sets_every_field() {
+        let builder = ErrorResponse::builder(401, AppCode::Unauthorized)
+            .message("token expired")
+            .retry_after_secs(5)
+            .www_authenticate("Bearer realm=\"api\"");
+        #[cfg(feature = "serde_json")]
+        let builder = builder.details_json(serde_json::json!({"scope": "admin"}));
+        #[cfg(not(feature = "serde_json"))]
+        let builder = builder.details_text("scope: admin");
+        let resp = builder.build().expect("response");
+        assert_eq!(resp.status, 401);
+        assert_eq!(resp.code, AppCode::Unauthorized);
+        assert_eq!(resp.message, "token expired");
+        assert_eq!(resp.retry.expect("retry").after_seconds, 5);
+        assert_eq!(
+            resp.www_authenticate.as_deref(),
+            Some("Bearer realm=\"api\"")
+        );
+        #[cfg(feature = "serde_json")]
+        assert_eq!(resp.details, Some(serde_json::json!({"scope": "admin"})));
+        #[cfg(not(feature = "serde_json"))]
+        assert_eq!(resp.details.as_deref(), Some("scope: admin"));
+    }
+
+    #[test]
+    fn builder_defaults_message_to_reason_phrase() {
+        let resp = ErrorResponse::builder(404, AppCode::NotFound)
+            .build()
+            .expect("response");
+        assert_eq!(resp.message, "Not Found");
+        assert!(resp.details.is_none());
+        assert!(resp.retry.is_none());
+        assert!(resp.www_authenticate.is_none());
+    }
+
+    #[test]
+    fn builder_rejects_invalid_status_at_build() {
+        let err = ErrorResponse::builder(1000, AppCode::Internal)
+            .message("bad status")
+            .retry_after_secs(1)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind, AppErrorKind::BadRequest);
+        assert_eq!(err.message.as_deref(), Some("invalid HTTP status: 1000"));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn builder_reports_details_serialization_failure() {
+        use std::collections::BTreeMap;
+
+        let mut payload = BTreeMap::new();
+        payload.insert((1, 2), "tuple keys are not valid JSON object keys");
+        let err = ErrorResponse::builder(422, AppCode::Validation)
+            .details(payload)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind, AppErrorKind::BadRequest);
+    }
+}
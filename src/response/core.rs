@@ -97,6 +97,24 @@ impl ErrorResponse {
         StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
     }
 
+    /// Rebuild an [`AppError`] from this response.
+    ///
+    /// Shorthand for [`AppError::from`]; see that conversion for how the
+    /// status is mapped back to a kind.
+    ///
+    /// # Examples
+    /// ```
+    /// use masterror::{AppCode, AppErrorKind, ErrorResponse};
+    ///
+    /// let resp = ErrorResponse::new(429, AppCode::RateLimited, "slow down").expect("status");
+    /// let err = resp.into_app_error();
+    /// assert_eq!(err.kind, AppErrorKind::RateLimited);
+    /// ```
+    #[must_use]
+    pub fn into_app_error(self) -> AppError {
+        AppError::from(self)
+    }
+
     /// Formatter exposing internals for diagnostic logs.
     #[must_use]
     pub fn internal(&self) -> crate::response::internal::ErrorResponseFormatter<'_> {
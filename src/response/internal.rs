@@ -61,6 +61,7 @@ impl Debug for ProblemJsonFormatter<'_> {
             .field("status", &self.inner.status)
             .field("detail", &self.inner.detail)
             .field("details", &self.inner.details)
+            .field("help", &self.inner.help)
             .field("code", &self.inner.code)
             .field("grpc", &self.inner.grpc)
             .field("metadata", &self.inner.metadata)
@@ -2,16 +2,21 @@
 //
 // SPDX-License-Identifier: MIT
 
-//! Mapping from [`AppError`] to [`ErrorResponse`].
+//! Mapping between [`AppError`] and [`ErrorResponse`].
 //!
 //! This module provides [`From`] trait implementations and [`Display`]
-//! formatting for converting application errors into HTTP-ready responses.
+//! formatting for converting application errors into HTTP-ready responses
+//! and back.
 //!
 //! # Conversions
 //!
 //! - [`From<AppError>`]: Consumes the error, transferring ownership of message
 //!   and metadata
 //! - [`From<&AppError>`]: Borrows the error, cloning message and metadata
+//! - [`From<ErrorResponse>`] for [`AppError`]: Rebuilds an error received from
+//!   a remote service (the source chain cannot be restored)
+//! - [`AppError::from_status_and_body`] (requires `serde_json`): Rebuilds an
+//!   error from an upstream status and raw JSON body
 //!
 //! Both conversions respect the [`MessageEditPolicy`] to control message
 //! visibility.
@@ -42,7 +47,7 @@ use core::{
 };
 
 use super::core::ErrorResponse;
-use crate::{AppCode, AppError};
+use crate::{AppCode, AppError, AppErrorKind};
 
 /// Format [`ErrorResponse`] for logging and debugging.
 ///
@@ -91,10 +96,13 @@ impl From<AppError> for ErrorResponse {
         let retry = err.retry.take();
         let www_authenticate = err.www_authenticate.take();
         let policy = err.edit_policy;
-        let status = kind.http_status();
-        let message = match err.message.take() {
-            Some(msg) if !matches!(policy, crate::MessageEditPolicy::Redact) => msg.into_owned(),
-            _ => String::from(kind.label())
+        let status = err.status();
+        let message = match (err.public_message.take(), err.message.take()) {
+            (Some(public), _) => public,
+            (None, Some(msg)) if !matches!(policy, crate::MessageEditPolicy::Redact) => {
+                msg.into_owned()
+            }
+            _ => String::from(err.localized_label())
         };
         #[cfg(feature = "serde_json")]
         let details = if matches!(policy, crate::MessageEditPolicy::Redact) {
@@ -140,11 +148,13 @@ impl From<AppError> for ErrorResponse {
 /// [`MessageEditPolicy`]: crate::MessageEditPolicy
 impl From<&AppError> for ErrorResponse {
     fn from(err: &AppError) -> Self {
-        let status = err.kind.http_status();
-        let message = if matches!(err.edit_policy, crate::MessageEditPolicy::Redact) {
-            String::from(err.kind.label())
-        } else {
-            err.render_message().into_owned()
+        let status = err.status();
+        let message = match (err.public_message.as_deref(), err.message.as_deref()) {
+            (Some(public), _) => String::from(public),
+            (None, Some(msg)) if !matches!(err.edit_policy, crate::MessageEditPolicy::Redact) => {
+                String::from(msg)
+            }
+            _ => String::from(err.localized_label())
         };
         #[cfg(feature = "serde_json")]
         let details = if matches!(err.edit_policy, crate::MessageEditPolicy::Redact) {
@@ -168,3 +178,107 @@ impl From<&AppError> for ErrorResponse {
         }
     }
 }
+
+/// Rebuild an [`AppError`] from a received [`ErrorResponse`].
+///
+/// The kind is resolved from `status` via [`TryFrom<u16>`] and falls back to
+/// [`AppErrorKind::Internal`] for statuses outside the taxonomy. Code,
+/// message, details, retry advice and authentication challenge are kept; the
+/// source chain never crosses the wire and cannot be restored.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{AppCode, AppError, AppErrorKind, ErrorResponse};
+///
+/// let resp = ErrorResponse::new(404, AppCode::NotFound, "user not found")
+///     .expect("status")
+///     .with_retry_after_secs(5);
+/// let err = AppError::from(resp);
+///
+/// assert_eq!(err.kind, AppErrorKind::NotFound);
+/// assert_eq!(err.code, AppCode::NotFound);
+/// assert_eq!(err.message.as_deref(), Some("user not found"));
+/// assert_eq!(err.retry.map(|retry| retry.after_seconds), Some(5));
+/// ```
+impl From<ErrorResponse> for AppError {
+    fn from(response: ErrorResponse) -> Self {
+        let ErrorResponse {
+            status,
+            code,
+            message,
+            details,
+            retry,
+            www_authenticate
+        } = response;
+        let kind = AppErrorKind::try_from(status).unwrap_or(AppErrorKind::Internal);
+        let mut err = AppError::with(kind, message).with_code(code);
+        err.details = details;
+        err.retry = retry;
+        err.www_authenticate = www_authenticate;
+        err
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl AppError {
+    /// Rebuild an error from an upstream HTTP status and JSON body.
+    ///
+    /// The kind is resolved from `status` like the [`From<ErrorResponse>`]
+    /// conversion. Bodies that look like an [`ErrorResponse`] or
+    /// [`ProblemJson`](crate::ProblemJson) payload (an object with a valid
+    /// `code` plus a `message`, `detail` or `title`) contribute their code,
+    /// message (`message`, falling back to `detail`) and `details`. Any other
+    /// body is kept verbatim in `details`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind};
+    /// use serde_json::json;
+    ///
+    /// let body = json!({"status": 404, "code": "USER_MISSING", "message": "no such user"});
+    /// let err = AppError::from_status_and_body(404, &body);
+    /// assert_eq!(err.kind, AppErrorKind::NotFound);
+    /// assert_eq!(err.code.as_str(), "USER_MISSING");
+    /// assert_eq!(err.message.as_deref(), Some("no such user"));
+    ///
+    /// let opaque = json!(["upstream", "exploded"]);
+    /// let err = AppError::from_status_and_body(502, &opaque);
+    /// assert_eq!(err.kind, AppErrorKind::Internal);
+    /// assert_eq!(err.details, Some(opaque));
+    /// ```
+    #[must_use]
+    pub fn from_status_and_body(status: u16, body: &serde_json::Value) -> Self {
+        let kind = AppErrorKind::try_from(status).unwrap_or(AppErrorKind::Internal);
+        let Some((code, message, details)) = recognize_error_body(body) else {
+            return AppError::bare(kind).with_details_json(body.clone());
+        };
+        let mut err = match message {
+            Some(message) => AppError::with(kind, String::from(message)),
+            None => AppError::bare(kind)
+        };
+        err = err.with_code(code);
+        if let Some(details) = details {
+            err = err.with_details_json(details.clone());
+        }
+        err
+    }
+}
+
+/// Extract `code`, message and `details` from an [`ErrorResponse`]- or
+/// problem-shaped JSON object.
+#[cfg(feature = "serde_json")]
+fn recognize_error_body(
+    body: &serde_json::Value
+) -> Option<(AppCode, Option<&str>, Option<&serde_json::Value>)> {
+    let object = body.as_object()?;
+    let code = object.get("code")?.as_str()?.parse::<AppCode>().ok()?;
+    let text = |key: &str| object.get(key).and_then(serde_json::Value::as_str);
+    if text("message").is_none() && text("detail").is_none() && text("title").is_none() {
+        return None;
+    }
+    let message = text("message").or_else(|| text("detail"));
+    let details = object.get("details").filter(|details| !details.is_null());
+    Some((code, message, details))
+}
@@ -5,6 +5,7 @@
 use alloc::{
     borrow::Cow,
     collections::BTreeMap,
+    format,
     string::{String, ToString}
 };
 use core::{
@@ -15,12 +16,18 @@ use core::{
 use http::StatusCode;
 use serde::Serialize;
 #[cfg(feature = "serde_json")]
+use serde::{Deserialize, Deserializer, de::Error as DeError};
+#[cfg(feature = "serde_json")]
 use serde_json::Value as JsonValue;
 
 use super::core::ErrorResponse;
 use crate::{
-    AppCode, AppError, AppErrorKind, FieldRedaction, FieldValue, MessageEditPolicy, Metadata,
-    app_error::redaction::{REDACTED_PLACEHOLDER, hash_field_value, mask_last4_field_value}
+    AppCode, AppError, AppErrorKind, AppResult, FieldRedaction, FieldValue, MessageEditPolicy,
+    Metadata,
+    app_error::{
+        non_finite_label,
+        redaction::{REDACTED_PLACEHOLDER, hash_field_value, mask_last4_field_value}
+    }
 };
 
 /// Canonical mapping for a public [`AppCode`].
@@ -94,6 +101,54 @@ pub struct GrpcCode {
     pub value: i32
 }
 
+/// Canonical gRPC code names indexed by their numeric value.
+#[cfg(feature = "serde_json")]
+const GRPC_CODE_NAMES: [&str; 17] = [
+    "OK",
+    "CANCELLED",
+    "UNKNOWN",
+    "INVALID_ARGUMENT",
+    "DEADLINE_EXCEEDED",
+    "NOT_FOUND",
+    "ALREADY_EXISTS",
+    "PERMISSION_DENIED",
+    "RESOURCE_EXHAUSTED",
+    "FAILED_PRECONDITION",
+    "ABORTED",
+    "OUT_OF_RANGE",
+    "UNIMPLEMENTED",
+    "INTERNAL",
+    "UNAVAILABLE",
+    "DATA_LOSS",
+    "UNAUTHENTICATED"
+];
+
+/// Deserialized from `{"name": .., "value": ..}`; the canonical name is
+/// derived from `value`, so upstream spelling of `name` does not matter.
+#[cfg(feature = "serde_json")]
+impl<'de> Deserialize<'de> for GrpcCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            value: i32
+        }
+        let Raw {
+            value
+        } = Raw::deserialize(deserializer)?;
+        usize::try_from(value)
+            .ok()
+            .and_then(|index| GRPC_CODE_NAMES.get(index))
+            .map(|name| Self {
+                name,
+                value
+            })
+            .ok_or_else(|| D::Error::custom(format!("unknown gRPC code: {value}")))
+    }
+}
+
 /// RFC7807 `application/problem+json` payload enriched with machine-readable
 /// metadata.
 ///
@@ -110,7 +165,33 @@ pub struct GrpcCode {
 /// assert_eq!(problem.status, 404);
 /// assert_eq!(problem.code.as_str(), "NOT_FOUND");
 /// ```
+///
+/// With `serde_json` the payload also implements `Deserialize`, so problem
+/// documents from upstream services can be consumed. `status` must be a valid
+/// HTTP status and members without a dedicated field are kept in
+/// [`extensions`](Self::extensions). Plain RFC 9457 documents may omit `code`
+/// and `title`; both are then derived from `status`, with the code falling
+/// back to [`AppCode::Internal`] for statuses outside the taxonomy:
+///
+/// ```rust
+/// # #[cfg(feature = "serde_json")]
+/// # {
+/// use masterror::ProblemJson;
+///
+/// let problem: ProblemJson = serde_json::from_str(
+///     r#"{"title":"Not found","status":404,"code":"NOT_FOUND","trace":"abc"}"#
+/// )
+/// .expect("problem");
+/// assert_eq!(problem.status, 404);
+/// assert_eq!(problem.extensions["trace"], "abc");
+/// # }
+/// ```
 #[derive(Clone, Debug, Serialize)]
+#[cfg_attr(
+    feature = "serde_json",
+    derive(Deserialize),
+    serde(from = "ProblemDocument")
+)]
 pub struct ProblemJson {
     /// Canonical type URI describing the problem class.
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
@@ -130,6 +211,9 @@ pub struct ProblemJson {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg(not(feature = "serde_json"))]
     pub details:          Option<String>,
+    /// Optional client-safe remediation hint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help:             Option<String>,
     /// Stable machine-readable code.
     pub code:             AppCode,
     /// Optional gRPC mapping for multi-protocol clients.
@@ -139,11 +223,97 @@ pub struct ProblemJson {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata:         Option<ProblemMetadata>,
     /// Retry advice propagated as the `Retry-After` header.
+    ///
+    /// Built from the error's [`RetryAdvice`](crate::RetryAdvice), falling
+    /// back to [`AppErrorKind::retry_after_hint`](crate::AppErrorKind::retry_after_hint).
     #[serde(skip)]
     pub retry_after:      Option<u64>,
     /// Authentication challenge propagated as `WWW-Authenticate`.
     #[serde(skip)]
-    pub www_authenticate: Option<String>
+    pub www_authenticate: Option<String>,
+    /// Allowed methods propagated as the `Allow` header.
+    #[serde(skip)]
+    pub allow:            Option<String>,
+    /// Extension members without a dedicated field, serialized inline.
+    ///
+    /// Empty for payloads built from [`AppError`]; filled when deserializing
+    /// documents produced elsewhere.
+    #[serde(flatten)]
+    #[cfg(feature = "serde_json")]
+    pub extensions:       BTreeMap<String, JsonValue>,
+    /// Textual extension members serialized inline when JSON is disabled.
+    #[serde(flatten)]
+    #[cfg(not(feature = "serde_json"))]
+    pub extensions:       BTreeMap<String, String>
+}
+
+/// Received problem document before `code` and `title` defaults are resolved.
+#[cfg(feature = "serde_json")]
+#[derive(Deserialize)]
+struct ProblemDocument {
+    #[serde(rename = "type")]
+    type_uri:   Option<Cow<'static, str>>,
+    title:      Option<Cow<'static, str>>,
+    #[serde(deserialize_with = "deserialize_status")]
+    status:     u16,
+    detail:     Option<Cow<'static, str>>,
+    details:    Option<JsonValue>,
+    help:       Option<String>,
+    code:       Option<AppCode>,
+    grpc:       Option<GrpcCode>,
+    metadata:   Option<ProblemMetadata>,
+    #[serde(flatten)]
+    extensions: BTreeMap<String, JsonValue>
+}
+
+#[cfg(feature = "serde_json")]
+impl From<ProblemDocument> for ProblemJson {
+    fn from(document: ProblemDocument) -> Self {
+        let known = AppErrorKind::try_from(document.status).ok();
+        let kind = known.unwrap_or(AppErrorKind::Internal);
+        let title = document.title.unwrap_or_else(|| {
+            let reason = StatusCode::from_u16(document.status)
+                .ok()
+                .and_then(|status| status.canonical_reason());
+            Cow::Borrowed(match (known, reason) {
+                (None, Some(reason)) => reason,
+                _ => kind.label()
+            })
+        });
+        Self {
+            type_uri: document.type_uri,
+            title,
+            status: document.status,
+            detail: document.detail,
+            details: document.details,
+            help: document.help,
+            code: document.code.unwrap_or_else(|| AppCode::from(kind)),
+            grpc: document.grpc,
+            metadata: document.metadata,
+            retry_after: None,
+            www_authenticate: None,
+            allow: None,
+            extensions: document.extensions
+        }
+    }
+}
+
+/// Whether `status` is an HTTP status code a problem document may carry.
+const fn is_problem_status(status: u16) -> bool {
+    status >= 100 && status <= 599
+}
+
+#[cfg(feature = "serde_json")]
+fn deserialize_status<'de, D>(deserializer: D) -> Result<u16, D::Error>
+where
+    D: Deserializer<'de>
+{
+    let status = u16::deserialize(deserializer)?;
+    if is_problem_status(status) {
+        Ok(status)
+    } else {
+        Err(D::Error::custom(format!("invalid HTTP status: {status}")))
+    }
 }
 
 impl ProblemJson {
@@ -173,22 +343,32 @@ impl ProblemJson {
         let details = sanitize_details_owned(error.details.take(), edit_policy);
         let retry = error.retry.take();
         let www_authenticate = error.www_authenticate.take();
+        let allow = error.allow.take();
+        let help = error.help.take();
         let mapping = mapping_for_code(&code);
-        let status = kind.http_status();
-        let title = Cow::Borrowed(kind.label());
-        let detail = sanitize_detail(message, kind, edit_policy);
+        let status = error.status();
+        let title = error.localized_label();
+        let detail = match error.public_message.take() {
+            Some(public) => Some(Cow::Owned(public)),
+            None => sanitize_detail(message, title, edit_policy)
+        };
         let metadata = sanitize_metadata_owned(metadata, edit_policy);
         Self {
             type_uri: Some(Cow::Borrowed(mapping.problem_type())),
-            title,
+            title: Cow::Borrowed(title),
             status,
             detail,
             details,
+            help,
             code,
             grpc: Some(mapping.grpc()),
             metadata,
-            retry_after: retry.map(|value| value.after_seconds),
-            www_authenticate
+            retry_after: retry
+                .map(|value| value.after_seconds)
+                .or(kind.retry_after_hint()),
+            www_authenticate,
+            allow,
+            extensions: BTreeMap::new()
         }
     }
 
@@ -210,8 +390,8 @@ impl ProblemJson {
     #[must_use]
     pub fn from_ref(error: &AppError) -> Self {
         let mapping = mapping_for_code(&error.code);
-        let status = error.kind.http_status();
-        let title = Cow::Borrowed(error.kind.label());
+        let status = error.status();
+        let title = Cow::Borrowed(error.localized_label());
         let detail = sanitize_detail_ref(error);
         let details = sanitize_details_ref(error);
         let metadata = sanitize_metadata_ref(error.metadata(), error.edit_policy);
@@ -221,11 +401,17 @@ impl ProblemJson {
             status,
             detail,
             details,
+            help: error.help.clone(),
             code: error.code.clone(),
             grpc: Some(mapping.grpc()),
             metadata,
-            retry_after: error.retry.map(|value| value.after_seconds),
-            www_authenticate: error.www_authenticate.clone()
+            retry_after: error
+                .retry
+                .map(|value| value.after_seconds)
+                .or(error.kind.retry_after_hint()),
+            www_authenticate: error.www_authenticate.clone(),
+            allow: error.allow.clone(),
+            extensions: BTreeMap::new()
         }
     }
 
@@ -261,15 +447,18 @@ impl ProblemJson {
         };
         Self {
             type_uri: Some(Cow::Borrowed(mapping.problem_type())),
-            title: Cow::Borrowed(mapping.kind().label()),
+            title: Cow::Borrowed(mapping.kind().problem_title()),
             status,
             detail,
             details,
+            help: None,
             code,
             grpc: Some(mapping.grpc()),
             metadata: None,
             retry_after: retry.map(|value| value.after_seconds),
-            www_authenticate
+            www_authenticate,
+            allow: None,
+            extensions: BTreeMap::new()
         }
     }
 
@@ -294,6 +483,39 @@ impl ProblemJson {
         }
     }
 
+    /// Check the invariants of a problem document.
+    ///
+    /// Deserialization already rejects invalid statuses; this also covers
+    /// payloads assembled or modified by hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BadRequest` [`AppError`] when `status` is outside
+    /// `100..=599` or `title` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, ProblemJson};
+    ///
+    /// let mut problem = ProblemJson::from_ref(&AppError::not_found("missing"));
+    /// assert!(problem.validate().is_ok());
+    /// problem.status = 700;
+    /// assert!(problem.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> AppResult<()> {
+        if !is_problem_status(self.status) {
+            return Err(AppError::bad_request(format!(
+                "invalid HTTP status: {}",
+                self.status
+            )));
+        }
+        if self.title.is_empty() {
+            return Err(AppError::bad_request("problem title is empty"));
+        }
+        Ok(())
+    }
+
     /// Formatter exposing internals for diagnostic logging.
     #[must_use]
     pub fn internal(&self) -> crate::response::internal::ProblemJsonFormatter<'_> {
@@ -313,6 +535,7 @@ impl ProblemJson {
 /// assert!(problem.metadata.is_some());
 /// ```
 #[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "serde_json", derive(Deserialize))]
 #[serde(transparent)]
 pub struct ProblemMetadata(BTreeMap<Cow<'static, str>, ProblemMetadataValue>);
 
@@ -335,6 +558,7 @@ impl ProblemMetadata {
 /// assert!(matches!(value, ProblemMetadataValue::U64(2)));
 /// ```
 #[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "serde_json", derive(Deserialize))]
 #[serde(untagged)]
 pub enum ProblemMetadataValue {
     /// String value preserved as-is.
@@ -361,13 +585,24 @@ pub enum ProblemMetadataValue {
     Json(JsonValue)
 }
 
+impl ProblemMetadataValue {
+    /// Finite floats stay numeric; `NaN` and infinities become their labels,
+    /// which JSON cannot represent as numbers.
+    fn from_f64(value: f64) -> Self {
+        match non_finite_label(value) {
+            Some(label) => Self::String(Cow::Borrowed(label)),
+            None => Self::F64(value)
+        }
+    }
+}
+
 impl From<FieldValue> for ProblemMetadataValue {
     fn from(value: FieldValue) -> Self {
         match value {
             FieldValue::Str(value) => Self::String(value),
             FieldValue::I64(value) => Self::I64(value),
             FieldValue::U64(value) => Self::U64(value),
-            FieldValue::F64(value) => Self::F64(value),
+            FieldValue::F64(value) => Self::from_f64(value),
             FieldValue::Bool(value) => Self::Bool(value),
             FieldValue::Uuid(value) => Self::String(Cow::Owned(value.to_string())),
             FieldValue::Duration(value) => Self::Duration {
@@ -387,7 +622,7 @@ impl From<&FieldValue> for ProblemMetadataValue {
             FieldValue::Str(value) => Self::String(value.clone()),
             FieldValue::I64(value) => Self::I64(*value),
             FieldValue::U64(value) => Self::U64(*value),
-            FieldValue::F64(value) => Self::F64(*value),
+            FieldValue::F64(value) => Self::from_f64(*value),
             FieldValue::Bool(value) => Self::Bool(*value),
             FieldValue::Uuid(value) => Self::String(Cow::Owned(value.to_string())),
             FieldValue::Duration(value) => Self::Duration {
@@ -403,23 +638,26 @@ impl From<&FieldValue> for ProblemMetadataValue {
 
 fn sanitize_detail(
     message: Option<Cow<'static, str>>,
-    kind: AppErrorKind,
+    title: &'static str,
     policy: MessageEditPolicy
 ) -> Option<Cow<'static, str>> {
     if matches!(policy, MessageEditPolicy::Redact) {
         return None;
     }
-    Some(message.unwrap_or_else(|| Cow::Borrowed(kind.label())))
+    Some(message.unwrap_or(Cow::Borrowed(title)))
 }
 
 fn sanitize_detail_ref(error: &AppError) -> Option<Cow<'static, str>> {
+    if let Some(public) = error.public_message.as_deref() {
+        return Some(Cow::Owned(String::from(public)));
+    }
     if matches!(error.edit_policy, MessageEditPolicy::Redact) {
         return None;
     }
     match error.message.as_ref() {
         Some(Cow::Borrowed(msg)) => Some(Cow::Borrowed(*msg)),
         Some(Cow::Owned(msg)) => Some(Cow::Owned(msg.clone())),
-        None => Some(Cow::Borrowed(error.kind.label()))
+        None => Some(Cow::Borrowed(error.localized_label()))
     }
 }
 
@@ -647,6 +885,54 @@ pub const CODE_MAPPINGS: &[(AppCode, CodeMapping)] = &[
             kind:         AppErrorKind::BadRequest
         }
     ),
+    (
+        AppCode::MethodNotAllowed,
+        CodeMapping {
+            http_status:  405,
+            grpc:         GrpcCode {
+                name:  "UNIMPLEMENTED",
+                value: 12
+            },
+            problem_type: "https://errors.masterror.rs/method-not-allowed",
+            kind:         AppErrorKind::MethodNotAllowed
+        }
+    ),
+    (
+        AppCode::PayloadTooLarge,
+        CodeMapping {
+            http_status:  413,
+            grpc:         GrpcCode {
+                name:  "RESOURCE_EXHAUSTED",
+                value: 8
+            },
+            problem_type: "https://errors.masterror.rs/payload-too-large",
+            kind:         AppErrorKind::PayloadTooLarge
+        }
+    ),
+    (
+        AppCode::UnsupportedMediaType,
+        CodeMapping {
+            http_status:  415,
+            grpc:         GrpcCode {
+                name:  "INVALID_ARGUMENT",
+                value: 3
+            },
+            problem_type: "https://errors.masterror.rs/unsupported-media-type",
+            kind:         AppErrorKind::UnsupportedMediaType
+        }
+    ),
+    (
+        AppCode::Locked,
+        CodeMapping {
+            http_status:  423,
+            grpc:         GrpcCode {
+                name:  "ABORTED",
+                value: 10
+            },
+            problem_type: "https://errors.masterror.rs/locked",
+            kind:         AppErrorKind::Locked
+        }
+    ),
     (
         AppCode::RateLimited,
         CodeMapping {
@@ -893,7 +1179,7 @@ mod tests {
     use crate::{
         AppError,
         app_error::redaction::mask_last4,
-        field::{duration, f64, ip, str, u64, uuid}
+        field::{bool, duration, f64, ip, secret, str, u64, uuid}
     };
 
     fn sha256_hex(input: &[u8]) -> String {
@@ -961,6 +1247,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bool_metadata_serializes_as_json_boolean() {
+        let err = AppError::service("cache miss").with_field(bool("cache_hit", false));
+        let problem = ProblemJson::from_ref(&err);
+        let json = serde_json::to_value(&problem).expect("serialize problem");
+        assert_eq!(json["metadata"]["cache_hit"], Value::Bool(false));
+    }
+
+    #[test]
+    fn non_finite_float_metadata_serializes_as_label() {
+        let err = AppError::service("probe failed")
+            .with_field(f64("latency_ms", 1e300))
+            .with_field(f64("ratio", f64::NAN))
+            .with_field(f64("ceiling", f64::INFINITY));
+        let json = serde_json::to_value(ProblemJson::from_app_error(err)).expect("serialize");
+        assert_eq!(json["metadata"]["latency_ms"], Value::from(1e300));
+        assert_eq!(json["metadata"]["ratio"], Value::from("NaN"));
+        assert_eq!(json["metadata"]["ceiling"], Value::from("inf"));
+    }
+
     #[test]
     fn redacted_metadata_uses_placeholder() {
         let err = AppError::internal("oops").with_field(str("password", "secret"));
@@ -975,6 +1281,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn secret_metadata_uses_placeholder() {
+        let err = AppError::internal("oops").with_field(secret("note", "private"));
+        assert_eq!(
+            err.metadata().redaction("note"),
+            Some(FieldRedaction::Redact)
+        );
+        let problem = ProblemJson::from_ref(&err);
+        let metadata = problem.metadata.expect("metadata");
+        match metadata.0.get("note").expect("note field") {
+            ProblemMetadataValue::String(text) => {
+                assert_eq!(text.as_ref(), super::REDACTED_PLACEHOLDER);
+            }
+            other => panic!("unexpected metadata value: {other:?}")
+        }
+    }
+
     #[test]
     fn hashed_metadata_masks_original_value() {
         let err = AppError::internal("oops").with_field(str("token", "super"));
@@ -1006,6 +1329,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn redact_all_fields_hashes_every_public_value() {
+        let err = AppError::internal("oops")
+            .with_field(str("user_id", "42"))
+            .with_field(u64("attempt", 7))
+            .redact_all_fields(FieldRedaction::Hash);
+        let problem = ProblemJson::from_ref(&err);
+        let metadata = problem.metadata.expect("metadata");
+        for (name, raw) in [("user_id", "42"), ("attempt", "7")] {
+            match metadata.0.get(name).expect("field") {
+                ProblemMetadataValue::String(text) => {
+                    assert_eq!(text.as_ref(), sha256_hex(raw.as_bytes()));
+                }
+                other => panic!("unexpected metadata value: {other:?}")
+            }
+        }
+    }
+
     #[test]
     fn hashed_uuid_metadata_preserves_hyphenated_text() {
         let trace_id = Uuid::from_u128(0x1234_5678_9abc_def0_1234_5678_9abc_def0);
@@ -1179,6 +1520,34 @@ mod tests {
         assert!(debug_repr.contains("ProblemJson"));
     }
 
+    #[test]
+    fn problem_json_exposes_help_independently_of_message() {
+        let err =
+            AppError::unauthorized("token expired").with_help("Try regenerating your token.");
+        let problem = ProblemJson::from_ref(&err);
+        assert_eq!(
+            problem.help.as_deref(),
+            Some("Try regenerating your token.")
+        );
+        assert_eq!(problem.detail.as_deref(), Some("token expired"));
+        let json = serde_json::to_value(&problem).expect("serialize problem");
+        assert_eq!(json["help"], "Try regenerating your token.");
+
+        let redacted = AppError::internal("db password leaked")
+            .redactable()
+            .with_help("Contact support.");
+        let problem = ProblemJson::from_app_error(redacted);
+        assert_eq!(problem.help.as_deref(), Some("Contact support."));
+        assert_eq!(problem.detail, None);
+    }
+
+    #[test]
+    fn problem_json_omits_absent_help() {
+        let problem = ProblemJson::from_ref(&AppError::not_found("missing"));
+        let json = serde_json::to_value(&problem).expect("serialize problem");
+        assert!(json.get("help").is_none());
+    }
+
     #[test]
     fn mapping_for_every_code_matches_http_status() {
         for (code, mapping) in CODE_MAPPINGS {
@@ -1187,4 +1556,165 @@ mod tests {
             assert_eq!(status, expected, "status mismatch for {:?}", code);
         }
     }
+
+    #[test]
+    fn title_matches_kind_problem_title_for_every_code() {
+        for (code, mapping) in CODE_MAPPINGS {
+            let problem = ProblemJson::from_app_error(AppError::new(mapping.kind(), "boom"));
+            assert_eq!(problem.title, mapping.kind().problem_title(), "{:?}", code);
+        }
+    }
+
+    #[test]
+    fn locale_translates_title_and_bare_detail() {
+        let err = AppError::bare(AppErrorKind::NotFound).with_locale("ru");
+        let problem = ProblemJson::from_ref(&err);
+        assert_eq!(problem.title, "Не найдено");
+        assert_eq!(problem.detail.as_deref(), Some("Не найдено"));
+        assert_eq!(ErrorResponse::from(&err).message, "Не найдено");
+
+        let problem = ProblemJson::from_app_error(err);
+        assert_eq!(problem.title, "Не найдено");
+        assert_eq!(problem.detail.as_deref(), Some("Не найдено"));
+    }
+
+    #[test]
+    fn locale_keeps_caller_messages() {
+        let err = AppError::not_found("user 7 missing").with_locale("ko");
+        let problem = ProblemJson::from_ref(&err);
+        assert_eq!(problem.title, "찾을 수 없음");
+        assert_eq!(problem.detail.as_deref(), Some("user 7 missing"));
+        assert_eq!(ErrorResponse::from(err).message, "user 7 missing");
+
+        let redacted = AppError::internal("db password leaked")
+            .redactable()
+            .with_locale("ru");
+        assert_eq!(
+            ErrorResponse::from(redacted).message,
+            "Внутренняя ошибка сервера"
+        );
+    }
+
+    #[test]
+    fn every_kind_has_status_title_and_grpc_mapping() {
+        for &kind in AppErrorKind::ALL {
+            let code = AppCode::from(kind);
+            let mapping = CODE_MAPPINGS
+                .iter()
+                .find_map(|(candidate, mapping)| (*candidate == code).then_some(*mapping))
+                .unwrap_or_else(|| panic!("{kind:?} has no entry in CODE_MAPPINGS"));
+            assert_eq!(mapping.kind(), kind, "{kind:?}");
+            assert_eq!(mapping.http_status(), kind.http_status(), "{kind:?}");
+            assert!((400..600).contains(&kind.http_status()), "{kind:?}");
+            assert!(!kind.problem_title().is_empty(), "{kind:?}");
+            assert!(!mapping.grpc().name.is_empty(), "{kind:?}");
+            assert!(mapping.problem_type().starts_with("https://"), "{kind:?}");
+            // FAILED_PRECONDITION tells gRPC clients not to retry as-is.
+            if kind.is_retryable() {
+                assert_ne!(mapping.grpc().name, "FAILED_PRECONDITION", "{kind:?}");
+            }
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn deserialize_accepts_plain_rfc9457_document() {
+        let problem: ProblemJson = serde_json::from_value(serde_json::json!({
+            "type": "https://example.com/probs/out-of-credit",
+            "title": "You do not have enough credit.",
+            "status": 403,
+            "detail": "Your current balance is 30, but that costs 50.",
+            "instance": "/account/12345/msgs/abc"
+        }))
+        .expect("problem");
+        assert_eq!(problem.title, "You do not have enough credit.");
+        assert_eq!(problem.code, AppCode::Forbidden);
+        assert_eq!(problem.extensions["instance"], "/account/12345/msgs/abc");
+        assert!(problem.validate().is_ok());
+
+        let problem: ProblemJson =
+            serde_json::from_value(serde_json::json!({"status": 404})).expect("problem");
+        assert_eq!(problem.title, AppErrorKind::NotFound.label());
+        assert_eq!(problem.code, AppCode::NotFound);
+
+        let problem: ProblemJson =
+            serde_json::from_value(serde_json::json!({"status": 402})).expect("problem");
+        assert_eq!(problem.title, "Payment Required");
+        assert_eq!(problem.code, AppCode::Internal);
+        assert!(problem.validate().is_ok());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn deserialize_captures_unknown_members_as_extensions() {
+        let problem: ProblemJson = serde_json::from_value(serde_json::json!({
+            "type": "https://errors.example.com/out-of-credit",
+            "title": "You do not have enough credit.",
+            "status": 403,
+            "detail": "Your current balance is 30, but that costs 50.",
+            "code": "FORBIDDEN",
+            "grpc": {"name": "PERMISSION_DENIED", "value": 7},
+            "metadata": {"attempt": 2},
+            "balance": 30,
+            "accounts": ["/account/12345", "/account/67890"]
+        }))
+        .expect("problem");
+        assert_eq!(
+            problem.type_uri.as_deref(),
+            Some("https://errors.example.com/out-of-credit")
+        );
+        assert_eq!(problem.title, "You do not have enough credit.");
+        assert_eq!(problem.status, 403);
+        assert_eq!(
+            problem.detail.as_deref(),
+            Some("Your current balance is 30, but that costs 50.")
+        );
+        assert_eq!(problem.code, AppCode::Forbidden);
+        assert_eq!(
+            problem.grpc,
+            Some(mapping_for_code(&AppCode::Forbidden).grpc())
+        );
+        assert!(problem.metadata.is_some());
+        assert_eq!(problem.extensions.len(), 2);
+        assert_eq!(problem.extensions["balance"], 30);
+        assert_eq!(
+            problem.extensions["accounts"],
+            serde_json::json!(["/account/12345", "/account/67890"])
+        );
+        assert!(problem.validate().is_ok());
+
+        let value = serde_json::to_value(&problem).expect("serialize");
+        assert_eq!(value["balance"], 30);
+        assert!(value.get("extensions").is_none());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn deserialize_rejects_out_of_range_status_and_unknown_grpc_code() {
+        let parse = |status: u16, grpc: i32| {
+            serde_json::from_value::<ProblemJson>(serde_json::json!({
+                "title": "Broken",
+                "status": status,
+                "code": "INTERNAL",
+                "grpc": {"name": "INTERNAL", "value": grpc}
+            }))
+        };
+        assert!(parse(500, 13).is_ok());
+        assert!(parse(99, 13).is_err());
+        assert!(parse(600, 13).is_err());
+        assert!(parse(500, 17).is_err());
+        assert!(parse(500, -1).is_err());
+    }
+
+    #[test]
+    fn validate_checks_status_and_title() {
+        let mut problem = ProblemJson::from_ref(&AppError::conflict("exists"));
+        assert!(problem.validate().is_ok());
+        problem.status = 42;
+        let err = problem.validate().expect_err("status");
+        assert_eq!(err.kind, AppErrorKind::BadRequest);
+        problem.status = 409;
+        problem.title = Cow::Borrowed("");
+        assert!(problem.validate().is_err());
+    }
 }
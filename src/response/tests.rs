@@ -730,3 +730,108 @@ fn from_borrowed_app_error_redacts_message() {
     assert!(!resp.message.contains("secret123"));
     assert_eq!(err.message.as_deref(), Some("database password: secret123"));
 }
+
+#[test]
+fn client_conversions_prefer_public_message() {
+    let err = AppError::database_with_message("deadlock on orders (tx 8812)")
+        .with_public_message("Please try again.");
+    let borrowed: ErrorResponse = (&err).into();
+    assert_eq!(borrowed.message, "Please try again.");
+    let problem = ProblemJson::from_ref(&err);
+    assert_eq!(problem.detail.as_deref(), Some("Please try again."));
+    assert_eq!(err.message.as_deref(), Some("deadlock on orders (tx 8812)"));
+
+    let owned: ErrorResponse = err.into();
+    assert_eq!(owned.message, "Please try again.");
+    let problem = ProblemJson::from_app_error(
+        AppError::internal("pool exhausted")
+            .redactable()
+            .with_public_message("Service busy.")
+    );
+    assert_eq!(problem.detail.as_deref(), Some("Service busy."));
+}
+
+// --- Mapping: From<ErrorResponse> ---------------------------------------
+
+#[test]
+fn app_error_round_trips_through_error_response() {
+    let original = AppError::rate_limited("slow down")
+        .with_code(AppCode::new("QUOTA_EXCEEDED"))
+        .with_retry_after_secs(30)
+        .with_www_authenticate("Bearer");
+    let response = ErrorResponse::from(&original);
+    let restored = AppError::from(response);
+    assert_eq!(restored.kind, original.kind);
+    assert_eq!(restored.code, original.code);
+    assert_eq!(restored.message, original.message);
+    assert_eq!(restored.retry, original.retry);
+    assert_eq!(restored.www_authenticate.as_deref(), Some("Bearer"));
+    assert!(restored.source_ref().is_none());
+}
+
+#[test]
+fn error_response_with_unknown_status_becomes_internal() {
+    let response =
+        ErrorResponse::new(418, AppCode::new("TEAPOT"), "short and stout").expect("status");
+    let err = response.into_app_error();
+    assert_eq!(err.kind, AppErrorKind::Internal);
+    assert_eq!(err.code.as_str(), "TEAPOT");
+    assert_eq!(err.message.as_deref(), Some("short and stout"));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn error_response_details_are_restored() {
+    let payload = serde_json::json!({"field": "email"});
+    let source = AppError::validation("invalid").with_details_json(payload.clone());
+    let err = AppError::from(ErrorResponse::from(source));
+    assert_eq!(err.kind, AppErrorKind::Validation);
+    assert_eq!(err.details, Some(payload));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn from_status_and_body_recognizes_error_response() {
+    let response = ErrorResponse::from(
+        AppError::conflict("version mismatch")
+            .with_code(AppCode::new("STALE_WRITE"))
+            .with_details_json(serde_json::json!({"expected": 3}))
+    );
+    let body = serde_json::to_value(&response).expect("body");
+    let err = AppError::from_status_and_body(409, &body);
+    assert_eq!(err.kind, AppErrorKind::Conflict);
+    assert_eq!(err.code.as_str(), "STALE_WRITE");
+    assert_eq!(err.message.as_deref(), Some("version mismatch"));
+    assert_eq!(err.details, Some(serde_json::json!({"expected": 3})));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn from_status_and_body_recognizes_problem_json() {
+    let problem = ProblemJson::from_app_error(AppError::not_found("no such order"));
+    let body = serde_json::to_value(&problem).expect("body");
+    let err = AppError::from_status_and_body(404, &body);
+    assert_eq!(err.kind, AppErrorKind::NotFound);
+    assert_eq!(err.code, AppCode::NotFound);
+    assert_eq!(err.message.as_deref(), Some("no such order"));
+    assert_eq!(err.details, None);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn from_status_and_body_keeps_opaque_body_in_details() {
+    let body = serde_json::json!({"error": "upstream exploded", "code": 17});
+    let err = AppError::from_status_and_body(418, &body);
+    assert_eq!(err.kind, AppErrorKind::Internal);
+    assert_eq!(err.code, AppCode::Internal);
+    assert_eq!(err.message, None);
+    assert_eq!(err.details, Some(body));
+}
+
+#[cfg(not(feature = "serde_json"))]
+#[test]
+fn error_response_text_details_are_restored() {
+    let response = ErrorResponse::from(AppError::validation("invalid").with_details_text("email"));
+    let err = AppError::from(response);
+    assert_eq!(err.details.as_deref(), Some("email"));
+}
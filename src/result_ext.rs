@@ -5,7 +5,10 @@
 use alloc::{borrow::Cow, boxed::Box};
 use core::error::Error as CoreError;
 
-use crate::app_error::{Context, Error};
+use crate::{
+    AppErrorKind, AppResult,
+    app_error::{Context, Error}
+};
 
 /// Extension trait for enriching `Result` errors with [`Context`].
 ///
@@ -68,6 +71,50 @@ pub trait ResultExt<T, E> {
     fn context(self, msg: impl Into<Cow<'static, str>>) -> Result<T, Error>
     where
         E: CoreError + Send + Sync + 'static;
+
+    /// Classify a foreign error as `kind`, keeping it as the source.
+    ///
+    /// The resulting [`Error`] has no message, so clients see the kind label
+    /// while logs still reach the original error through the source chain.
+    /// `Ok` values pass through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Error as IoError, ErrorKind};
+    ///
+    /// use masterror::{AppErrorKind, ResultExt};
+    ///
+    /// let res: Result<(), IoError> = Err(IoError::from(ErrorKind::ConnectionReset));
+    /// let err = res.map_foreign(AppErrorKind::Network).unwrap_err();
+    /// assert_eq!(err.kind, AppErrorKind::Network);
+    /// assert!(err.is::<IoError>());
+    /// ```
+    #[allow(clippy::result_large_err)]
+    fn map_foreign(self, kind: AppErrorKind) -> AppResult<T>
+    where
+        E: CoreError + Send + Sync + 'static;
+
+    /// Convert the error into [`Error`], [`log`](Error::log) it and pass the
+    /// `Result` through.
+    ///
+    /// Handy on fire-and-forget paths where the error only needs to be
+    /// recorded. `Ok` values are returned untouched and emit nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::{AppError, AppErrorKind, AppResult, ResultExt};
+    ///
+    /// let res: AppResult<()> = Err(AppError::timeout("cache warmup"));
+    /// let err = res.log_err().unwrap_err();
+    /// assert_eq!(err.kind, AppErrorKind::Timeout);
+    /// ```
+    #[cfg(feature = "tracing")]
+    #[allow(clippy::result_large_err)]
+    fn log_err(self) -> Result<T, Error>
+    where
+        E: Into<Error>;
 }
 
 impl<T, E> ResultExt<T, E> for Result<T, E> {
@@ -114,6 +161,25 @@ impl<T, E> ResultExt<T, E> for Result<T, E> {
             }
         })
     }
+
+    fn map_foreign(self, kind: AppErrorKind) -> AppResult<T>
+    where
+        E: CoreError + Send + Sync + 'static
+    {
+        self.map_err(|err| Error::bare(kind).with_source(err))
+    }
+
+    #[cfg(feature = "tracing")]
+    fn log_err(self) -> Result<T, Error>
+    where
+        E: Into<Error>
+    {
+        self.map_err(|err| {
+            let err = err.into();
+            err.log();
+            err
+        })
+    }
 }
 
 #[cfg(test)]
@@ -292,6 +358,26 @@ mod tests {
         assert!(err.source_ref().unwrap().is::<DummyError>());
     }
 
+    #[test]
+    fn map_foreign_classifies_io_error_and_keeps_source() {
+        use std::io::{Error as IoError, ErrorKind};
+
+        let result: Result<(), IoError> = Err(IoError::new(ErrorKind::ConnectionReset, "reset"));
+        let err = result.map_foreign(AppErrorKind::Network).expect_err("err");
+        assert_eq!(err.kind, AppErrorKind::Network);
+        assert_eq!(err.code, AppCode::Network);
+        assert!(err.message.is_none());
+        let io = err.downcast_ref::<IoError>().expect("io source");
+        assert_eq!(io.kind(), ErrorKind::ConnectionReset);
+        assert_eq!(io.to_string(), "reset");
+    }
+
+    #[test]
+    fn map_foreign_passes_ok_through() {
+        let result: Result<u8, DummyError> = Ok(7);
+        assert_eq!(result.map_foreign(AppErrorKind::Network).expect("ok"), 7);
+    }
+
     #[test]
     fn context_preserves_app_error_classification() {
         let base = Error::bad_request("missing flag")
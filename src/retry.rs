@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Async retry loop driven by [`AppErrorKind::is_retryable`].
+//!
+//! Enabled with the `tokio` feature flag.
+//!
+//! ## Behavior
+//! - The operation runs until it succeeds, returns a non-retryable error or
+//!   `max_attempts` attempts have been made.
+//! - Between attempts the loop sleeps for the error's [`RetryAdvice`] when
+//!   present, otherwise for an exponential backoff starting at 100 ms and
+//!   capped at 10 s.
+//! - The returned error carries an `attempts` metadata field with the number of
+//!   attempts made.
+//!
+//! [`AppErrorKind::is_retryable`]: crate::AppErrorKind::is_retryable
+//! [`RetryAdvice`]: crate::RetryAdvice
+
+use core::{future::Future, time::Duration};
+
+use tokio::time::sleep;
+
+use crate::{AppResult, field};
+
+/// Delay before the second attempt when the error carries no retry advice.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Upper bound for the exponential backoff.
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Run `op` until it succeeds, retrying transient failures.
+///
+/// `max_attempts` counts the first call as well; `0` is treated as `1`.
+///
+/// # Errors
+///
+/// Returns the last [`AppError`](crate::AppError) produced by `op`, annotated
+/// with an `attempts` field, when the error is not retryable or the attempt
+/// budget is exhausted.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{AppError, FieldValue, retry};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let err = retry(5, || async {
+///     Err::<(), _>(AppError::validation("bad input"))
+/// })
+/// .await
+/// .unwrap_err();
+/// assert_eq!(err.metadata().get("attempts"), Some(&FieldValue::U64(1)));
+/// # }
+/// ```
+pub async fn retry<T, F, Fut>(max_attempts: u32, mut op: F) -> AppResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = AppResult<T>>
+{
+    let max_attempts = max_attempts.max(1);
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        let error = match op().await {
+            Ok(value) => return Ok(value),
+            Err(error) => error
+        };
+        if attempt >= max_attempts || !error.kind.is_retryable() {
+            return Err(error.with_field(field::u64("attempts", u64::from(attempt))));
+        }
+        let delay = match error.retry {
+            Some(advice) => Duration::from_secs(advice.after_seconds),
+            None => backoff
+        };
+        sleep(delay).await;
+        backoff = backoff.saturating_mul(2).min(MAX_BACKOFF);
+        attempt += 1;
+    }
+}
@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Triage severity of application errors.
+//!
+//! Every [`AppErrorKind`] has a default [`Severity`]: client mistakes are
+//! warnings, infrastructure failures are errors and failures that usually
+//! need an operator are critical. Individual errors may override it with
+//! [`AppError::with_severity`](crate::AppError::with_severity).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use masterror::{AppError, Severity};
+//!
+//! let mut errors = [
+//!     AppError::not_found("missing"),
+//!     AppError::internal("boom"),
+//!     AppError::timeout("slow")
+//! ];
+//! errors.sort_by_key(|err| core::cmp::Reverse(err.severity()));
+//! assert_eq!(errors[0].severity(), Severity::Critical);
+//! assert_eq!(errors[2].severity(), Severity::Warning);
+//! ```
+
+use core::fmt::{self, Display, Formatter};
+
+use crate::AppErrorKind;
+
+/// Severity used to triage errors, ordered from least to most severe.
+///
+/// With the `tracing` feature the severity selects the level of the event
+/// emitted by [`AppError::log`](crate::AppError::log): `Debug` and `Info` map
+/// to the same-named levels, `Warning` to `WARN`, and `Error` and `Critical`
+/// to `ERROR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Diagnostic noise, useful only while debugging.
+    Debug,
+    /// Expected condition worth recording.
+    Info,
+    /// Client-side failure; the service behaved correctly.
+    Warning,
+    /// Server-side failure affecting the request.
+    Error,
+    /// Failure that likely needs operator attention.
+    Critical
+}
+
+impl Severity {
+    /// Lowercase label used in logs and telemetry.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Critical => "critical"
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AppErrorKind {
+    /// Default [`Severity`] of errors of this kind.
+    ///
+    /// `Internal`, `Database`, `Config` and `DependencyUnavailable` are
+    /// critical, the remaining server-side kinds are errors and client-side
+    /// kinds are warnings.
+    ///
+    /// ```rust
+    /// use masterror::{AppErrorKind, Severity};
+    ///
+    /// assert_eq!(AppErrorKind::Internal.severity(), Severity::Critical);
+    /// assert_eq!(AppErrorKind::Timeout.severity(), Severity::Error);
+    /// assert_eq!(AppErrorKind::Validation.severity(), Severity::Warning);
+    /// ```
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        match self {
+            Self::Internal | Self::Database | Self::Config | Self::DependencyUnavailable => {
+                Severity::Critical
+            }
+            Self::NotImplemented
+            | Self::Service
+            | Self::Turnkey
+            | Self::Timeout
+            | Self::Network
+            | Self::Serialization
+            | Self::Deserialization
+            | Self::ExternalApi
+            | Self::Queue
+            | Self::Cache => Severity::Error,
+            Self::NotFound
+            | Self::Validation
+            | Self::Conflict
+            | Self::Unauthorized
+            | Self::Forbidden
+            | Self::BadRequest
+            | Self::MethodNotAllowed
+            | Self::PayloadTooLarge
+            | Self::UnsupportedMediaType
+            | Self::Locked
+            | Self::TelegramAuth
+            | Self::InvalidJwt
+            | Self::RateLimited => Severity::Warning
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Severity;
+    use crate::AppErrorKind;
+
+    #[test]
+    fn severities_are_ordered() {
+        assert!(Severity::Debug < Severity::Info);
+        assert!(Severity::Info < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+        assert!(Severity::Error < Severity::Critical);
+    }
+
+    #[test]
+    fn default_severity_follows_kind() {
+        assert_eq!(AppErrorKind::Internal.severity(), Severity::Critical);
+        assert_eq!(
+            AppErrorKind::DependencyUnavailable.severity(),
+            Severity::Critical
+        );
+        assert_eq!(AppErrorKind::Validation.severity(), Severity::Warning);
+        assert_eq!(AppErrorKind::NotFound.severity(), Severity::Warning);
+        assert_eq!(AppErrorKind::Network.severity(), Severity::Error);
+    }
+
+    #[test]
+    fn client_kinds_are_warnings_and_server_kinds_are_not() {
+        for &kind in AppErrorKind::ALL {
+            let client = kind.http_status() < 500;
+            assert_eq!(
+                kind.severity() == Severity::Warning,
+                client,
+                "{kind:?} severity does not match its status class"
+            );
+        }
+    }
+
+    #[test]
+    fn display_uses_lowercase_label() {
+        assert_eq!(Severity::Critical.to_string(), "critical");
+        assert_eq!(Severity::Warning.to_string(), "warning");
+    }
+}
@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Span field capture: [`SpanFieldsLayer`] keeps the values recorded on spans
+//! so [`AppError::with_span`](crate::AppError::with_span) can copy them into
+//! error metadata.
+//!
+//! Enabled with the `span-fields` feature flag, which pulls in
+//! `tracing-subscriber`; plain `tracing` keeps only the span identity.
+//!
+//! ## Behavior
+//! - `tracing` hands recorded values to the subscriber only, so they cannot be
+//!   read back from a [`Span`] handle. The layer stores them in the span's
+//!   registry extensions as they are recorded.
+//! - Values are keyed `span.field.<name>`, so a span field called `target` or
+//!   `id` never overwrites the `span.target` and `span.id` identity fields.
+//! - Integers, floats, booleans and strings keep their type; any other value
+//!   (including errors) is stored using its `Debug` output.
+//! - The layer must run on a [`tracing_subscriber::Registry`]. Spans of other
+//!   subscribers contribute only their name, target and id.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use masterror::{AppError, FieldValue, span_fields::SpanFieldsLayer};
+//! use tracing::info_span;
+//! use tracing_subscriber::layer::SubscriberExt;
+//!
+//! let subscriber = tracing_subscriber::registry().with(SpanFieldsLayer::new());
+//! tracing::subscriber::with_default(subscriber, || {
+//!     let span = info_span!("checkout", order = 42);
+//!     let err = AppError::service("gateway declined").with_span(&span);
+//!     assert_eq!(
+//!         err.metadata().get("span.field.order"),
+//!         Some(&FieldValue::I64(42))
+//!     );
+//! });
+//! ```
+
+use alloc::{format, vec::Vec};
+use core::fmt::Debug;
+
+use tracing::{
+    Span, Subscriber,
+    field::{Field as TracingField, Visit},
+    span::{Attributes, Id, Record}
+};
+use tracing_subscriber::{
+    Registry,
+    layer::{Context, Layer},
+    registry::{LookupSpan, SpanData}
+};
+
+use crate::{Field, app_error::intern_name, field};
+
+/// Layer recording span field values for
+/// [`AppError::with_span`](crate::AppError::with_span).
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::span_fields::SpanFieldsLayer;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::registry().with(SpanFieldsLayer::new());
+/// # drop(subscriber);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SpanFieldsLayer;
+
+impl SpanFieldsLayer {
+    /// Create the layer.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for SpanFieldsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = RecordedFields(Vec::new());
+        attrs.record(&mut fields);
+        span.extensions_mut().replace(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        match extensions.get_mut::<RecordedFields>() {
+            Some(fields) => values.record(fields),
+            None => {
+                let mut fields = RecordedFields(Vec::new());
+                values.record(&mut fields);
+                extensions.insert(fields);
+            }
+        }
+    }
+}
+
+/// Field values recorded on a span, keyed as `span.field.<name>`.
+struct RecordedFields(Vec<Field>);
+
+impl RecordedFields {
+    fn push(&mut self, field: Field) {
+        match self
+            .0
+            .iter_mut()
+            .find(|recorded| recorded.name() == field.name())
+        {
+            Some(recorded) => *recorded = field,
+            None => self.0.push(field)
+        }
+    }
+}
+
+fn span_key(field: &TracingField) -> &'static str {
+    intern_name(&format!("span.field.{}", field.name()))
+}
+
+impl Visit for RecordedFields {
+    fn record_f64(&mut self, field: &TracingField, value: f64) {
+        self.push(field::f64(span_key(field), value));
+    }
+
+    fn record_i64(&mut self, field: &TracingField, value: i64) {
+        self.push(field::i64(span_key(field), value));
+    }
+
+    fn record_u64(&mut self, field: &TracingField, value: u64) {
+        self.push(field::u64(span_key(field), value));
+    }
+
+    fn record_bool(&mut self, field: &TracingField, value: bool) {
+        self.push(field::bool(span_key(field), value));
+    }
+
+    fn record_str(&mut self, field: &TracingField, value: &str) {
+        self.push(field::str(span_key(field), value.to_owned()));
+    }
+
+    fn record_debug(&mut self, field: &TracingField, value: &dyn Debug) {
+        self.push(field::str(span_key(field), format!("{value:?}")));
+    }
+}
+
+/// Field values recorded on `span` by [`SpanFieldsLayer`].
+///
+/// Empty when the span is disabled, the active subscriber is not a
+/// [`Registry`] or the layer is not installed.
+pub(crate) fn recorded_fields(span: &Span) -> Vec<Field> {
+    span.with_subscriber(|(id, dispatch)| {
+        let registry = dispatch.downcast_ref::<Registry>()?;
+        let data = registry.span_data(id)?;
+        let fields = data.extensions().get::<RecordedFields>()?.0.clone();
+        Some(fields)
+    })
+    .flatten()
+    .unwrap_or_default()
+}
@@ -0,0 +1,297 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Tower integration: [`ErrorLayer`] renders service failures as RFC7807
+//! responses.
+//!
+//! Enabled with the `tower` feature flag.
+//!
+//! ## Behavior
+//! - Wraps any [`Service`] whose error converts into [`AppError`] and turns
+//!   failures into `application/problem+json` responses built from
+//!   [`ProblemJson`]. The wrapped service is infallible, which is what axum
+//!   expects from `route_service` and `fallback_service`.
+//! - Adds `Retry-After`, `WWW-Authenticate` and `Allow` headers when the error
+//!   carries retry advice, an authentication challenge or the allowed methods.
+//! - Flushes telemetry through [`AppError::log`] before responding unless
+//!   logging is disabled.
+//! - Under [`DisplayMode::Prod`], server errors (5xx) are redacted so their
+//!   messages and metadata never reach clients. `Local` and `Staging` keep the
+//!   error's own redaction policy.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use axum::Router;
+//! use masterror::{DisplayMode, tower::ErrorLayer};
+//! use tower_layer::Layer;
+//!
+//! let layer = ErrorLayer::new().with_display_mode(DisplayMode::Prod);
+//! let app: Router = Router::new().route_service("/upstream", layer.layer(upstream));
+//! ```
+
+use core::{
+    convert::Infallible,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, ready}
+};
+
+use http::{
+    HeaderValue, Response,
+    header::{ALLOW, CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE}
+};
+use itoa::Buffer as IntegerBuffer;
+use pin_project_lite::pin_project;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{AppError, DisplayMode, MessageEditPolicy, ProblemJson};
+
+/// [`Layer`] that converts errors of the inner service into problem+json
+/// responses.
+///
+/// Defaults to the detected [`DisplayMode::current`] with logging enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{DisplayMode, tower::ErrorLayer};
+///
+/// let layer = ErrorLayer::new()
+///     .with_display_mode(DisplayMode::Prod)
+///     .with_logging(false);
+/// assert_eq!(layer.display_mode(), DisplayMode::Prod);
+/// assert!(!layer.logs_errors());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorLayer {
+    display_mode: DisplayMode,
+    log:          bool
+}
+
+impl ErrorLayer {
+    /// Create a layer using the detected display mode with logging enabled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            display_mode: DisplayMode::current(),
+            log:          true
+        }
+    }
+
+    /// Override the display mode that decides whether server errors are
+    /// redacted.
+    #[must_use]
+    pub const fn with_display_mode(mut self, mode: DisplayMode) -> Self {
+        self.display_mode = mode;
+        self
+    }
+
+    /// Enable or disable [`AppError::log`] before each error response.
+    #[must_use]
+    pub const fn with_logging(mut self, enabled: bool) -> Self {
+        self.log = enabled;
+        self
+    }
+
+    /// Display mode applied to error responses.
+    #[must_use]
+    pub const fn display_mode(&self) -> DisplayMode {
+        self.display_mode
+    }
+
+    /// Whether errors are logged before being rendered.
+    #[must_use]
+    pub const fn logs_errors(&self) -> bool {
+        self.log
+    }
+
+    fn respond<B>(&self, mut error: AppError) -> Response<B>
+    where
+        B: From<String>
+    {
+        if self.log {
+            error.log();
+        }
+        if matches!(self.display_mode, DisplayMode::Prod) && error.is_server_error() {
+            error.edit_policy = MessageEditPolicy::Redact;
+        }
+        problem_response(ProblemJson::from_ref(&error))
+    }
+}
+
+impl Default for ErrorLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for ErrorLayer {
+    type Service = ErrorService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ErrorService {
+            inner,
+            layer: *self,
+            ready_error: None
+        }
+    }
+}
+
+/// [`Service`] produced by [`ErrorLayer`].
+///
+/// Errors from both `poll_ready` and the response future of the inner
+/// service are rendered as problem+json responses.
+#[derive(Debug)]
+pub struct ErrorService<S> {
+    inner:       S,
+    layer:       ErrorLayer,
+    ready_error: Option<AppError>
+}
+
+impl<S> ErrorService<S> {
+    /// Borrow the wrapped service.
+    #[must_use]
+    pub const fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Consume the wrapper and return the inner service.
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Clone> Clone for ErrorService<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner:       self.inner.clone(),
+            layer:       self.layer,
+            ready_error: None
+        }
+    }
+}
+
+impl<S, Req, B> Service<Req> for ErrorService<S>
+where
+    S: Service<Req, Response = Response<B>>,
+    S::Error: Into<AppError>,
+    B: From<String>
+{
+    type Response = Response<B>;
+    type Error = Infallible;
+    type Future = ErrorFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.ready_error.is_none()
+            && let Err(error) = ready!(self.inner.poll_ready(cx))
+        {
+            self.ready_error = Some(error.into());
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Req) -> Self::Future {
+        let state = match self.ready_error.take() {
+            Some(error) => State::Failed {
+                error: Some(error)
+            },
+            None => State::Pending {
+                future: self.inner.call(request)
+            }
+        };
+        ErrorFuture {
+            state,
+            layer: self.layer
+        }
+    }
+}
+
+pin_project! {
+    /// Response future returned by [`ErrorService`].
+    #[derive(Debug)]
+    pub struct ErrorFuture<F> {
+        #[pin]
+        state: State<F>,
+        layer: ErrorLayer
+    }
+}
+
+pin_project! {
+    #[project = StateProj]
+    #[derive(Debug)]
+    enum State<F> {
+        Pending {
+            #[pin]
+            future: F
+        },
+        Failed {
+            error: Option<AppError>
+        }
+    }
+}
+
+impl<F, B, E> Future for ErrorFuture<F>
+where
+    F: Future<Output = Result<Response<B>, E>>,
+    E: Into<AppError>,
+    B: From<String>
+{
+    type Output = Result<Response<B>, Infallible>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let error = match this.state.project() {
+            StateProj::Pending {
+                future
+            } => match ready!(future.poll(cx)) {
+                Ok(response) => return Poll::Ready(Ok(response)),
+                Err(error) => error.into()
+            },
+            StateProj::Failed {
+                error
+            } => error.take().expect("ErrorFuture polled after completion")
+        };
+        Poll::Ready(Ok(this.layer.respond(error)))
+    }
+}
+
+fn problem_response<B>(mut problem: ProblemJson) -> Response<B>
+where
+    B: From<String>
+{
+    let status = problem.status_code();
+    let retry_after = problem.retry_after;
+    let www_authenticate = problem.www_authenticate.take();
+    let allow = problem.allow.take();
+    // ProblemJson only holds strings, numbers and JSON values, so
+    // serialization cannot fail.
+    let body = serde_json::to_string(&problem).unwrap_or_default();
+    let mut response = Response::new(B::from(body));
+    *response.status_mut() = status;
+    let headers = response.headers_mut();
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("application/problem+json")
+    );
+    if let Some(retry) = retry_after {
+        let mut buffer = IntegerBuffer::new();
+        if let Ok(value) = HeaderValue::from_str(buffer.format(retry)) {
+            headers.insert(RETRY_AFTER, value);
+        }
+    }
+    if let Some(challenge) = www_authenticate
+        && let Ok(value) = HeaderValue::from_str(&challenge)
+    {
+        headers.insert(WWW_AUTHENTICATE, value);
+    }
+    if let Some(methods) = allow
+        && let Ok(value) = HeaderValue::from_str(&methods)
+    {
+        headers.insert(ALLOW, value);
+    }
+    response
+}
@@ -36,7 +36,7 @@ mod classifier;
 mod conversions;
 mod domain;
 
-pub use classifier::classify_turnkey_error;
+pub use classifier::{Confidence, classify_turnkey_error, classify_turnkey_error_detailed};
 pub use domain::{TurnkeyError, TurnkeyErrorKind, map_turnkey_kind};
 
 #[cfg(test)]
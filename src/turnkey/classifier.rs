@@ -6,6 +6,100 @@ use super::domain::TurnkeyErrorKind;
 
 const STACK_NEEDLE_INLINE_CAP: usize = 64;
 
+/// How specific the signal behind a Turnkey classification was.
+///
+/// Variants are ordered from weakest to strongest, so callers can compare
+/// against a threshold (`confidence >= Confidence::Medium`).
+///
+/// # Examples
+/// ```rust
+/// use masterror::turnkey::Confidence;
+/// assert!(Confidence::High > Confidence::Low);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Confidence {
+    /// Only a fuzzy substring matched, or nothing matched and the kind fell
+    /// back to [`TurnkeyErrorKind::Service`].
+    Low,
+    /// A specific keyword matched on word boundaries.
+    Medium,
+    /// An explicit status code or canonical status phrase matched on word
+    /// boundaries.
+    High
+}
+
+/// Matching signals for a single [`TurnkeyErrorKind`].
+///
+/// `explicit` and `keywords` match on word boundaries, `fuzzy` matches
+/// anywhere. A kind matches when any of the three lists hits; the strongest
+/// hit decides the confidence.
+struct Signals {
+    kind:     TurnkeyErrorKind,
+    explicit: &'static [&'static str],
+    keywords: &'static [&'static str],
+    fuzzy:    &'static [&'static str]
+}
+
+/// Signals in priority order; the first matching kind wins.
+const SIGNALS: &[Signals] = &[
+    Signals {
+        kind:     TurnkeyErrorKind::UniqueLabel,
+        explicit: &["label must be unique"],
+        keywords: &["already exists", "duplicate", "unique"],
+        fuzzy:    &["already exists", "duplicate", "unique"]
+    },
+    Signals {
+        kind:     TurnkeyErrorKind::RateLimited,
+        explicit: &["429", "too many requests"],
+        keywords: &[
+            "throttle",
+            "throttled",
+            "rate limit",
+            "rate limited",
+            "rate limiting",
+            "rate-limit",
+            "rate-limited",
+            "rate-limiting",
+            "ratelimit",
+            "ratelimited",
+            "ratelimiting"
+        ],
+        fuzzy:    &["429", "throttle", "too many requests"]
+    },
+    Signals {
+        kind:     TurnkeyErrorKind::Timeout,
+        explicit: &["deadline exceeded"],
+        keywords: &["timeout", "timed out"],
+        fuzzy:    &["timeout", "timed out", "deadline exceeded"]
+    },
+    Signals {
+        kind:     TurnkeyErrorKind::Auth,
+        explicit: &["401", "403"],
+        keywords: &["unauthorized", "unauthorised", "forbidden"],
+        fuzzy:    &["401", "403", "unauthor", "forbidden"]
+    },
+    Signals {
+        kind:     TurnkeyErrorKind::Network,
+        explicit: &["connection refused", "connection reset"],
+        keywords: &["network", "connection", "dns", "tls", "socket"],
+        fuzzy:    &["network", "connection", "connect", "dns", "tls", "socket"]
+    }
+];
+
+impl Signals {
+    fn confidence(&self, msg: &str) -> Option<Confidence> {
+        if contains_any_nocase_with_boundaries(msg, self.explicit) {
+            Some(Confidence::High)
+        } else if contains_any_nocase_with_boundaries(msg, self.keywords) {
+            Some(Confidence::Medium)
+        } else if contains_any_nocase(msg, self.fuzzy) {
+            Some(Confidence::Low)
+        } else {
+            None
+        }
+    }
+}
+
 /// Heuristic classifier for raw SDK/provider messages (ASCII case-insensitive).
 ///
 /// This helper keeps allocations to a minimum; it performs case-insensitive
@@ -15,7 +109,8 @@ const STACK_NEEDLE_INLINE_CAP: usize = 64;
 ///
 /// The classifier is intentionally minimal; providers can and will change
 /// messages. Prefer returning structured errors from adapters whenever
-/// possible.
+/// possible. Use [`classify_turnkey_error_detailed`] to learn how specific the
+/// matched signal was.
 ///
 /// # Examples
 /// ```rust
@@ -35,42 +130,39 @@ const STACK_NEEDLE_INLINE_CAP: usize = 64;
 /// ```
 #[must_use]
 pub fn classify_turnkey_error(msg: &str) -> TurnkeyErrorKind {
-    const UNIQUE_PATTERNS: &[&str] = &[
-        "label must be unique",
-        "already exists",
-        "duplicate",
-        "unique"
-    ];
-    const RL_PATTERNS: &[&str] = &["429", "throttle", "throttled", "too many requests"];
-    const RL_BOUNDARY_PATTERNS: &[&str] = &[
-        "rate limit",
-        "rate limited",
-        "rate limiting",
-        "rate-limit",
-        "rate-limited",
-        "rate-limiting",
-        "ratelimit",
-        "ratelimited",
-        "ratelimiting"
-    ];
-    const TO_PATTERNS: &[&str] = &["timeout", "timed out", "deadline exceeded"];
-    const AUTH_PATTERNS: &[&str] = &["401", "403", "unauthor", "forbidden"];
-    const NET_PATTERNS: &[&str] = &["network", "connection", "connect", "dns", "tls", "socket"];
-    if contains_any_nocase(msg, UNIQUE_PATTERNS) {
-        TurnkeyErrorKind::UniqueLabel
-    } else if contains_any_nocase(msg, RL_PATTERNS)
-        || contains_any_nocase_with_boundaries(msg, RL_BOUNDARY_PATTERNS)
-    {
-        TurnkeyErrorKind::RateLimited
-    } else if contains_any_nocase(msg, TO_PATTERNS) {
-        TurnkeyErrorKind::Timeout
-    } else if contains_any_nocase(msg, AUTH_PATTERNS) {
-        TurnkeyErrorKind::Auth
-    } else if contains_any_nocase(msg, NET_PATTERNS) {
-        TurnkeyErrorKind::Network
-    } else {
-        TurnkeyErrorKind::Service
-    }
+    classify_turnkey_error_detailed(msg).0
+}
+
+/// Classify a raw message and report the [`Confidence`] of the match.
+///
+/// The kind always equals [`classify_turnkey_error`]. Confidence is `High`
+/// for explicit status codes or canonical status phrases, `Medium` for
+/// specific keywords on word boundaries and `Low` for fuzzy substrings or the
+/// [`TurnkeyErrorKind::Service`] fallback. Callers that need certainty can
+/// treat low-confidence results as `Service`.
+///
+/// # Examples
+/// ```rust
+/// use masterror::turnkey::{Confidence, TurnkeyErrorKind, classify_turnkey_error_detailed};
+/// assert_eq!(
+///     classify_turnkey_error_detailed("429 Too Many Requests"),
+///     (TurnkeyErrorKind::RateLimited, Confidence::High)
+/// );
+/// assert_eq!(
+///     classify_turnkey_error_detailed("reconnecting"),
+///     (TurnkeyErrorKind::Network, Confidence::Low)
+/// );
+/// ```
+#[must_use]
+pub fn classify_turnkey_error_detailed(msg: &str) -> (TurnkeyErrorKind, Confidence) {
+    SIGNALS
+        .iter()
+        .find_map(|signals| {
+            signals
+                .confidence(msg)
+                .map(|confidence| (signals.kind, confidence))
+        })
+        .unwrap_or((TurnkeyErrorKind::Service, Confidence::Low))
 }
 
 /// Returns true if `haystack` contains `needle` ignoring ASCII case.
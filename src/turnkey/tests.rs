@@ -2,7 +2,10 @@
 //
 // SPDX-License-Identifier: MIT
 
-use super::{TurnkeyError, TurnkeyErrorKind, classify_turnkey_error, map_turnkey_kind};
+use super::{
+    Confidence, TurnkeyError, TurnkeyErrorKind, classify_turnkey_error,
+    classify_turnkey_error_detailed, map_turnkey_kind
+};
 use crate::{AppError, AppErrorKind};
 
 #[test]
@@ -116,6 +119,56 @@ fn classifier_service_fallback() {
     }
 }
 
+#[test]
+fn detailed_classifier_reports_high_confidence_for_status_codes() {
+    for (s, kind) in [
+        ("429", TurnkeyErrorKind::RateLimited),
+        ("upstream returned 429", TurnkeyErrorKind::RateLimited),
+        ("Too Many Requests", TurnkeyErrorKind::RateLimited),
+        ("403 Forbidden", TurnkeyErrorKind::Auth),
+        ("deadline exceeded", TurnkeyErrorKind::Timeout),
+        ("Label must be UNIQUE", TurnkeyErrorKind::UniqueLabel)
+    ] {
+        assert_eq!(
+            classify_turnkey_error_detailed(s),
+            (kind, Confidence::High),
+            "failed on: {s}"
+        );
+    }
+}
+
+#[test]
+fn detailed_classifier_reports_medium_confidence_for_keywords() {
+    for (s, kind) in [
+        ("rate limit exceeded", TurnkeyErrorKind::RateLimited),
+        ("Timeout while waiting", TurnkeyErrorKind::Timeout),
+        ("unauthorized key", TurnkeyErrorKind::Auth),
+        ("DNS failure", TurnkeyErrorKind::Network)
+    ] {
+        assert_eq!(
+            classify_turnkey_error_detailed(s),
+            (kind, Confidence::Medium),
+            "failed on: {s}"
+        );
+    }
+}
+
+#[test]
+fn detailed_classifier_reports_low_confidence_for_vague_messages() {
+    for (s, kind) in [
+        ("could not reconnect", TurnkeyErrorKind::Network),
+        ("order id 14290 rejected", TurnkeyErrorKind::RateLimited),
+        ("uniqueness check", TurnkeyErrorKind::UniqueLabel),
+        ("something went wrong", TurnkeyErrorKind::Service)
+    ] {
+        assert_eq!(
+            classify_turnkey_error_detailed(s),
+            (kind, Confidence::Low),
+            "failed on: {s}"
+        );
+    }
+}
+
 #[test]
 fn from_turnkey_error_into_app_error() {
     let e = TurnkeyError::new(TurnkeyErrorKind::RateLimited, "try later");
@@ -1,5 +1,9 @@
 #![allow(unused_variables, unused_assignments)]
 #![deny(non_shorthand_field_patterns)]
+#![cfg_attr(
+    masterror_requires_error_generic_feature,
+    feature(error_generic_member_access)
+)]
 
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 //
@@ -7,6 +11,8 @@
 
 use core::ptr::null;
 #[cfg(masterror_has_error_generic_member_access)]
+use std::error::{request_ref, request_value};
+#[cfg(masterror_has_error_generic_member_access)]
 use std::ptr;
 use std::{error::Error as StdError, fmt};
 
@@ -302,12 +308,50 @@ struct FormatterDebugShowcase {
     tuple: (&'static str, u8)
 }
 
+#[derive(Debug)]
+#[allow(dead_code)]
+struct RetryPlan {
+    attempts: u8,
+    hosts:    Vec<&'static str>
+}
+
+#[derive(Debug, Error)]
+#[error("retry plan rejected:\n{plan:#?}")]
+struct PrettyPlanError {
+    plan: RetryPlan
+}
+
 #[derive(Debug, Error)]
 #[error("{formatted}", formatted = self.message.to_uppercase())]
 struct FormatArgExpressionError {
     message: &'static str
 }
 
+#[derive(Debug, Error)]
+#[error("{code}: retry {code} times ({code:>3})", code = self.next_code())]
+struct RepeatedFormatArgError {
+    calls: core::cell::Cell<u32>
+}
+
+impl RepeatedFormatArgError {
+    fn next_code(&self) -> u32 {
+        let calls = self.calls.get() + 1;
+        self.calls.set(calls);
+        calls * 3
+    }
+}
+
+fn bump(calls: &core::cell::Cell<u32>) -> u32 {
+    calls.set(calls.get() + 1);
+    calls.get()
+}
+
+#[derive(Debug, Error)]
+enum RepeatedFormatArgEnum {
+    #[error("attempt {n} of {n}: {n:?}", n = bump(calls))]
+    Attempt { calls: core::cell::Cell<u32> }
+}
+
 #[derive(Debug, Error)]
 #[error("{}, {label}, {}", label = self.label, self.first, self.second)]
 struct MixedImplicitArgsError {
@@ -322,6 +366,47 @@ enum FormatArgEnum {
     Upper { detail: String }
 }
 
+fn fmt_money(cents: &u64) -> String {
+    format!("${}.{:02}", cents / 100, cents % 100)
+}
+
+mod money {
+    pub fn with_currency(cents: &u64, currency: &str) -> String {
+        format!("{} {currency}", super::fmt_money(cents))
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("charge of {} failed", fmt_money(&self.amount))]
+struct FreeFunctionArgError {
+    amount: u64
+}
+
+#[derive(Debug, Error)]
+#[error(
+    "refund {total} ({})",
+    crate::money::with_currency(&self.amount, self.currency),
+    total = Self::label(self.amount)
+)]
+struct PathFunctionArgError {
+    amount:   u64,
+    currency: &'static str
+}
+
+impl PathFunctionArgError {
+    fn label(amount: u64) -> &'static str {
+        if amount >= 10_000 { "large" } else { "small" }
+    }
+}
+
+#[derive(Debug, Error)]
+enum FunctionArgEnum {
+    #[error("payout {}", fmt_money(amount))]
+    Payout { amount: u64 },
+    #[error("fee {fee}", fee = crate::money::with_currency(cents, "EUR"))]
+    Fee { cents: u64 }
+}
+
 #[derive(Debug, Error)]
 #[error("{1}::{0}", self.first, self.second)]
 struct ExplicitIndexArgsError {
@@ -346,6 +431,22 @@ struct FieldShortcutError {
 #[error("{}, {}", .0, .1)]
 struct TupleShortcutError(&'static str, &'static str);
 
+#[derive(Debug, Error)]
+#[error("status {}", 200)]
+struct LiteralIntArgError;
+
+#[derive(Debug, Error)]
+#[error("code {0}, upstream {1}", 404, self.0)]
+struct LiteralShadowsTupleFieldError(u16);
+
+#[derive(Debug, Error)]
+enum LiteralArgEnum {
+    #[error("{}: {:>5}|{}", "db", "pool", .0)]
+    Store(&'static str),
+    #[error("{kind} after {}ms", 250_u64, kind = "timeout")]
+    Slow
+}
+
 #[derive(Debug)]
 struct RangeLimits {
     lo: i32,
@@ -466,15 +567,55 @@ struct DisplayDynamicPrecisionError {
     precision: usize
 }
 
+#[derive(Debug, Error)]
+#[error("code={code:0pad$}")]
+struct FieldWidthError {
+    code: u32,
+    pad:  usize
+}
+
+#[derive(Debug, Error)]
+#[error("{value:>width$.digits$}")]
+struct FieldWidthPrecisionError {
+    value:  f64,
+    width:  usize,
+    digits: usize
+}
+
+#[derive(Debug, Error)]
+#[error("{0:01$}")]
+struct TupleFieldWidthError(u32, usize);
+
+#[derive(Debug, Error)]
+enum FieldWidthEnum {
+    #[error("named {code:0pad$}")]
+    Named { code: u32, pad: usize },
+    #[error("tuple {0:01$}")]
+    Tuple(u32, usize)
+}
+
+#[derive(Debug, Error)]
+#[error("lookup failed for user {user?} in {region?=unknown}")]
+struct OptionalFieldError {
+    user:   Option<String>,
+    region: Option<&'static str>
+}
+
+#[derive(Debug, Error)]
+enum OptionalFieldEnum {
+    #[error("retry {0?=-:>3} after {1?:#x}")]
+    Tuple(Option<u32>, Option<u16>),
+    #[error("{code?=none} ({code?=none})")]
+    Repeated { code: Option<u32> }
+}
+
 #[cfg(masterror_has_error_generic_member_access)]
 fn assert_backtrace_interfaces<E>(error: &E, expected: &std::backtrace::Backtrace)
 where
     E: StdError + ?Sized
 {
-    let reported = std::error::Error::backtrace(error).expect("backtrace");
-    assert!(ptr::eq(expected, reported));
     let provided = request_ref::<std::backtrace::Backtrace>(error).expect("provided backtrace");
-    assert!(ptr::eq(reported, provided));
+    assert!(ptr::eq(expected, provided));
 }
 
 #[cfg(not(masterror_has_error_generic_member_access))]
@@ -591,6 +732,33 @@ fn app_error_conversion_forwards_source_backtrace() {
     assert!(ptr::eq(&source.trace, provided));
 }
 
+#[cfg(all(masterror_has_error_generic_member_access, feature = "backtrace"))]
+#[test]
+fn app_error_provides_domain_backtrace_over_own_capture() {
+    let err = ConvertedBacktraceError {
+        trace: std::backtrace::Backtrace::force_capture()
+    };
+    let app =
+        masterror::AppError::from(err).with_backtrace(std::backtrace::Backtrace::force_capture());
+    let own = app.backtrace().expect("own backtrace");
+    let source = app
+        .downcast_ref::<ConvertedBacktraceError>()
+        .expect("attached source");
+    let provided = request_ref::<std::backtrace::Backtrace>(&app).expect("provided backtrace");
+    assert!(ptr::eq(&source.trace, provided));
+    assert!(!ptr::eq(own, provided));
+}
+
+#[cfg(all(masterror_has_error_generic_member_access, feature = "backtrace"))]
+#[test]
+fn app_error_falls_back_to_own_backtrace() {
+    let app = masterror::AppError::internal("no domain trace")
+        .with_backtrace(std::backtrace::Backtrace::force_capture());
+    let own = app.backtrace().expect("own backtrace");
+    let provided = request_ref::<std::backtrace::Backtrace>(&app).expect("provided backtrace");
+    assert!(ptr::eq(own, provided));
+}
+
 #[test]
 fn named_struct_display_and_source() {
     let err = NamedError {
@@ -634,6 +802,23 @@ fn named_format_arg_expression_is_used() {
     assert_eq!(err.to_string(), "VALUE");
 }
 
+#[test]
+fn repeated_named_format_arg_is_evaluated_once() {
+    let err = RepeatedFormatArgError {
+        calls: core::cell::Cell::new(0)
+    };
+    assert_eq!(err.to_string(), "3: retry 3 times (  3)");
+    assert_eq!(err.calls.get(), 1);
+    let err = RepeatedFormatArgEnum::Attempt {
+        calls: core::cell::Cell::new(0)
+    };
+    assert_eq!(err.to_string(), "attempt 1 of 1: 1");
+    let RepeatedFormatArgEnum::Attempt {
+        calls
+    } = &err;
+    assert_eq!(calls.get(), 1);
+}
+
 #[test]
 fn implicit_format_args_follow_positional_ordering() {
     let err = MixedImplicitArgsError {
@@ -644,6 +829,31 @@ fn implicit_format_args_follow_positional_ordering() {
     assert_eq!(err.to_string(), "one, tag, two");
 }
 
+#[test]
+fn free_function_format_args_render_struct_fields() {
+    let err = FreeFunctionArgError {
+        amount: 1999
+    };
+    assert_eq!(err.to_string(), "charge of $19.99 failed");
+    let err = PathFunctionArgError {
+        amount:   25_000,
+        currency: "USD"
+    };
+    assert_eq!(err.to_string(), "refund large ($250.00 USD)");
+}
+
+#[test]
+fn free_function_format_args_render_variant_bindings() {
+    let payout = FunctionArgEnum::Payout {
+        amount: 705
+    };
+    assert_eq!(payout.to_string(), "payout $7.05");
+    let fee = FunctionArgEnum::Fee {
+        cents: 50
+    };
+    assert_eq!(fee.to_string(), "fee $0.50 EUR");
+}
+
 #[test]
 fn explicit_format_arg_indices_resolve() {
     let err = ExplicitIndexArgsError {
@@ -676,6 +886,24 @@ fn tuple_shorthand_arguments_resolve_positions() {
     assert_eq!(err.to_string(), "first, second");
 }
 
+#[test]
+fn literal_int_arguments_render_verbatim() {
+    assert_eq!(LiteralIntArgError.to_string(), "status 200");
+    assert_eq!(
+        LiteralShadowsTupleFieldError(500).to_string(),
+        "code 404, upstream 500"
+    );
+}
+
+#[test]
+fn literal_string_arguments_render_verbatim() {
+    assert_eq!(
+        LiteralArgEnum::Store("closed").to_string(),
+        "db:  pool|closed"
+    );
+    assert_eq!(LiteralArgEnum::Slow.to_string(), "timeout after 250ms");
+}
+
 #[test]
 fn enum_variant_format_args_resolve_bindings() {
     let err = FormatArgEnum::Upper {
@@ -833,7 +1061,6 @@ fn optional_source_backtrace_absent_when_none() {
     assert!(StdError::source(&err).is_none());
     #[cfg(masterror_has_error_generic_member_access)]
     {
-        assert!(std::error::Error::backtrace(&err).is_none());
         assert!(std::error::request_ref::<std::backtrace::Backtrace>(&err).is_none());
     }
 }
@@ -857,10 +1084,31 @@ fn enum_backtrace_field_is_returned() {
     let unit = EnumWithBacktrace::Unit;
     #[cfg(masterror_has_error_generic_member_access)]
     {
-        assert!(std::error::Error::backtrace(&unit).is_none());
+        assert!(request_ref::<std::backtrace::Backtrace>(&unit).is_none());
     }
 }
 
+#[test]
+fn alternate_debug_renders_multiline_struct() {
+    let err = PrettyPlanError {
+        plan: RetryPlan {
+            attempts: 3,
+            hosts:    vec!["a", "b"]
+        }
+    };
+    assert_eq!(
+        err.to_string(),
+        "retry plan rejected:\n\
+         RetryPlan {\n    \
+             attempts: 3,\n    \
+             hosts: [\n        \
+                 \"a\",\n        \
+                 \"b\",\n    \
+             ],\n\
+         }"
+    );
+}
+
 #[test]
 fn supports_display_and_debug_formatters() {
     let value = PrettyDebugValue {
@@ -978,7 +1226,7 @@ fn enum_backtrace_is_inferred_without_attribute() {
     #[cfg(masterror_has_error_generic_member_access)]
     {
         let none = AutoBacktraceEnum::Tuple(None);
-        assert!(std::error::Error::backtrace(&none).is_none());
+        assert!(request_ref::<std::backtrace::Backtrace>(&none).is_none());
     }
 }
 
@@ -1121,3 +1369,66 @@ fn display_format_specs_match_standard_formatting() {
         format!("{value:.precision$}", value = 123.456_f64, precision = 4)
     );
 }
+
+#[test]
+fn display_width_and_precision_resolve_fields() {
+    let padded = FieldWidthError {
+        code: 42, pad: 5
+    };
+    assert_eq!(padded.to_string(), "code=00042");
+    let both = FieldWidthPrecisionError {
+        value:  12.34567,
+        width:  8,
+        digits: 2
+    };
+    assert_eq!(both.to_string(), format!("{:>8.2}", 12.34567));
+    assert_eq!(TupleFieldWidthError(7, 3).to_string(), "007");
+    let named = FieldWidthEnum::Named {
+        code: 9, pad: 4
+    };
+    assert_eq!(named.to_string(), "named 0009");
+    assert_eq!(FieldWidthEnum::Tuple(12, 4).to_string(), "tuple 0012");
+}
+
+#[test]
+fn optional_placeholder_renders_some_value() {
+    let err = OptionalFieldError {
+        user:   Some("alice".to_owned()),
+        region: Some("eu-west")
+    };
+    assert_eq!(err.to_string(), "lookup failed for user alice in eu-west");
+    assert_eq!(
+        OptionalFieldEnum::Tuple(Some(7), Some(255)).to_string(),
+        "retry   7 after 0xff"
+    );
+}
+
+#[test]
+fn optional_placeholder_renders_none_as_empty() {
+    let err = OptionalFieldError {
+        user:   None,
+        region: Some("eu-west")
+    };
+    assert_eq!(err.to_string(), "lookup failed for user  in eu-west");
+    assert_eq!(
+        OptionalFieldEnum::Tuple(Some(7), None).to_string(),
+        "retry   7 after "
+    );
+}
+
+#[test]
+fn optional_placeholder_uses_configured_fallback() {
+    let err = OptionalFieldError {
+        user:   None,
+        region: None
+    };
+    assert_eq!(err.to_string(), "lookup failed for user  in unknown");
+    assert_eq!(
+        OptionalFieldEnum::Tuple(None, None).to_string(),
+        "retry   - after "
+    );
+    let repeated = OptionalFieldEnum::Repeated {
+        code: None
+    };
+    assert_eq!(repeated.to_string(), "none (none)");
+}
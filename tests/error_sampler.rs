@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Process-wide error sampler.
+//!
+//! Lives in its own binary because the sampler is global state. Everything
+//! runs in a single test so installations never race each other.
+
+#![cfg(feature = "std")]
+
+use std::sync::Arc;
+
+use masterror::{AppError, EveryNth, error_sampler, set_error_sampler};
+
+#[test]
+fn global_sampler_selects_every_nth_occurrence_per_fingerprint() {
+    assert!(error_sampler().is_none());
+    assert!(AppError::internal("boom").should_sample());
+
+    set_error_sampler(Some(Arc::new(EveryNth::new(3))));
+    let timeouts: Vec<bool> = (0..7)
+        .map(|attempt| AppError::timeout(format!("attempt {attempt} timed out")).should_sample())
+        .collect();
+    assert_eq!(timeouts, [true, false, false, true, false, false, true]);
+
+    let conflict = AppError::conflict("version mismatch");
+    assert!(conflict.should_sample());
+    assert!(!conflict.should_sample());
+
+    set_error_sampler(None);
+    assert!(error_sampler().is_none());
+    assert!(AppError::timeout("attempt 8 timed out").should_sample());
+}
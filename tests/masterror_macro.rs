@@ -7,7 +7,8 @@
 use std::{error::Error as StdError, sync::Arc};
 
 use masterror::{
-    AppCode, AppErrorKind, Error as MasterrorError, FieldRedaction, Masterror, MessageEditPolicy,
+    AppCode, AppErrorKind, Error as MasterrorError, ErrorResponse, FieldRedaction, FieldValue,
+    Masterror, MessageEditPolicy, RetryAdvice,
     mapping::{GrpcMapping, HttpMapping, ProblemMapping}
 };
 
@@ -191,3 +192,457 @@ struct ArcSourceError {
     #[source]
     source: Arc<ArcLeafError>
 }
+
+#[derive(Debug, Masterror)]
+#[error("too many requests")]
+#[masterror(
+    code = AppCode::RateLimited,
+    category = AppErrorKind::RateLimited,
+    message,
+    retry_after = 30
+)]
+struct Throttled;
+
+#[derive(Debug, Masterror)]
+enum QuotaError {
+    #[error("burst limit")]
+    #[masterror(
+        code = AppCode::RateLimited,
+        category = AppErrorKind::RateLimited,
+        retry_after = 5
+    )]
+    Burst,
+    #[error("daily limit")]
+    #[masterror(
+        code = AppCode::RateLimited,
+        category = AppErrorKind::RateLimited,
+        retry_after = std::time::Duration::from_secs(3600)
+    )]
+    Daily,
+    #[error("backoff {seconds}s")]
+    #[masterror(
+        code = AppCode::RateLimited,
+        category = AppErrorKind::RateLimited,
+        retry_after = seconds
+    )]
+    Backoff { seconds: u32 },
+    #[error("storage offline")]
+    #[masterror(code = AppCode::Service, category = AppErrorKind::Service)]
+    Offline
+}
+
+#[test]
+fn struct_retry_after_sets_retry_advice() {
+    let converted: MasterrorError = Throttled.into();
+    assert_eq!(
+        converted.retry,
+        Some(RetryAdvice {
+            after_seconds: 30
+        })
+    );
+}
+
+#[test]
+fn enum_retry_after_is_per_variant() {
+    let retry = |err: QuotaError| MasterrorError::from(err).retry.map(|r| r.after_seconds);
+    assert_eq!(retry(QuotaError::Burst), Some(5));
+    assert_eq!(retry(QuotaError::Daily), Some(3600));
+    assert_eq!(
+        retry(QuotaError::Backoff {
+            seconds: 12
+        }),
+        Some(12)
+    );
+    assert_eq!(retry(QuotaError::Offline), None);
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn retry_after_reaches_axum_response_header() {
+    use axum::response::IntoResponse;
+
+    let response = MasterrorError::from(Throttled).into_response();
+    assert_eq!(response.status().as_u16(), 429);
+    assert_eq!(
+        response
+            .headers()
+            .get("retry-after")
+            .and_then(|value| value.to_str().ok()),
+        Some("30")
+    );
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Provider {
+    Stripe,
+    Adyen
+}
+
+impl Provider {
+    fn code(self) -> AppCode {
+        match self {
+            Self::Stripe => AppCode::new("STRIPE_DECLINED"),
+            Self::Adyen => AppCode::new("ADYEN_REFUSED")
+        }
+    }
+}
+
+#[derive(Debug, Masterror)]
+#[error("payment declined by {provider:?}")]
+#[masterror(
+    code = provider.code(),
+    category = AppErrorKind::ExternalApi,
+    message,
+    map.grpc = 9
+)]
+struct PaymentDeclined {
+    provider: Provider
+}
+
+#[derive(Debug, Masterror)]
+enum GatewayError {
+    #[error("upstream {provider:?} rejected request")]
+    #[masterror(code = provider.code(), category = AppErrorKind::ExternalApi)]
+    Rejected { provider: Provider },
+    #[error("upstream status {status}")]
+    #[masterror(
+        code = if status >= 500 {
+            AppCode::DependencyUnavailable
+        } else {
+            AppCode::ExternalApi
+        },
+        category = AppErrorKind::ExternalApi
+    )]
+    Status { status: u16 },
+    #[error("gateway offline")]
+    #[masterror(code = AppCode::Service, category = AppErrorKind::Service)]
+    Offline
+}
+
+#[test]
+fn struct_code_expression_reads_fields() {
+    let converted: MasterrorError = PaymentDeclined {
+        provider: Provider::Adyen
+    }
+    .into();
+    assert_eq!(converted.code.as_str(), "ADYEN_REFUSED");
+    assert_eq!(converted.kind, AppErrorKind::ExternalApi);
+    let converted: MasterrorError = PaymentDeclined {
+        provider: Provider::Stripe
+    }
+    .into();
+    assert_eq!(converted.code.as_str(), "STRIPE_DECLINED");
+    assert_eq!(
+        PaymentDeclined::HTTP_MAPPING,
+        HttpMapping::new(AppCode::ExternalApi, AppErrorKind::ExternalApi)
+    );
+    let grpc = PaymentDeclined::GRPC_MAPPING.expect("grpc mapping");
+    assert_eq!(grpc.code(), &AppCode::ExternalApi);
+}
+
+#[test]
+fn enum_code_expression_is_per_variant() {
+    let code = |err: GatewayError| MasterrorError::from(err).code.clone();
+    assert_eq!(
+        code(GatewayError::Rejected {
+            provider: Provider::Stripe
+        })
+        .as_str(),
+        "STRIPE_DECLINED"
+    );
+    assert_eq!(
+        code(GatewayError::Status {
+            status: 503
+        }),
+        AppCode::DependencyUnavailable
+    );
+    assert_eq!(
+        code(GatewayError::Status {
+            status: 404
+        }),
+        AppCode::ExternalApi
+    );
+    assert_eq!(code(GatewayError::Offline), AppCode::Service);
+    assert_eq!(
+        GatewayError::HTTP_MAPPINGS,
+        [
+            HttpMapping::new(AppCode::ExternalApi, AppErrorKind::ExternalApi),
+            HttpMapping::new(AppCode::ExternalApi, AppErrorKind::ExternalApi),
+            HttpMapping::new(AppCode::Service, AppErrorKind::Service)
+        ]
+    );
+}
+
+#[derive(Debug, Masterror)]
+#[error("card declined")]
+#[masterror(
+    code = AppCode::ExternalApi,
+    category = AppErrorKind::ExternalApi,
+    telemetry(Some(masterror::field::u64("attempt", attempt))),
+    tags("subsystem" = "billing", "team" = "payments")
+)]
+struct CardDeclined {
+    attempt: u64
+}
+
+#[test]
+fn tags_attach_static_metadata() {
+    let converted: MasterrorError = CardDeclined {
+        attempt: 2
+    }
+    .into();
+    let metadata = converted.metadata();
+    assert_eq!(
+        metadata.get("subsystem"),
+        Some(&FieldValue::Str("billing".into()))
+    );
+    assert_eq!(
+        metadata.get("team"),
+        Some(&FieldValue::Str("payments".into()))
+    );
+    assert_eq!(metadata.redaction("subsystem"), Some(FieldRedaction::None));
+    assert_eq!(metadata.redaction("team"), Some(FieldRedaction::None));
+    assert_eq!(metadata.get("attempt"), Some(&FieldValue::U64(2)));
+}
+
+#[derive(Debug, Masterror)]
+#[error("token expired")]
+#[masterror(
+    code = AppCode::Unauthorized,
+    category = AppErrorKind::Unauthorized,
+    message,
+    www_authenticate = "Bearer error=\"invalid_token\""
+)]
+struct TokenExpired;
+
+#[derive(Debug, Masterror)]
+enum RealmError {
+    #[error("admin login required")]
+    #[masterror(
+        code = AppCode::Unauthorized,
+        category = AppErrorKind::Unauthorized,
+        www_authenticate = "Bearer realm=\"admin\""
+    )]
+    Admin,
+    #[error("login required for {realm}")]
+    #[masterror(
+        code = AppCode::Unauthorized,
+        category = AppErrorKind::Unauthorized,
+        www_authenticate = format!("Basic realm=\"{realm}\"")
+    )]
+    Custom { realm: String },
+    #[error("forbidden")]
+    #[masterror(code = AppCode::Forbidden, category = AppErrorKind::Forbidden)]
+    Forbidden
+}
+
+#[test]
+fn struct_www_authenticate_sets_challenge() {
+    let converted: MasterrorError = TokenExpired.into();
+    assert_eq!(
+        converted.www_authenticate.as_deref(),
+        Some("Bearer error=\"invalid_token\"")
+    );
+}
+
+#[test]
+fn enum_www_authenticate_is_per_variant() {
+    let challenge = |err: RealmError| MasterrorError::from(err).www_authenticate.clone();
+    assert_eq!(
+        challenge(RealmError::Admin).as_deref(),
+        Some("Bearer realm=\"admin\"")
+    );
+    assert_eq!(
+        challenge(RealmError::Custom {
+            realm: "reports".to_owned()
+        })
+        .as_deref(),
+        Some("Basic realm=\"reports\"")
+    );
+    assert_eq!(challenge(RealmError::Forbidden), None);
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn www_authenticate_reaches_axum_response_header() {
+    use axum::response::IntoResponse;
+
+    let response = MasterrorError::from(TokenExpired).into_response();
+    assert_eq!(response.status().as_u16(), 401);
+    assert_eq!(
+        response
+            .headers()
+            .get("www-authenticate")
+            .and_then(|value| value.to_str().ok()),
+        Some("Bearer error=\"invalid_token\"")
+    );
+}
+
+#[derive(Debug, Masterror)]
+#[error("I'm a teapot")]
+#[masterror(
+    code = AppCode::BadRequest,
+    category = AppErrorKind::BadRequest,
+    message,
+    status = 418
+)]
+struct Teapot;
+
+#[derive(Debug, Masterror)]
+enum BrewError {
+    #[error("still brewing")]
+    #[masterror(code = AppCode::Conflict, category = AppErrorKind::Conflict, status = 425)]
+    TooEarly,
+    #[error("kettle missing")]
+    #[masterror(code = AppCode::NotFound, category = AppErrorKind::NotFound)]
+    Missing
+}
+
+#[test]
+fn struct_status_overrides_kind_status_in_error_response() {
+    let converted: MasterrorError = Teapot.into();
+    assert_eq!(converted.kind, AppErrorKind::BadRequest);
+    assert_eq!(converted.status(), 418);
+    let response = ErrorResponse::from(converted);
+    assert_eq!(response.status, 418);
+    assert_eq!(response.code, AppCode::BadRequest);
+}
+
+#[test]
+fn enum_status_is_per_variant() {
+    let status = |err: BrewError| ErrorResponse::from(MasterrorError::from(err)).status;
+    assert_eq!(status(BrewError::TooEarly), 425);
+    assert_eq!(status(BrewError::Missing), 404);
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn status_reaches_axum_response() {
+    use axum::response::IntoResponse;
+
+    let response = MasterrorError::from(Teapot).into_response();
+    assert_eq!(response.status().as_u16(), 418);
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Masterror)]
+#[error("upload failed")]
+#[masterror(
+    code = AppCode::Service,
+    category = AppErrorKind::Service,
+    message,
+    source_as_detail
+)]
+struct UploadError {
+    #[source]
+    source: std::io::Error
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Masterror)]
+enum SyncError {
+    #[error("sync failed")]
+    #[masterror(code = AppCode::Service, category = AppErrorKind::Service, source_as_detail)]
+    Remote {
+        #[allow(non_shorthand_field_patterns)]
+        #[source]
+        _source: Option<std::io::Error>
+    }
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn source_as_detail_copies_source_message_into_details_cause() {
+    use serde_json::json;
+
+    let converted: MasterrorError = UploadError {
+        source: std::io::Error::other("disk quota exceeded")
+    }
+    .into();
+    assert_eq!(converted.message.as_deref(), Some("upload failed"));
+    assert_eq!(
+        converted.details,
+        Some(json!({"cause": "disk quota exceeded"}))
+    );
+    assert!(converted.source_ref().is_some());
+    let response = ErrorResponse::from(converted);
+    assert_eq!(
+        response.details,
+        Some(json!({"cause": "disk quota exceeded"}))
+    );
+
+    let remote = |source| {
+        MasterrorError::from(SyncError::Remote {
+            _source: source
+        })
+        .details
+        .clone()
+    };
+    assert_eq!(
+        remote(Some(std::io::Error::other("connection reset"))),
+        Some(json!({"cause": "connection reset"}))
+    );
+    assert_eq!(remote(None), None);
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Debug, serde::Serialize)]
+struct CheckoutContext {
+    cart_id: &'static str,
+    items:   Vec<u32>,
+    buyer:   Buyer
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Debug, serde::Serialize)]
+struct Buyer {
+    id:      u64,
+    premium: bool
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Masterror)]
+#[error("checkout failed")]
+#[masterror(
+    code = AppCode::Service,
+    category = AppErrorKind::Service,
+    telemetry(
+        serialize(self.context, prefix = "checkout"),
+        Some(masterror::field::u64("attempt", attempt))
+    )
+)]
+struct CheckoutFailed {
+    context: CheckoutContext,
+    attempt: u64
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn telemetry_serialize_flattens_struct_into_metadata() {
+    let converted: MasterrorError = CheckoutFailed {
+        context: CheckoutContext {
+            cart_id: "cart-42",
+            items:   vec![3, 5],
+            buyer:   Buyer {
+                id:      7,
+                premium: true
+            }
+        },
+        attempt: 1
+    }
+    .into();
+    let metadata = converted.metadata();
+    assert_eq!(
+        metadata.get("checkout.cart_id"),
+        Some(&FieldValue::Str("cart-42".into()))
+    );
+    assert_eq!(metadata.get("checkout.items.0"), Some(&FieldValue::U64(3)));
+    assert_eq!(metadata.get("checkout.items.1"), Some(&FieldValue::U64(5)));
+    assert_eq!(metadata.get("checkout.buyer.id"), Some(&FieldValue::U64(7)));
+    assert_eq!(
+        metadata.get("checkout.buyer.premium"),
+        Some(&FieldValue::Bool(true))
+    );
+    assert_eq!(metadata.get("attempt"), Some(&FieldValue::U64(1)));
+    assert_eq!(metadata.len(), 6);
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Allocation budget for small metadata sets.
+//!
+//! Lives in its own binary because it installs a counting global allocator.
+//! Counts are tracked per thread so concurrently running tests cannot skew
+//! the measurement.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell
+};
+
+use masterror::{AppError, Metadata, field};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn up_to_four_fields_do_not_allocate() {
+    let allocations = allocations_during(|| {
+        let mut meta = Metadata::new();
+        meta.insert(field::str("request_id", "abc-123"));
+        meta.insert(field::u64("attempt", 2));
+        meta.insert(field::bool("cached", false));
+        meta.insert(field::i64("delta", -1));
+        assert_eq!(meta.len(), 4);
+        drop(meta);
+    });
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn fifth_field_spills_to_heap() {
+    let allocations = allocations_during(|| {
+        let mut meta = Metadata::new();
+        for name in ["a", "b", "c", "d", "e"] {
+            meta.insert(field::u64(name, 1));
+        }
+        assert_eq!(meta.len(), 5);
+        drop(meta);
+    });
+    assert!(allocations > 0);
+}
+
+#[test]
+fn reserved_capacity_absorbs_spill() {
+    const NAMES: [&str; 6] = ["a", "b", "c", "d", "e", "f"];
+    let with_fields = |err: AppError| {
+        let err = NAMES
+            .into_iter()
+            .fold(err, |err, name| err.with_field(field::u64(name, 1)));
+        assert_eq!(err.metadata().len(), NAMES.len());
+    };
+
+    let reserved = AppError::internal("boom").with_metadata_capacity(NAMES.len());
+    assert_eq!(allocations_during(|| with_fields(reserved)), 0);
+
+    let unreserved = AppError::internal("boom");
+    assert!(allocations_during(|| with_fields(unreserved)) > 0);
+}
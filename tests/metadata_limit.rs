@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Process-wide metadata field limit.
+//!
+//! Lives in its own binary because the limit is global state. Everything runs
+//! in a single test so the policies never race each other.
+
+use masterror::{
+    AppError, FieldValue, Metadata, MetadataLimit, MetadataOverflow, field, metadata_limit,
+    set_metadata_limit
+};
+
+#[test]
+fn global_limit_applies_configured_overflow_policy() {
+    assert_eq!(metadata_limit(), None);
+
+    set_metadata_limit(Some(MetadataLimit::new(2, MetadataOverflow::Drop)));
+    let err = AppError::internal("boom")
+        .with_field(field::u64("a", 1))
+        .with_field(field::u64("b", 2))
+        .with_fields([field::u64("c", 3), field::u64("d", 4)]);
+    assert_eq!(err.metadata().len(), 3);
+    assert!(!err.metadata().contains_key("c"));
+    assert_eq!(
+        err.metadata().get("metadata.dropped"),
+        Some(&FieldValue::U64(2))
+    );
+
+    let mut prebuilt = Metadata::from_fields([field::u64("z", 1), field::u64("y", 2)]);
+    prebuilt.extend([field::u64("x", 3)]);
+    prebuilt.insert(field::u64("w", 4));
+    assert_eq!(prebuilt.len(), 4, "direct inserts are not limited");
+    let err = AppError::internal("boom").with_metadata(prebuilt);
+    assert!(err.metadata().contains_key("z") && err.metadata().contains_key("y"));
+    assert!(!err.metadata().contains_key("x") && !err.metadata().contains_key("w"));
+    assert_eq!(
+        err.metadata().get("metadata.dropped"),
+        Some(&FieldValue::U64(2))
+    );
+
+    set_metadata_limit(Some(MetadataLimit::new(2, MetadataOverflow::ReplaceOldest)));
+    let err = AppError::internal("boom")
+        .with_field(field::u64("b", 1))
+        .with_field(field::u64("a", 2))
+        .with_field(field::u64("c", 3));
+    assert!(!err.metadata().contains_key("b"));
+    assert!(err.metadata().contains_key("a"));
+    assert!(err.metadata().contains_key("c"));
+
+    set_metadata_limit(None);
+    let err = AppError::internal("boom").with_fields([
+        field::u64("a", 1),
+        field::u64("b", 2),
+        field::u64("c", 3)
+    ]);
+    assert_eq!(err.metadata().len(), 3);
+}
@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Compile checks for the framework-specific prelude bundles.
+//!
+//! Each module imports only its prelude and defines a handler returning
+//! `AppResult<&'static str>`, proving the bundle is sufficient on its own.
+
+#[cfg(feature = "axum")]
+mod axum_prelude {
+    use masterror::prelude::axum::*;
+
+    async fn handler(flag: bool) -> AppResult<&'static str> {
+        if !flag {
+            return Err(AppError::new(AppErrorKind::BadRequest, "flag_required"));
+        }
+        Ok("ok")
+    }
+
+    #[tokio::test]
+    async fn handler_error_renders_problem_response() {
+        let ok: Response = handler(true).await.into_response();
+        assert_eq!(ok.status().as_u16(), 200);
+
+        let err: Response = handler(false).await.into_response();
+        assert_eq!(err.status().as_u16(), 400);
+
+        let problem = ProblemJson::from_app_error(AppError::bad_request("flag_required"));
+        assert_eq!(problem.code, AppCode::BadRequest);
+    }
+}
+
+#[cfg(feature = "actix")]
+mod actix_prelude {
+    use masterror::prelude::actix::*;
+
+    async fn handler(flag: bool) -> AppResult<&'static str> {
+        if !flag {
+            return Err(AppError::new(AppErrorKind::BadRequest, "flag_required"));
+        }
+        Ok("ok")
+    }
+
+    fn assert_responder<T: Responder>(_: &T) {}
+
+    #[tokio::test]
+    async fn handler_result_is_a_responder() {
+        let ok = handler(true).await;
+        assert_responder(&ok);
+
+        let err = handler(false).await.unwrap_err();
+        let response: HttpResponse = err.error_response();
+        assert_eq!(response.status().as_u16(), 400);
+
+        let problem = ProblemJson::from_app_error(AppError::bad_request("flag_required"));
+        assert_eq!(problem.code, AppCode::BadRequest);
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for the `tokio` retry helper.
+
+#![cfg(feature = "tokio")]
+
+use std::{cell::Cell, time::Duration};
+
+use masterror::{AppError, AppErrorKind, FieldValue, retry};
+use tokio::time::Instant;
+
+#[tokio::test(start_paused = true)]
+async fn transient_timeout_succeeds_on_third_attempt() {
+    let calls = Cell::new(0u32);
+    let start = Instant::now();
+    let value = retry(5, || {
+        calls.set(calls.get() + 1);
+        let attempt = calls.get();
+        async move {
+            if attempt < 3 {
+                Err(AppError::timeout("upstream stalled"))
+            } else {
+                Ok(attempt)
+            }
+        }
+    })
+    .await
+    .expect("third attempt succeeds");
+    assert_eq!(value, 3);
+    assert_eq!(calls.get(), 3);
+    assert_eq!(start.elapsed(), Duration::from_millis(300));
+}
+
+#[tokio::test(start_paused = true)]
+async fn non_retryable_validation_fails_immediately() {
+    let calls = Cell::new(0u32);
+    let start = Instant::now();
+    let err = retry(5, || {
+        calls.set(calls.get() + 1);
+        async { Err::<(), _>(AppError::validation("email is invalid")) }
+    })
+    .await
+    .expect_err("validation is not retried");
+    assert_eq!(calls.get(), 1);
+    assert_eq!(err.kind, AppErrorKind::Validation);
+    assert_eq!(err.metadata().get("attempts"), Some(&FieldValue::U64(1)));
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_advice_sets_delay_and_budget_is_enforced() {
+    let calls = Cell::new(0u32);
+    let start = Instant::now();
+    let err = retry(3, || {
+        calls.set(calls.get() + 1);
+        async { Err::<(), _>(AppError::rate_limited("slow down").with_retry_after_secs(2)) }
+    })
+    .await
+    .expect_err("budget exhausted");
+    assert_eq!(calls.get(), 3);
+    assert_eq!(err.kind, AppErrorKind::RateLimited);
+    assert_eq!(err.metadata().get("attempts"), Some(&FieldValue::U64(3)));
+    assert_eq!(start.elapsed(), Duration::from_secs(4));
+}
@@ -0,0 +1,241 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Integration tests for the `tower` error layer.
+
+#![cfg(feature = "tower")]
+
+use std::{
+    convert::Infallible,
+    future::{Ready, poll_fn, ready},
+    task::{Context, Poll}
+};
+
+use http::{
+    Request, Response, StatusCode,
+    header::{ALLOW, CONTENT_TYPE, RETRY_AFTER}
+};
+use masterror::{AppError, DisplayMode, field, tower::ErrorLayer};
+use serde_json::Value;
+use tower_layer::Layer;
+use tower_service::Service;
+
+#[derive(Clone)]
+struct Upstream {
+    error: Option<fn() -> AppError>
+}
+
+impl Service<Request<()>> for Upstream {
+    type Response = Response<String>;
+    type Error = AppError;
+    type Future = Ready<Result<Response<String>, AppError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), AppError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _request: Request<()>) -> Self::Future {
+        ready(match self.error {
+            Some(error) => Err(error()),
+            None => Ok(Response::new("ok".to_owned()))
+        })
+    }
+}
+
+struct Unavailable;
+
+impl Service<Request<()>> for Unavailable {
+    type Response = Response<String>;
+    type Error = AppError;
+    type Future = Ready<Result<Response<String>, AppError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), AppError>> {
+        Poll::Ready(Err(AppError::dependency_unavailable("pool exhausted")))
+    }
+
+    fn call(&mut self, _request: Request<()>) -> Self::Future {
+        unreachable!("call must not reach a service that failed poll_ready")
+    }
+}
+
+async fn send<S>(service: &mut S) -> Response<String>
+where
+    S: Service<Request<()>, Response = Response<String>, Error = Infallible>
+{
+    poll_fn(|cx| service.poll_ready(cx))
+        .await
+        .expect("infallible readiness");
+    service
+        .call(Request::new(()))
+        .await
+        .expect("infallible response")
+}
+
+fn json_body(response: Response<String>) -> Value {
+    serde_json::from_str(response.body()).expect("problem json body")
+}
+
+#[tokio::test]
+async fn failing_service_responds_with_problem_json() {
+    let mut service = ErrorLayer::new()
+        .with_display_mode(DisplayMode::Local)
+        .layer(Upstream {
+            error: Some(|| {
+                AppError::rate_limited("slow down")
+                    .with_retry_after_secs(30)
+                    .with_field(field::str("tenant", "acme"))
+            })
+        });
+    let response = send(&mut service).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(
+        response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok()),
+        Some("application/problem+json")
+    );
+    assert_eq!(
+        response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok()),
+        Some("30")
+    );
+    let body = json_body(response);
+    assert_eq!(body["status"], 429);
+    assert_eq!(body["title"], "Rate limit exceeded");
+    assert_eq!(body["code"], "RATE_LIMITED");
+    assert_eq!(body["detail"], "slow down");
+    assert!(body["type"].as_str().is_some());
+    assert_eq!(body["metadata"]["tenant"], "acme");
+}
+
+#[tokio::test]
+async fn successful_responses_pass_through() {
+    let mut service = ErrorLayer::new().layer(Upstream {
+        error: None
+    });
+    let response = send(&mut service).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.body(), "ok");
+}
+
+#[tokio::test]
+async fn prod_mode_redacts_server_errors() {
+    let layer = ErrorLayer::new()
+        .with_display_mode(DisplayMode::Prod)
+        .with_logging(false);
+    let mut service = layer.layer(Upstream {
+        error: Some(|| {
+            AppError::internal("connection string leaked").with_field(field::str("host", "db"))
+        })
+    });
+    let response = send(&mut service).await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let body = json_body(response);
+    assert!(body.get("detail").is_none());
+    assert!(body.get("metadata").is_none());
+    let mut service = layer.layer(Upstream {
+        error: Some(|| AppError::not_found("no such user"))
+    });
+    let body = json_body(send(&mut service).await);
+    assert_eq!(body["detail"], "no such user");
+}
+
+#[tokio::test]
+async fn prod_mode_redaction_follows_status_override() {
+    let layer = ErrorLayer::new()
+        .with_display_mode(DisplayMode::Prod)
+        .with_logging(false);
+    let mut service = layer.layer(Upstream {
+        error: Some(|| {
+            AppError::bad_request("upstream said tenant db-7 is down").with_status(500)
+        })
+    });
+    let response = send(&mut service).await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(json_body(response).get("detail").is_none());
+    let mut service = layer.layer(Upstream {
+        error: Some(|| AppError::internal("quota exceeded for this plan").with_status(402))
+    });
+    let response = send(&mut service).await;
+    assert_eq!(response.status(), StatusCode::PAYMENT_REQUIRED);
+    assert_eq!(
+        json_body(response)["detail"],
+        "quota exceeded for this plan"
+    );
+}
+
+#[tokio::test]
+async fn redacted_method_not_allowed_keeps_allow_header() {
+    let layer = ErrorLayer::new()
+        .with_display_mode(DisplayMode::Prod)
+        .with_logging(false);
+    let mut service = layer.layer(Upstream {
+        error: Some(|| AppError::method_not_allowed(&["GET", "HEAD"]).redactable())
+    });
+    let response = send(&mut service).await;
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(
+        response.headers().get(ALLOW).and_then(|v| v.to_str().ok()),
+        Some("GET, HEAD")
+    );
+    assert!(json_body(response).get("metadata").is_none());
+}
+
+#[tokio::test]
+async fn readiness_errors_are_rendered_on_call() {
+    let mut service = ErrorLayer::new()
+        .with_display_mode(DisplayMode::Staging)
+        .layer(Unavailable);
+    let response = send(&mut service).await;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(json_body(response)["detail"], "pool exhausted");
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn layer_plugs_into_axum_router() {
+    use axum::{
+        Router,
+        body::{Body, to_bytes}
+    };
+
+    #[derive(Clone)]
+    struct Forbidden;
+
+    impl Service<Request<Body>> for Forbidden {
+        type Response = Response<Body>;
+        type Error = AppError;
+        type Future = Ready<Result<Response<Body>, AppError>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), AppError>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: Request<Body>) -> Self::Future {
+            ready(Err(AppError::forbidden("no access")))
+        }
+    }
+
+    let mut router: Router = Router::new().route_service(
+        "/guarded",
+        ErrorLayer::new()
+            .with_display_mode(DisplayMode::Prod)
+            .layer(Forbidden)
+    );
+    let request = Request::builder()
+        .uri("/guarded")
+        .body(Body::empty())
+        .expect("request");
+    let response = router.call(request).await.expect("infallible router");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let bytes = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body: Value = serde_json::from_slice(&bytes).expect("problem json body");
+    assert_eq!(body["detail"], "no access");
+    assert_eq!(body["status"], 403);
+}
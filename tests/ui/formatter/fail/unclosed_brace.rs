@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use masterror::Error;
+
+#[derive(Debug, Error)]
+#[error("id {unclosed")]
+struct UnclosedBrace {
+    unclosed: u32,
+}
+
+fn main() {}
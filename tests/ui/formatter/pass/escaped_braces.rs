@@ -0,0 +1,15 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use masterror::Error;
+
+#[derive(Debug, Error)]
+#[error("{{literal}} {value} }}{{")]
+struct EscapedBraces {
+    value: u8,
+}
+
+fn main() {
+    assert_eq!(EscapedBraces { value: 7 }.to_string(), "{literal} 7 }{");
+}